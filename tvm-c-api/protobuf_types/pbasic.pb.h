@@ -1,24 +1,25 @@
 // Generated by the protocol buffer compiler.  DO NOT EDIT!
 // source: protobuf_types/pbasic.proto
 
-#ifndef PROTOBUF_INCLUDED_protobuf_5ftypes_2fpbasic_2eproto
-#define PROTOBUF_INCLUDED_protobuf_5ftypes_2fpbasic_2eproto
+#ifndef GOOGLE_PROTOBUF_INCLUDED_protobuf_5ftypes_2fpbasic_2eproto
+#define GOOGLE_PROTOBUF_INCLUDED_protobuf_5ftypes_2fpbasic_2eproto
 
+#include <limits>
 #include <string>
 
-#include <google/protobuf/stubs/common.h>
-
-#if GOOGLE_PROTOBUF_VERSION < 3006001
+#include <google/protobuf/port_def.inc>
+#if PROTOBUF_VERSION < 3011000
 #error This file was generated by a newer version of protoc which is
-#error incompatible with your Protocol Buffer headers.  Please update
+#error incompatible with your Protocol Buffer headers. Please update
 #error your headers.
 #endif
-#if 3006001 < GOOGLE_PROTOBUF_MIN_PROTOC_VERSION
+#if 3011004 < PROTOBUF_MIN_PROTOC_VERSION
 #error This file was generated by an older version of protoc which is
-#error incompatible with your Protocol Buffer headers.  Please
+#error incompatible with your Protocol Buffer headers. Please
 #error regenerate this file with a newer version of protoc.
 #endif
 
+#include <google/protobuf/port_undef.inc>
 #include <google/protobuf/io/coded_stream.h>
 #include <google/protobuf/arena.h>
 #include <google/protobuf/arenastring.h>
@@ -26,25 +27,33 @@
 #include <google/protobuf/generated_message_util.h>
 #include <google/protobuf/inlined_string_field.h>
 #include <google/protobuf/metadata.h>
+#include <google/protobuf/generated_message_reflection.h>
 #include <google/protobuf/message.h>
 #include <google/protobuf/repeated_field.h>  // IWYU pragma: export
 #include <google/protobuf/extension_set.h>  // IWYU pragma: export
 #include <google/protobuf/unknown_field_set.h>
 // @@protoc_insertion_point(includes)
-#define PROTOBUF_INTERNAL_EXPORT_protobuf_protobuf_5ftypes_2fpbasic_2eproto 
+#include <google/protobuf/port_def.inc>
+#define PROTOBUF_INTERNAL_EXPORT_protobuf_5ftypes_2fpbasic_2eproto
+PROTOBUF_NAMESPACE_OPEN
+namespace internal {
+class AnyMetadata;
+}  // namespace internal
+PROTOBUF_NAMESPACE_CLOSE
 
-namespace protobuf_protobuf_5ftypes_2fpbasic_2eproto {
 // Internal implementation detail -- do not use these members.
-struct TableStruct {
-  static const ::google::protobuf::internal::ParseTableField entries[];
-  static const ::google::protobuf::internal::AuxillaryParseTableField aux[];
-  static const ::google::protobuf::internal::ParseTable schema[1];
-  static const ::google::protobuf::internal::FieldMetadata field_metadata[];
-  static const ::google::protobuf::internal::SerializationTable serialization_table[];
-  static const ::google::protobuf::uint32 offsets[];
+struct TableStruct_protobuf_5ftypes_2fpbasic_2eproto {
+  static const ::PROTOBUF_NAMESPACE_ID::internal::ParseTableField entries[]
+    PROTOBUF_SECTION_VARIABLE(protodesc_cold);
+  static const ::PROTOBUF_NAMESPACE_ID::internal::AuxillaryParseTableField aux[]
+    PROTOBUF_SECTION_VARIABLE(protodesc_cold);
+  static const ::PROTOBUF_NAMESPACE_ID::internal::ParseTable schema[1]
+    PROTOBUF_SECTION_VARIABLE(protodesc_cold);
+  static const ::PROTOBUF_NAMESPACE_ID::internal::FieldMetadata field_metadata[];
+  static const ::PROTOBUF_NAMESPACE_ID::internal::SerializationTable serialization_table[];
+  static const ::PROTOBUF_NAMESPACE_ID::uint32 offsets[];
 };
-void AddDescriptors();
-}  // namespace protobuf_protobuf_5ftypes_2fpbasic_2eproto
+extern const ::PROTOBUF_NAMESPACE_ID::internal::DescriptorTable descriptor_table_protobuf_5ftypes_2fpbasic_2eproto;
 namespace top {
 namespace tvm_engine {
 namespace basic {
@@ -54,34 +63,31 @@ extern PAddressDefaultTypeInternal _PAddress_default_instance_;
 }  // namespace basic
 }  // namespace tvm_engine
 }  // namespace top
-namespace google {
-namespace protobuf {
+PROTOBUF_NAMESPACE_OPEN
 template<> ::top::tvm_engine::basic::PAddress* Arena::CreateMaybeMessage<::top::tvm_engine::basic::PAddress>(Arena*);
-}  // namespace protobuf
-}  // namespace google
+PROTOBUF_NAMESPACE_CLOSE
 namespace top {
 namespace tvm_engine {
 namespace basic {
 
 // ===================================================================
 
-class PAddress : public ::google::protobuf::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.basic.PAddress) */ {
+class PAddress :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.basic.PAddress) */ {
  public:
   PAddress();
   virtual ~PAddress();
 
   PAddress(const PAddress& from);
-
-  inline PAddress& operator=(const PAddress& from) {
-    CopyFrom(from);
-    return *this;
-  }
-  #if LANG_CXX11
   PAddress(PAddress&& from) noexcept
     : PAddress() {
     *this = ::std::move(from);
   }
 
+  inline PAddress& operator=(const PAddress& from) {
+    CopyFrom(from);
+    return *this;
+  }
   inline PAddress& operator=(PAddress&& from) noexcept {
     if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
       if (this != &from) InternalSwap(&from);
@@ -90,8 +96,16 @@ class PAddress : public ::google::protobuf::Message /* @@protoc_insertion_point(
     }
     return *this;
   }
-  #endif
-  static const ::google::protobuf::Descriptor* descriptor();
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
   static const PAddress& default_instance();
 
   static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
@@ -102,77 +116,94 @@ class PAddress : public ::google::protobuf::Message /* @@protoc_insertion_point(
   static constexpr int kIndexInFileMessages =
     0;
 
-  void Swap(PAddress* other);
   friend void swap(PAddress& a, PAddress& b) {
     a.Swap(&b);
   }
+  inline void Swap(PAddress* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
 
   // implements Message ----------------------------------------------
 
   inline PAddress* New() const final {
-    return CreateMaybeMessage<PAddress>(NULL);
+    return CreateMaybeMessage<PAddress>(nullptr);
   }
 
-  PAddress* New(::google::protobuf::Arena* arena) const final {
+  PAddress* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
     return CreateMaybeMessage<PAddress>(arena);
   }
-  void CopyFrom(const ::google::protobuf::Message& from) final;
-  void MergeFrom(const ::google::protobuf::Message& from) final;
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
   void CopyFrom(const PAddress& from);
   void MergeFrom(const PAddress& from);
-  void Clear() final;
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
   bool IsInitialized() const final;
 
   size_t ByteSizeLong() const final;
-  bool MergePartialFromCodedStream(
-      ::google::protobuf::io::CodedInputStream* input) final;
-  void SerializeWithCachedSizes(
-      ::google::protobuf::io::CodedOutputStream* output) const final;
-  ::google::protobuf::uint8* InternalSerializeWithCachedSizesToArray(
-      bool deterministic, ::google::protobuf::uint8* target) const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
   int GetCachedSize() const final { return _cached_size_.Get(); }
 
   private:
-  void SharedCtor();
-  void SharedDtor();
+  inline void SharedCtor();
+  inline void SharedDtor();
   void SetCachedSize(int size) const final;
   void InternalSwap(PAddress* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.basic.PAddress";
+  }
   private:
-  inline ::google::protobuf::Arena* GetArenaNoVirtual() const {
-    return NULL;
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
   }
   inline void* MaybeArenaPtr() const {
-    return NULL;
+    return nullptr;
   }
   public:
 
-  ::google::protobuf::Metadata GetMetadata() const final;
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpbasic_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpbasic_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
 
   // nested types ----------------------------------------------------
 
   // accessors -------------------------------------------------------
 
+  enum : int {
+    kValueFieldNumber = 1,
+  };
   // bytes value = 1;
   void clear_value();
-  static const int kValueFieldNumber = 1;
-  const ::std::string& value() const;
-  void set_value(const ::std::string& value);
-  #if LANG_CXX11
-  void set_value(::std::string&& value);
-  #endif
+  const std::string& value() const;
+  void set_value(const std::string& value);
+  void set_value(std::string&& value);
   void set_value(const char* value);
   void set_value(const void* value, size_t size);
-  ::std::string* mutable_value();
-  ::std::string* release_value();
-  void set_allocated_value(::std::string* value);
+  std::string* mutable_value();
+  std::string* release_value();
+  void set_allocated_value(std::string* value);
+  private:
+  const std::string& _internal_value() const;
+  void _internal_set_value(const std::string& value);
+  std::string* _internal_mutable_value();
+  public:
 
   // @@protoc_insertion_point(class_scope:top.tvm_engine.basic.PAddress)
  private:
+  class _Internal;
 
-  ::google::protobuf::internal::InternalMetadataWithArena _internal_metadata_;
-  ::google::protobuf::internal::ArenaStringPtr value_;
-  mutable ::google::protobuf::internal::CachedSize _cached_size_;
-  friend struct ::protobuf_protobuf_5ftypes_2fpbasic_2eproto::TableStruct;
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr value_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpbasic_2eproto;
 };
 // ===================================================================
 
@@ -187,54 +218,61 @@ class PAddress : public ::google::protobuf::Message /* @@protoc_insertion_point(
 
 // bytes value = 1;
 inline void PAddress::clear_value() {
-  value_.ClearToEmptyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  value_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline const ::std::string& PAddress::value() const {
+inline const std::string& PAddress::value() const {
   // @@protoc_insertion_point(field_get:top.tvm_engine.basic.PAddress.value)
+  return _internal_value();
+}
+inline void PAddress::set_value(const std::string& value) {
+  _internal_set_value(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.basic.PAddress.value)
+}
+inline std::string* PAddress::mutable_value() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.basic.PAddress.value)
+  return _internal_mutable_value();
+}
+inline const std::string& PAddress::_internal_value() const {
   return value_.GetNoArena();
 }
-inline void PAddress::set_value(const ::std::string& value) {
+inline void PAddress::_internal_set_value(const std::string& value) {
   
-  value_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), value);
-  // @@protoc_insertion_point(field_set:top.tvm_engine.basic.PAddress.value)
+  value_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
 }
-#if LANG_CXX11
-inline void PAddress::set_value(::std::string&& value) {
+inline void PAddress::set_value(std::string&& value) {
   
   value_.SetNoArena(
-    &::google::protobuf::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
   // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.basic.PAddress.value)
 }
-#endif
 inline void PAddress::set_value(const char* value) {
-  GOOGLE_DCHECK(value != NULL);
+  GOOGLE_DCHECK(value != nullptr);
   
-  value_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  value_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
   // @@protoc_insertion_point(field_set_char:top.tvm_engine.basic.PAddress.value)
 }
 inline void PAddress::set_value(const void* value, size_t size) {
   
-  value_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(),
+  value_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
       ::std::string(reinterpret_cast<const char*>(value), size));
   // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.basic.PAddress.value)
 }
-inline ::std::string* PAddress::mutable_value() {
+inline std::string* PAddress::_internal_mutable_value() {
   
-  // @@protoc_insertion_point(field_mutable:top.tvm_engine.basic.PAddress.value)
-  return value_.MutableNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  return value_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline ::std::string* PAddress::release_value() {
+inline std::string* PAddress::release_value() {
   // @@protoc_insertion_point(field_release:top.tvm_engine.basic.PAddress.value)
   
-  return value_.ReleaseNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  return value_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline void PAddress::set_allocated_value(::std::string* value) {
-  if (value != NULL) {
+inline void PAddress::set_allocated_value(std::string* value) {
+  if (value != nullptr) {
     
   } else {
     
   }
-  value_.SetAllocatedNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), value);
+  value_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
   // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.basic.PAddress.value)
 }
 
@@ -250,4 +288,5 @@ inline void PAddress::set_allocated_value(::std::string* value) {
 
 // @@protoc_insertion_point(global_scope)
 
-#endif  // PROTOBUF_INCLUDED_protobuf_5ftypes_2fpbasic_2eproto
+#include <google/protobuf/port_undef.inc>
+#endif  // GOOGLE_PROTOBUF_INCLUDED_GOOGLE_PROTOBUF_INCLUDED_protobuf_5ftypes_2fpbasic_2eproto
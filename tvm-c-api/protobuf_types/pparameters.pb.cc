@@ -5,259 +5,562 @@
 
 #include <algorithm>
 
-#include <google/protobuf/stubs/common.h>
-#include <google/protobuf/stubs/port.h>
 #include <google/protobuf/io/coded_stream.h>
-#include <google/protobuf/wire_format_lite_inl.h>
+#include <google/protobuf/extension_set.h>
+#include <google/protobuf/wire_format_lite.h>
 #include <google/protobuf/descriptor.h>
 #include <google/protobuf/generated_message_reflection.h>
 #include <google/protobuf/reflection_ops.h>
 #include <google/protobuf/wire_format.h>
-// This is a temporary google only hack
-#ifdef GOOGLE_PROTOBUF_ENFORCE_UNIQUENESS
-#include "third_party/protobuf/version.h"
-#endif
 // @@protoc_insertion_point(includes)
-
-namespace protobuf_protobuf_5ftypes_2fpbasic_2eproto {
-extern PROTOBUF_INTERNAL_EXPORT_protobuf_protobuf_5ftypes_2fpbasic_2eproto ::google::protobuf::internal::SCCInfo<0> scc_info_PAddress;
-}  // namespace protobuf_protobuf_5ftypes_2fpbasic_2eproto
-namespace protobuf_protobuf_5ftypes_2fpparameters_2eproto {
-extern PROTOBUF_INTERNAL_EXPORT_protobuf_protobuf_5ftypes_2fpparameters_2eproto ::google::protobuf::internal::SCCInfo<0> scc_info_PReturnResult_PU256;
-extern PROTOBUF_INTERNAL_EXPORT_protobuf_protobuf_5ftypes_2fpparameters_2eproto ::google::protobuf::internal::SCCInfo<2> scc_info_PReturnResult_PLog;
-}  // namespace protobuf_protobuf_5ftypes_2fpparameters_2eproto
+#include <google/protobuf/port_def.inc>
+extern PROTOBUF_INTERNAL_EXPORT_protobuf_5ftypes_2fpbasic_2eproto ::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<0> scc_info_PAddress_protobuf_5ftypes_2fpbasic_2eproto;
+extern PROTOBUF_INTERNAL_EXPORT_protobuf_5ftypes_2fpparameters_2eproto ::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<1> scc_info_PCallArgs_PAccessListItem_protobuf_5ftypes_2fpparameters_2eproto;
+extern PROTOBUF_INTERNAL_EXPORT_protobuf_5ftypes_2fpparameters_2eproto ::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<0> scc_info_PForkSchedule_PActivation_protobuf_5ftypes_2fpparameters_2eproto;
+extern PROTOBUF_INTERNAL_EXPORT_protobuf_5ftypes_2fpparameters_2eproto ::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<2> scc_info_PReturnResult_protobuf_5ftypes_2fpparameters_2eproto;
+extern PROTOBUF_INTERNAL_EXPORT_protobuf_5ftypes_2fpparameters_2eproto ::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<2> scc_info_PReturnResult_PLog_protobuf_5ftypes_2fpparameters_2eproto;
+extern PROTOBUF_INTERNAL_EXPORT_protobuf_5ftypes_2fpparameters_2eproto ::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<0> scc_info_PReturnResult_PU256_protobuf_5ftypes_2fpparameters_2eproto;
 namespace top {
 namespace tvm_engine {
 namespace parameters {
 class PReturnResult_PU256DefaultTypeInternal {
  public:
-  ::google::protobuf::internal::ExplicitlyConstructed<PReturnResult_PU256>
-      _instance;
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PReturnResult_PU256> _instance;
 } _PReturnResult_PU256_default_instance_;
 class PReturnResult_PLogDefaultTypeInternal {
  public:
-  ::google::protobuf::internal::ExplicitlyConstructed<PReturnResult_PLog>
-      _instance;
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PReturnResult_PLog> _instance;
 } _PReturnResult_PLog_default_instance_;
 class PReturnResultDefaultTypeInternal {
  public:
-  ::google::protobuf::internal::ExplicitlyConstructed<PReturnResult>
-      _instance;
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PReturnResult> _instance;
 } _PReturnResult_default_instance_;
+class PStateDiffResultDefaultTypeInternal {
+ public:
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PStateDiffResult> _instance;
+} _PStateDiffResult_default_instance_;
+class PCallArgs_PAccessListItemDefaultTypeInternal {
+ public:
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PCallArgs_PAccessListItem> _instance;
+} _PCallArgs_PAccessListItem_default_instance_;
 class PCallArgsDefaultTypeInternal {
  public:
-  ::google::protobuf::internal::ExplicitlyConstructed<PCallArgs>
-      _instance;
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PCallArgs> _instance;
 } _PCallArgs_default_instance_;
+class PEstimateGasResultDefaultTypeInternal {
+ public:
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PEstimateGasResult> _instance;
+} _PEstimateGasResult_default_instance_;
+class PAccessListResultDefaultTypeInternal {
+ public:
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PAccessListResult> _instance;
+} _PAccessListResult_default_instance_;
+class PDeployAddressResultDefaultTypeInternal {
+ public:
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PDeployAddressResult> _instance;
+} _PDeployAddressResult_default_instance_;
+class PForkSchedule_PActivationDefaultTypeInternal {
+ public:
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PForkSchedule_PActivation> _instance;
+} _PForkSchedule_PActivation_default_instance_;
+class PForkScheduleDefaultTypeInternal {
+ public:
+  ::PROTOBUF_NAMESPACE_ID::internal::ExplicitlyConstructed<PForkSchedule> _instance;
+} _PForkSchedule_default_instance_;
 }  // namespace parameters
 }  // namespace tvm_engine
 }  // namespace top
-namespace protobuf_protobuf_5ftypes_2fpparameters_2eproto {
-static void InitDefaultsPReturnResult_PU256() {
+static void InitDefaultsscc_info_PAccessListResult_protobuf_5ftypes_2fpparameters_2eproto() {
   GOOGLE_PROTOBUF_VERIFY_VERSION;
 
   {
-    void* ptr = &::top::tvm_engine::parameters::_PReturnResult_PU256_default_instance_;
-    new (ptr) ::top::tvm_engine::parameters::PReturnResult_PU256();
-    ::google::protobuf::internal::OnShutdownDestroyMessage(ptr);
+    void* ptr = &::top::tvm_engine::parameters::_PAccessListResult_default_instance_;
+    new (ptr) ::top::tvm_engine::parameters::PAccessListResult();
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
   }
-  ::top::tvm_engine::parameters::PReturnResult_PU256::InitAsDefaultInstance();
+  ::top::tvm_engine::parameters::PAccessListResult::InitAsDefaultInstance();
 }
 
-::google::protobuf::internal::SCCInfo<0> scc_info_PReturnResult_PU256 =
-    {{ATOMIC_VAR_INIT(::google::protobuf::internal::SCCInfoBase::kUninitialized), 0, InitDefaultsPReturnResult_PU256}, {}};
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<1> scc_info_PAccessListResult_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 1, 0, InitDefaultsscc_info_PAccessListResult_protobuf_5ftypes_2fpparameters_2eproto}, {
+      &scc_info_PCallArgs_PAccessListItem_protobuf_5ftypes_2fpparameters_2eproto.base,}};
 
-static void InitDefaultsPReturnResult_PLog() {
+static void InitDefaultsscc_info_PCallArgs_protobuf_5ftypes_2fpparameters_2eproto() {
   GOOGLE_PROTOBUF_VERIFY_VERSION;
 
   {
-    void* ptr = &::top::tvm_engine::parameters::_PReturnResult_PLog_default_instance_;
-    new (ptr) ::top::tvm_engine::parameters::PReturnResult_PLog();
-    ::google::protobuf::internal::OnShutdownDestroyMessage(ptr);
+    void* ptr = &::top::tvm_engine::parameters::_PCallArgs_default_instance_;
+    new (ptr) ::top::tvm_engine::parameters::PCallArgs();
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
   }
-  ::top::tvm_engine::parameters::PReturnResult_PLog::InitAsDefaultInstance();
+  ::top::tvm_engine::parameters::PCallArgs::InitAsDefaultInstance();
+}
+
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<3> scc_info_PCallArgs_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 3, 0, InitDefaultsscc_info_PCallArgs_protobuf_5ftypes_2fpparameters_2eproto}, {
+      &scc_info_PAddress_protobuf_5ftypes_2fpbasic_2eproto.base,
+      &scc_info_PCallArgs_PAccessListItem_protobuf_5ftypes_2fpparameters_2eproto.base,
+      &scc_info_PReturnResult_PU256_protobuf_5ftypes_2fpparameters_2eproto.base,}};
+
+static void InitDefaultsscc_info_PCallArgs_PAccessListItem_protobuf_5ftypes_2fpparameters_2eproto() {
+  GOOGLE_PROTOBUF_VERIFY_VERSION;
+
+  {
+    void* ptr = &::top::tvm_engine::parameters::_PCallArgs_PAccessListItem_default_instance_;
+    new (ptr) ::top::tvm_engine::parameters::PCallArgs_PAccessListItem();
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
+  }
+  ::top::tvm_engine::parameters::PCallArgs_PAccessListItem::InitAsDefaultInstance();
+}
+
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<1> scc_info_PCallArgs_PAccessListItem_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 1, 0, InitDefaultsscc_info_PCallArgs_PAccessListItem_protobuf_5ftypes_2fpparameters_2eproto}, {
+      &scc_info_PAddress_protobuf_5ftypes_2fpbasic_2eproto.base,}};
+
+static void InitDefaultsscc_info_PDeployAddressResult_protobuf_5ftypes_2fpparameters_2eproto() {
+  GOOGLE_PROTOBUF_VERIFY_VERSION;
+
+  {
+    void* ptr = &::top::tvm_engine::parameters::_PDeployAddressResult_default_instance_;
+    new (ptr) ::top::tvm_engine::parameters::PDeployAddressResult();
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
+  }
+  ::top::tvm_engine::parameters::PDeployAddressResult::InitAsDefaultInstance();
+}
+
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<1> scc_info_PDeployAddressResult_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 1, 0, InitDefaultsscc_info_PDeployAddressResult_protobuf_5ftypes_2fpparameters_2eproto}, {
+      &scc_info_PAddress_protobuf_5ftypes_2fpbasic_2eproto.base,}};
+
+static void InitDefaultsscc_info_PEstimateGasResult_protobuf_5ftypes_2fpparameters_2eproto() {
+  GOOGLE_PROTOBUF_VERIFY_VERSION;
+
+  {
+    void* ptr = &::top::tvm_engine::parameters::_PEstimateGasResult_default_instance_;
+    new (ptr) ::top::tvm_engine::parameters::PEstimateGasResult();
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
+  }
+  ::top::tvm_engine::parameters::PEstimateGasResult::InitAsDefaultInstance();
+}
+
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<0> scc_info_PEstimateGasResult_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 0, 0, InitDefaultsscc_info_PEstimateGasResult_protobuf_5ftypes_2fpparameters_2eproto}, {}};
+
+static void InitDefaultsscc_info_PForkSchedule_protobuf_5ftypes_2fpparameters_2eproto() {
+  GOOGLE_PROTOBUF_VERIFY_VERSION;
+
+  {
+    void* ptr = &::top::tvm_engine::parameters::_PForkSchedule_default_instance_;
+    new (ptr) ::top::tvm_engine::parameters::PForkSchedule();
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
+  }
+  ::top::tvm_engine::parameters::PForkSchedule::InitAsDefaultInstance();
+}
+
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<1> scc_info_PForkSchedule_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 1, 0, InitDefaultsscc_info_PForkSchedule_protobuf_5ftypes_2fpparameters_2eproto}, {
+      &scc_info_PForkSchedule_PActivation_protobuf_5ftypes_2fpparameters_2eproto.base,}};
+
+static void InitDefaultsscc_info_PForkSchedule_PActivation_protobuf_5ftypes_2fpparameters_2eproto() {
+  GOOGLE_PROTOBUF_VERIFY_VERSION;
+
+  {
+    void* ptr = &::top::tvm_engine::parameters::_PForkSchedule_PActivation_default_instance_;
+    new (ptr) ::top::tvm_engine::parameters::PForkSchedule_PActivation();
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
+  }
+  ::top::tvm_engine::parameters::PForkSchedule_PActivation::InitAsDefaultInstance();
 }
 
-::google::protobuf::internal::SCCInfo<2> scc_info_PReturnResult_PLog =
-    {{ATOMIC_VAR_INIT(::google::protobuf::internal::SCCInfoBase::kUninitialized), 2, InitDefaultsPReturnResult_PLog}, {
-      &protobuf_protobuf_5ftypes_2fpbasic_2eproto::scc_info_PAddress.base,
-      &protobuf_protobuf_5ftypes_2fpparameters_2eproto::scc_info_PReturnResult_PU256.base,}};
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<0> scc_info_PForkSchedule_PActivation_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 0, 0, InitDefaultsscc_info_PForkSchedule_PActivation_protobuf_5ftypes_2fpparameters_2eproto}, {}};
 
-static void InitDefaultsPReturnResult() {
+static void InitDefaultsscc_info_PReturnResult_protobuf_5ftypes_2fpparameters_2eproto() {
   GOOGLE_PROTOBUF_VERIFY_VERSION;
 
   {
     void* ptr = &::top::tvm_engine::parameters::_PReturnResult_default_instance_;
     new (ptr) ::top::tvm_engine::parameters::PReturnResult();
-    ::google::protobuf::internal::OnShutdownDestroyMessage(ptr);
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
   }
   ::top::tvm_engine::parameters::PReturnResult::InitAsDefaultInstance();
 }
 
-::google::protobuf::internal::SCCInfo<1> scc_info_PReturnResult =
-    {{ATOMIC_VAR_INIT(::google::protobuf::internal::SCCInfoBase::kUninitialized), 1, InitDefaultsPReturnResult}, {
-      &protobuf_protobuf_5ftypes_2fpparameters_2eproto::scc_info_PReturnResult_PLog.base,}};
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<2> scc_info_PReturnResult_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 2, 0, InitDefaultsscc_info_PReturnResult_protobuf_5ftypes_2fpparameters_2eproto}, {
+      &scc_info_PReturnResult_PLog_protobuf_5ftypes_2fpparameters_2eproto.base,
+      &scc_info_PAddress_protobuf_5ftypes_2fpbasic_2eproto.base,}};
 
-static void InitDefaultsPCallArgs() {
+static void InitDefaultsscc_info_PReturnResult_PLog_protobuf_5ftypes_2fpparameters_2eproto() {
   GOOGLE_PROTOBUF_VERIFY_VERSION;
 
   {
-    void* ptr = &::top::tvm_engine::parameters::_PCallArgs_default_instance_;
-    new (ptr) ::top::tvm_engine::parameters::PCallArgs();
-    ::google::protobuf::internal::OnShutdownDestroyMessage(ptr);
+    void* ptr = &::top::tvm_engine::parameters::_PReturnResult_PLog_default_instance_;
+    new (ptr) ::top::tvm_engine::parameters::PReturnResult_PLog();
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
   }
-  ::top::tvm_engine::parameters::PCallArgs::InitAsDefaultInstance();
+  ::top::tvm_engine::parameters::PReturnResult_PLog::InitAsDefaultInstance();
+}
+
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<2> scc_info_PReturnResult_PLog_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 2, 0, InitDefaultsscc_info_PReturnResult_PLog_protobuf_5ftypes_2fpparameters_2eproto}, {
+      &scc_info_PAddress_protobuf_5ftypes_2fpbasic_2eproto.base,
+      &scc_info_PReturnResult_PU256_protobuf_5ftypes_2fpparameters_2eproto.base,}};
+
+static void InitDefaultsscc_info_PReturnResult_PU256_protobuf_5ftypes_2fpparameters_2eproto() {
+  GOOGLE_PROTOBUF_VERIFY_VERSION;
+
+  {
+    void* ptr = &::top::tvm_engine::parameters::_PReturnResult_PU256_default_instance_;
+    new (ptr) ::top::tvm_engine::parameters::PReturnResult_PU256();
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
+  }
+  ::top::tvm_engine::parameters::PReturnResult_PU256::InitAsDefaultInstance();
 }
 
-::google::protobuf::internal::SCCInfo<1> scc_info_PCallArgs =
-    {{ATOMIC_VAR_INIT(::google::protobuf::internal::SCCInfoBase::kUninitialized), 1, InitDefaultsPCallArgs}, {
-      &protobuf_protobuf_5ftypes_2fpbasic_2eproto::scc_info_PAddress.base,}};
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<0> scc_info_PReturnResult_PU256_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 0, 0, InitDefaultsscc_info_PReturnResult_PU256_protobuf_5ftypes_2fpparameters_2eproto}, {}};
 
-void InitDefaults() {
-  ::google::protobuf::internal::InitSCC(&scc_info_PReturnResult_PU256.base);
-  ::google::protobuf::internal::InitSCC(&scc_info_PReturnResult_PLog.base);
-  ::google::protobuf::internal::InitSCC(&scc_info_PReturnResult.base);
-  ::google::protobuf::internal::InitSCC(&scc_info_PCallArgs.base);
+static void InitDefaultsscc_info_PStateDiffResult_protobuf_5ftypes_2fpparameters_2eproto() {
+  GOOGLE_PROTOBUF_VERIFY_VERSION;
+
+  {
+    void* ptr = &::top::tvm_engine::parameters::_PStateDiffResult_default_instance_;
+    new (ptr) ::top::tvm_engine::parameters::PStateDiffResult();
+    ::PROTOBUF_NAMESPACE_ID::internal::OnShutdownDestroyMessage(ptr);
+  }
+  ::top::tvm_engine::parameters::PStateDiffResult::InitAsDefaultInstance();
 }
 
-::google::protobuf::Metadata file_level_metadata[4];
+::PROTOBUF_NAMESPACE_ID::internal::SCCInfo<1> scc_info_PStateDiffResult_protobuf_5ftypes_2fpparameters_2eproto =
+    {{ATOMIC_VAR_INIT(::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase::kUninitialized), 1, 0, InitDefaultsscc_info_PStateDiffResult_protobuf_5ftypes_2fpparameters_2eproto}, {
+      &scc_info_PReturnResult_protobuf_5ftypes_2fpparameters_2eproto.base,}};
 
-const ::google::protobuf::uint32 TableStruct::offsets[] GOOGLE_PROTOBUF_ATTRIBUTE_SECTION_VARIABLE(protodesc_cold) = {
+static ::PROTOBUF_NAMESPACE_ID::Metadata file_level_metadata_protobuf_5ftypes_2fpparameters_2eproto[11];
+static const ::PROTOBUF_NAMESPACE_ID::EnumDescriptor* file_level_enum_descriptors_protobuf_5ftypes_2fpparameters_2eproto[2];
+static constexpr ::PROTOBUF_NAMESPACE_ID::ServiceDescriptor const** file_level_service_descriptors_protobuf_5ftypes_2fpparameters_2eproto = nullptr;
+
+const ::PROTOBUF_NAMESPACE_ID::uint32 TableStruct_protobuf_5ftypes_2fpparameters_2eproto::offsets[] PROTOBUF_SECTION_VARIABLE(protodesc_cold) = {
+  ~0u,  // no _has_bits_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PU256, _internal_metadata_),
+  ~0u,  // no _extensions_
+  ~0u,  // no _oneof_case_
+  ~0u,  // no _weak_field_map_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PU256, data_),
+  ~0u,  // no _has_bits_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PLog, _internal_metadata_),
+  ~0u,  // no _extensions_
+  ~0u,  // no _oneof_case_
+  ~0u,  // no _weak_field_map_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PLog, address_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PLog, topics_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PLog, data_),
+  ~0u,  // no _has_bits_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, _internal_metadata_),
+  ~0u,  // no _extensions_
+  ~0u,  // no _oneof_case_
+  ~0u,  // no _weak_field_map_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, status_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, status_data_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, gas_used_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, logs_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, effective_gas_price_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, contract_address_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, code_hash_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, code_size_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, salt_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, error_message_),
+  ~0u,  // no _has_bits_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PStateDiffResult, _internal_metadata_),
+  ~0u,  // no _extensions_
+  ~0u,  // no _oneof_case_
+  ~0u,  // no _weak_field_map_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PStateDiffResult, result_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PStateDiffResult, state_diff_),
+  ~0u,  // no _has_bits_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs_PAccessListItem, _internal_metadata_),
+  ~0u,  // no _extensions_
+  ~0u,  // no _oneof_case_
+  ~0u,  // no _weak_field_map_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs_PAccessListItem, address_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs_PAccessListItem, storage_keys_),
+  ~0u,  // no _has_bits_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, _internal_metadata_),
+  ~0u,  // no _extensions_
+  ~0u,  // no _oneof_case_
+  ~0u,  // no _weak_field_map_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, sender_address_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, recver_address_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, value_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, input_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, gas_limit_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, gas_price_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, max_fee_per_gas_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, max_priority_fee_per_gas_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, access_list_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, nonce_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, chain_id_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, salt_),
+  ~0u,  // no _has_bits_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PEstimateGasResult, _internal_metadata_),
+  ~0u,  // no _extensions_
+  ~0u,  // no _oneof_case_
+  ~0u,  // no _weak_field_map_
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PEstimateGasResult, status_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PEstimateGasResult, status_data_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PEstimateGasResult, gas_used_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PEstimateGasResult, gas_limit_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PEstimateGasResult, error_message_),
   ~0u,  // no _has_bits_
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PU256, _internal_metadata_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PAccessListResult, _internal_metadata_),
   ~0u,  // no _extensions_
   ~0u,  // no _oneof_case_
   ~0u,  // no _weak_field_map_
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PU256, data_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PAccessListResult, status_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PAccessListResult, status_data_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PAccessListResult, gas_used_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PAccessListResult, access_list_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PAccessListResult, error_message_),
   ~0u,  // no _has_bits_
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PLog, _internal_metadata_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PDeployAddressResult, _internal_metadata_),
   ~0u,  // no _extensions_
   ~0u,  // no _oneof_case_
   ~0u,  // no _weak_field_map_
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PLog, address_),
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PLog, topics_),
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult_PLog, data_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PDeployAddressResult, status_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PDeployAddressResult, status_data_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PDeployAddressResult, contract_address_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PDeployAddressResult, salt_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PDeployAddressResult, error_message_),
   ~0u,  // no _has_bits_
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, _internal_metadata_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PForkSchedule_PActivation, _internal_metadata_),
   ~0u,  // no _extensions_
   ~0u,  // no _oneof_case_
   ~0u,  // no _weak_field_map_
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, status_),
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, status_data_),
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, gas_used_),
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PReturnResult, logs_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PForkSchedule_PActivation, height_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PForkSchedule_PActivation, hardfork_),
   ~0u,  // no _has_bits_
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, _internal_metadata_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PForkSchedule, _internal_metadata_),
   ~0u,  // no _extensions_
   ~0u,  // no _oneof_case_
   ~0u,  // no _weak_field_map_
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, sender_address_),
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, recver_address_),
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, value_),
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, input_),
-  GOOGLE_PROTOBUF_GENERATED_MESSAGE_FIELD_OFFSET(::top::tvm_engine::parameters::PCallArgs, gas_limit_),
+  PROTOBUF_FIELD_OFFSET(::top::tvm_engine::parameters::PForkSchedule, activations_),
 };
-static const ::google::protobuf::internal::MigrationSchema schemas[] GOOGLE_PROTOBUF_ATTRIBUTE_SECTION_VARIABLE(protodesc_cold) = {
+static const ::PROTOBUF_NAMESPACE_ID::internal::MigrationSchema schemas[] PROTOBUF_SECTION_VARIABLE(protodesc_cold) = {
   { 0, -1, sizeof(::top::tvm_engine::parameters::PReturnResult_PU256)},
   { 6, -1, sizeof(::top::tvm_engine::parameters::PReturnResult_PLog)},
   { 14, -1, sizeof(::top::tvm_engine::parameters::PReturnResult)},
-  { 23, -1, sizeof(::top::tvm_engine::parameters::PCallArgs)},
+  { 29, -1, sizeof(::top::tvm_engine::parameters::PStateDiffResult)},
+  { 36, -1, sizeof(::top::tvm_engine::parameters::PCallArgs_PAccessListItem)},
+  { 43, -1, sizeof(::top::tvm_engine::parameters::PCallArgs)},
+  { 60, -1, sizeof(::top::tvm_engine::parameters::PEstimateGasResult)},
+  { 70, -1, sizeof(::top::tvm_engine::parameters::PAccessListResult)},
+  { 80, -1, sizeof(::top::tvm_engine::parameters::PDeployAddressResult)},
+  { 90, -1, sizeof(::top::tvm_engine::parameters::PForkSchedule_PActivation)},
+  { 97, -1, sizeof(::top::tvm_engine::parameters::PForkSchedule)},
+};
+
+static ::PROTOBUF_NAMESPACE_ID::Message const * const file_default_instances[] = {
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PReturnResult_PU256_default_instance_),
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PReturnResult_PLog_default_instance_),
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PReturnResult_default_instance_),
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PStateDiffResult_default_instance_),
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PCallArgs_PAccessListItem_default_instance_),
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PCallArgs_default_instance_),
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PEstimateGasResult_default_instance_),
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PAccessListResult_default_instance_),
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PDeployAddressResult_default_instance_),
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PForkSchedule_PActivation_default_instance_),
+  reinterpret_cast<const ::PROTOBUF_NAMESPACE_ID::Message*>(&::top::tvm_engine::parameters::_PForkSchedule_default_instance_),
 };
 
-static ::google::protobuf::Message const * const file_default_instances[] = {
-  reinterpret_cast<const ::google::protobuf::Message*>(&::top::tvm_engine::parameters::_PReturnResult_PU256_default_instance_),
-  reinterpret_cast<const ::google::protobuf::Message*>(&::top::tvm_engine::parameters::_PReturnResult_PLog_default_instance_),
-  reinterpret_cast<const ::google::protobuf::Message*>(&::top::tvm_engine::parameters::_PReturnResult_default_instance_),
-  reinterpret_cast<const ::google::protobuf::Message*>(&::top::tvm_engine::parameters::_PCallArgs_default_instance_),
+const char descriptor_table_protodef_protobuf_5ftypes_2fpparameters_2eproto[] PROTOBUF_SECTION_VARIABLE(protodesc_cold) =
+  "\n protobuf_types/pparameters.proto\022\031top."
+  "tvm_engine.parameters\032\033protobuf_types/pb"
+  "asic.proto\"\354\003\n\rPReturnResult\0226\n\006status\030\001"
+  " \001(\0162&.top.tvm_engine.parameters.PStatus"
+  "Code\022\023\n\013status_data\030\002 \001(\014\022\020\n\010gas_used\030\003 "
+  "\001(\004\022;\n\004logs\030\004 \003(\0132-.top.tvm_engine.param"
+  "eters.PReturnResult.PLog\022\033\n\023effective_ga"
+  "s_price\030\005 \001(\004\0228\n\020contract_address\030\006 \001(\0132"
+  "\036.top.tvm_engine.basic.PAddress\022\021\n\tcode_"
+  "hash\030\007 \001(\014\022\021\n\tcode_size\030\010 \001(\004\022\014\n\004salt\030\t "
+  "\001(\014\022\025\n\rerror_message\030\n \001(\t\032\025\n\005PU256\022\014\n\004d"
+  "ata\030\001 \001(\014\032\205\001\n\004PLog\022/\n\007address\030\001 \001(\0132\036.to"
+  "p.tvm_engine.basic.PAddress\022>\n\006topics\030\002 "
+  "\003(\0132..top.tvm_engine.parameters.PReturnR"
+  "esult.PU256\022\014\n\004data\030\003 \001(\014\"`\n\020PStateDiffR"
+  "esult\0228\n\006result\030\001 \001(\0132(.top.tvm_engine.p"
+  "arameters.PReturnResult\022\022\n\nstate_diff\030\002 "
+  "\001(\t\"\376\003\n\tPCallArgs\0226\n\016sender_address\030\001 \001("
+  "\0132\036.top.tvm_engine.basic.PAddress\0226\n\016rec"
+  "ver_address\030\002 \001(\0132\036.top.tvm_engine.basic"
+  ".PAddress\022\r\n\005value\030\003 \001(\004\022\r\n\005input\030\004 \001(\014\022"
+  "\021\n\tgas_limit\030\005 \001(\004\022\021\n\tgas_price\030\006 \001(\004\022\027\n"
+  "\017max_fee_per_gas\030\007 \001(\004\022 \n\030max_priority_f"
+  "ee_per_gas\030\010 \001(\004\022I\n\013access_list\030\t \003(\01324."
+  "top.tvm_engine.parameters.PCallArgs.PAcc"
+  "essListItem\022=\n\005nonce\030\n \001(\0132..top.tvm_eng"
+  "ine.parameters.PReturnResult.PU256\022\020\n\010ch"
+  "ain_id\030\013 \001(\004\022\014\n\004salt\030\014 \001(\014\032X\n\017PAccessLis"
+  "tItem\022/\n\007address\030\001 \001(\0132\036.top.tvm_engine."
+  "basic.PAddress\022\024\n\014storage_keys\030\002 \003(\014\"\235\001\n"
+  "\022PEstimateGasResult\0226\n\006status\030\001 \001(\0162&.to"
+  "p.tvm_engine.parameters.PStatusCode\022\023\n\013s"
+  "tatus_data\030\002 \001(\014\022\020\n\010gas_used\030\003 \001(\004\022\021\n\tga"
+  "s_limit\030\004 \001(\004\022\025\n\rerror_message\030\005 \001(\t\"\324\001\n"
+  "\021PAccessListResult\0226\n\006status\030\001 \001(\0162&.top"
+  ".tvm_engine.parameters.PStatusCode\022\023\n\013st"
+  "atus_data\030\002 \001(\014\022\020\n\010gas_used\030\003 \001(\004\022I\n\013acc"
+  "ess_list\030\004 \003(\01324.top.tvm_engine.paramete"
+  "rs.PCallArgs.PAccessListItem\022\025\n\rerror_me"
+  "ssage\030\005 \001(\t\"\302\001\n\024PDeployAddressResult\0226\n\006"
+  "status\030\001 \001(\0162&.top.tvm_engine.parameters"
+  ".PStatusCode\022\023\n\013status_data\030\002 \001(\014\0228\n\020con"
+  "tract_address\030\003 \001(\0132\036.top.tvm_engine.bas"
+  "ic.PAddress\022\014\n\004salt\030\004 \001(\014\022\025\n\rerror_messa"
+  "ge\030\005 \001(\t\"\261\001\n\rPForkSchedule\022I\n\013activation"
+  "s\030\001 \003(\01324.top.tvm_engine.parameters.PFor"
+  "kSchedule.PActivation\032U\n\013PActivation\022\016\n\006"
+  "height\030\001 \001(\004\0226\n\010hardfork\030\002 \001(\0162$.top.tvm"
+  "_engine.parameters.PHardfork*\265\005\n\013PStatus"
+  "Code\022\013\n\007SUCCEED\020\000\022\n\n\006REVERT\020\001\022\016\n\nOUT_OF_"
+  "GAS\020\002\022\017\n\013OUT_OF_FUND\020\003\022\021\n\rOUT_OF_OFFSET\020"
+  "\004\022\021\n\rNONCE_TOO_LOW\020d\022\022\n\016NONCE_TOO_HIGH\020e"
+  "\022\025\n\021CHAIN_ID_MISMATCH\020f\022\031\n\025INTRINSIC_GAS"
+  "_TOO_LOW\020g\022\026\n\022INSUFFICIENT_FUNDS\020h\022\027\n\023IN"
+  "IT_CODE_TOO_LARGE\020i\022\023\n\017FEE_CAP_TOO_LOW\020j"
+  "\022\025\n\021TIP_ABOVE_FEE_CAP\020k\022\027\n\023SALT_TABLE_MI"
+  "SMATCH\020l\022\022\n\016MALFORMED_ARGS\020m\022\024\n\017STACK_UN"
+  "DERFLOW\020\310\001\022\023\n\016STACK_OVERFLOW\020\311\001\022\021\n\014INVAL"
+  "ID_JUMP\020\312\001\022\022\n\rINVALID_RANGE\020\313\001\022\027\n\022DESIGN"
+  "ATED_INVALID\020\314\001\022\022\n\rCALL_TOO_DEEP\020\315\001\022\025\n\020C"
+  "REATE_COLLISION\020\316\001\022\032\n\025CREATE_CONTRACT_LI"
+  "MIT\020\317\001\022\021\n\014INVALID_CODE\020\320\001\022\021\n\014PC_UNDERFLO"
+  "W\020\321\001\022\021\n\014CREATE_EMPTY\020\322\001\022\024\n\017EVM_ERROR_OTH"
+  "ER\020\253\002\022\022\n\rNOT_SUPPORTED\020\254\002\022\030\n\023UNHANDLED_I"
+  "NTERRUPT\020\255\002\022\030\n\023CALL_ERROR_AS_FATAL\020\256\002\022\024\n"
+  "\017EVM_FATAL_OTHER\020\217\003\022\027\n\022CROSS_TABLE_CREAT"
+  "E\020\220\003*\?\n\tPHardfork\022\n\n\006LONDON\020\000\022\014\n\010FRONTIE"
+  "R\020\001\022\014\n\010ISTANBUL\020\002\022\n\n\006BERLIN\020\003b\006proto3"
+  ;
+static const ::PROTOBUF_NAMESPACE_ID::internal::DescriptorTable*const descriptor_table_protobuf_5ftypes_2fpparameters_2eproto_deps[1] = {
+  &::descriptor_table_protobuf_5ftypes_2fpbasic_2eproto,
+};
+static ::PROTOBUF_NAMESPACE_ID::internal::SCCInfoBase*const descriptor_table_protobuf_5ftypes_2fpparameters_2eproto_sccs[11] = {
+  &scc_info_PAccessListResult_protobuf_5ftypes_2fpparameters_2eproto.base,
+  &scc_info_PCallArgs_protobuf_5ftypes_2fpparameters_2eproto.base,
+  &scc_info_PCallArgs_PAccessListItem_protobuf_5ftypes_2fpparameters_2eproto.base,
+  &scc_info_PDeployAddressResult_protobuf_5ftypes_2fpparameters_2eproto.base,
+  &scc_info_PEstimateGasResult_protobuf_5ftypes_2fpparameters_2eproto.base,
+  &scc_info_PForkSchedule_protobuf_5ftypes_2fpparameters_2eproto.base,
+  &scc_info_PForkSchedule_PActivation_protobuf_5ftypes_2fpparameters_2eproto.base,
+  &scc_info_PReturnResult_protobuf_5ftypes_2fpparameters_2eproto.base,
+  &scc_info_PReturnResult_PLog_protobuf_5ftypes_2fpparameters_2eproto.base,
+  &scc_info_PReturnResult_PU256_protobuf_5ftypes_2fpparameters_2eproto.base,
+  &scc_info_PStateDiffResult_protobuf_5ftypes_2fpparameters_2eproto.base,
+};
+static ::PROTOBUF_NAMESPACE_ID::internal::once_flag descriptor_table_protobuf_5ftypes_2fpparameters_2eproto_once;
+static bool descriptor_table_protobuf_5ftypes_2fpparameters_2eproto_initialized = false;
+const ::PROTOBUF_NAMESPACE_ID::internal::DescriptorTable descriptor_table_protobuf_5ftypes_2fpparameters_2eproto = {
+  &descriptor_table_protobuf_5ftypes_2fpparameters_2eproto_initialized, descriptor_table_protodef_protobuf_5ftypes_2fpparameters_2eproto, "protobuf_types/pparameters.proto", 2717,
+  &descriptor_table_protobuf_5ftypes_2fpparameters_2eproto_once, descriptor_table_protobuf_5ftypes_2fpparameters_2eproto_sccs, descriptor_table_protobuf_5ftypes_2fpparameters_2eproto_deps, 11, 1,
+  schemas, file_default_instances, TableStruct_protobuf_5ftypes_2fpparameters_2eproto::offsets,
+  file_level_metadata_protobuf_5ftypes_2fpparameters_2eproto, 11, file_level_enum_descriptors_protobuf_5ftypes_2fpparameters_2eproto, file_level_service_descriptors_protobuf_5ftypes_2fpparameters_2eproto,
 };
 
-void protobuf_AssignDescriptors() {
-  AddDescriptors();
-  AssignDescriptors(
-      "protobuf_types/pparameters.proto", schemas, file_default_instances, TableStruct::offsets,
-      file_level_metadata, NULL, NULL);
-}
-
-void protobuf_AssignDescriptorsOnce() {
-  static ::google::protobuf::internal::once_flag once;
-  ::google::protobuf::internal::call_once(once, protobuf_AssignDescriptors);
-}
-
-void protobuf_RegisterTypes(const ::std::string&) GOOGLE_PROTOBUF_ATTRIBUTE_COLD;
-void protobuf_RegisterTypes(const ::std::string&) {
-  protobuf_AssignDescriptorsOnce();
-  ::google::protobuf::internal::RegisterAllTypes(file_level_metadata, 4);
-}
-
-void AddDescriptorsImpl() {
-  InitDefaults();
-  static const char descriptor[] GOOGLE_PROTOBUF_ATTRIBUTE_SECTION_VARIABLE(protodesc_cold) = {
-      "\n protobuf_types/pparameters.proto\022\031top."
-      "tvm_engine.parameters\032\033protobuf_types/pb"
-      "asic.proto\"\242\002\n\rPReturnResult\022\016\n\006status\030\001"
-      " \001(\r\022\023\n\013status_data\030\002 \001(\014\022\020\n\010gas_used\030\003 "
-      "\001(\004\022;\n\004logs\030\004 \003(\0132-.top.tvm_engine.param"
-      "eters.PReturnResult.PLog\032\025\n\005PU256\022\014\n\004dat"
-      "a\030\001 \001(\014\032\205\001\n\004PLog\022/\n\007address\030\001 \001(\0132\036.top."
-      "tvm_engine.basic.PAddress\022>\n\006topics\030\002 \003("
-      "\0132..top.tvm_engine.parameters.PReturnRes"
-      "ult.PU256\022\014\n\004data\030\003 \001(\014\"\254\001\n\tPCallArgs\0226\n"
-      "\016sender_address\030\001 \001(\0132\036.top.tvm_engine.b"
-      "asic.PAddress\0226\n\016recver_address\030\002 \001(\0132\036."
-      "top.tvm_engine.basic.PAddress\022\r\n\005value\030\003"
-      " \001(\004\022\r\n\005input\030\004 \001(\014\022\021\n\tgas_limit\030\005 \001(\004b\006"
-      "proto3"
-  };
-  ::google::protobuf::DescriptorPool::InternalAddGeneratedFile(
-      descriptor, 566);
-  ::google::protobuf::MessageFactory::InternalRegisterGeneratedFile(
-    "protobuf_types/pparameters.proto", &protobuf_RegisterTypes);
-  ::protobuf_protobuf_5ftypes_2fpbasic_2eproto::AddDescriptors();
-}
-
-void AddDescriptors() {
-  static ::google::protobuf::internal::once_flag once;
-  ::google::protobuf::internal::call_once(once, AddDescriptorsImpl);
-}
-// Force AddDescriptors() to be called at dynamic initialization time.
-struct StaticDescriptorInitializer {
-  StaticDescriptorInitializer() {
-    AddDescriptors();
-  }
-} static_descriptor_initializer;
-}  // namespace protobuf_protobuf_5ftypes_2fpparameters_2eproto
+// Force running AddDescriptors() at dynamic initialization time.
+static bool dynamic_init_dummy_protobuf_5ftypes_2fpparameters_2eproto = (  ::PROTOBUF_NAMESPACE_ID::internal::AddDescriptors(&descriptor_table_protobuf_5ftypes_2fpparameters_2eproto), true);
 namespace top {
 namespace tvm_engine {
 namespace parameters {
+const ::PROTOBUF_NAMESPACE_ID::EnumDescriptor* PStatusCode_descriptor() {
+  ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+  return file_level_enum_descriptors_protobuf_5ftypes_2fpparameters_2eproto[0];
+}
+bool PStatusCode_IsValid(int value) {
+  switch (value) {
+    case 0:
+    case 1:
+    case 2:
+    case 3:
+    case 4:
+    case 100:
+    case 101:
+    case 102:
+    case 103:
+    case 104:
+    case 105:
+    case 106:
+    case 107:
+    case 108:
+    case 109:
+    case 200:
+    case 201:
+    case 202:
+    case 203:
+    case 204:
+    case 205:
+    case 206:
+    case 207:
+    case 208:
+    case 209:
+    case 210:
+    case 299:
+    case 300:
+    case 301:
+    case 302:
+    case 399:
+    case 400:
+      return true;
+    default:
+      return false;
+  }
+}
+
+const ::PROTOBUF_NAMESPACE_ID::EnumDescriptor* PHardfork_descriptor() {
+  ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+  return file_level_enum_descriptors_protobuf_5ftypes_2fpparameters_2eproto[1];
+}
+bool PHardfork_IsValid(int value) {
+  switch (value) {
+    case 0:
+    case 1:
+    case 2:
+    case 3:
+      return true;
+    default:
+      return false;
+  }
+}
+
 
 // ===================================================================
 
 void PReturnResult_PU256::InitAsDefaultInstance() {
 }
-#if !defined(_MSC_VER) || _MSC_VER >= 1900
-const int PReturnResult_PU256::kDataFieldNumber;
-#endif  // !defined(_MSC_VER) || _MSC_VER >= 1900
+class PReturnResult_PU256::_Internal {
+ public:
+};
 
 PReturnResult_PU256::PReturnResult_PU256()
-  : ::google::protobuf::Message(), _internal_metadata_(NULL) {
-  ::google::protobuf::internal::InitSCC(
-      &protobuf_protobuf_5ftypes_2fpparameters_2eproto::scc_info_PReturnResult_PU256.base);
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
   SharedCtor();
   // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PReturnResult.PU256)
 }
 PReturnResult_PU256::PReturnResult_PU256(const PReturnResult_PU256& from)
-  : ::google::protobuf::Message(),
-      _internal_metadata_(NULL) {
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr) {
   _internal_metadata_.MergeFrom(from._internal_metadata_);
-  data_.UnsafeSetDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-  if (from.data().size() > 0) {
-    data_.AssignWithDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), from.data_);
+  data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_data().empty()) {
+    data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.data_);
   }
   // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PReturnResult.PU256)
 }
 
 void PReturnResult_PU256::SharedCtor() {
-  data_.UnsafeSetDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&scc_info_PReturnResult_PU256_protobuf_5ftypes_2fpparameters_2eproto.base);
+  data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
 
 PReturnResult_PU256::~PReturnResult_PU256() {
@@ -266,111 +569,78 @@ PReturnResult_PU256::~PReturnResult_PU256() {
 }
 
 void PReturnResult_PU256::SharedDtor() {
-  data_.DestroyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  data_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
 
 void PReturnResult_PU256::SetCachedSize(int size) const {
   _cached_size_.Set(size);
 }
-const ::google::protobuf::Descriptor* PReturnResult_PU256::descriptor() {
-  ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::protobuf_AssignDescriptorsOnce();
-  return ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::file_level_metadata[kIndexInFileMessages].descriptor;
-}
-
 const PReturnResult_PU256& PReturnResult_PU256::default_instance() {
-  ::google::protobuf::internal::InitSCC(&protobuf_protobuf_5ftypes_2fpparameters_2eproto::scc_info_PReturnResult_PU256.base);
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PReturnResult_PU256_protobuf_5ftypes_2fpparameters_2eproto.base);
   return *internal_default_instance();
 }
 
 
 void PReturnResult_PU256::Clear() {
 // @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PReturnResult.PU256)
-  ::google::protobuf::uint32 cached_has_bits = 0;
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   // Prevent compiler warnings about cached_has_bits being unused
   (void) cached_has_bits;
 
-  data_.ClearToEmptyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
   _internal_metadata_.Clear();
 }
 
-bool PReturnResult_PU256::MergePartialFromCodedStream(
-    ::google::protobuf::io::CodedInputStream* input) {
-#define DO_(EXPRESSION) if (!GOOGLE_PREDICT_TRUE(EXPRESSION)) goto failure
-  ::google::protobuf::uint32 tag;
-  // @@protoc_insertion_point(parse_start:top.tvm_engine.parameters.PReturnResult.PU256)
-  for (;;) {
-    ::std::pair<::google::protobuf::uint32, bool> p = input->ReadTagWithCutoffNoLastTag(127u);
-    tag = p.first;
-    if (!p.second) goto handle_unusual;
-    switch (::google::protobuf::internal::WireFormatLite::GetTagFieldNumber(tag)) {
+const char* PReturnResult_PU256::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
       // bytes data = 1;
-      case 1: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(10u /* 10 & 0xFF */)) {
-          DO_(::google::protobuf::internal::WireFormatLite::ReadBytes(
-                input, this->mutable_data()));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 10)) {
+          auto str = _internal_mutable_data();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
       default: {
       handle_unusual:
-        if (tag == 0) {
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
           goto success;
         }
-        DO_(::google::protobuf::internal::WireFormat::SkipField(
-              input, tag, _internal_metadata_.mutable_unknown_fields()));
-        break;
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
       }
-    }
-  }
+    }  // switch
+  }  // while
 success:
-  // @@protoc_insertion_point(parse_success:top.tvm_engine.parameters.PReturnResult.PU256)
-  return true;
+  return ptr;
 failure:
-  // @@protoc_insertion_point(parse_failure:top.tvm_engine.parameters.PReturnResult.PU256)
-  return false;
-#undef DO_
-}
-
-void PReturnResult_PU256::SerializeWithCachedSizes(
-    ::google::protobuf::io::CodedOutputStream* output) const {
-  // @@protoc_insertion_point(serialize_start:top.tvm_engine.parameters.PReturnResult.PU256)
-  ::google::protobuf::uint32 cached_has_bits = 0;
-  (void) cached_has_bits;
-
-  // bytes data = 1;
-  if (this->data().size() > 0) {
-    ::google::protobuf::internal::WireFormatLite::WriteBytesMaybeAliased(
-      1, this->data(), output);
-  }
-
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    ::google::protobuf::internal::WireFormat::SerializeUnknownFields(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()), output);
-  }
-  // @@protoc_insertion_point(serialize_end:top.tvm_engine.parameters.PReturnResult.PU256)
+  ptr = nullptr;
+  goto success;
+#undef CHK_
 }
 
-::google::protobuf::uint8* PReturnResult_PU256::InternalSerializeWithCachedSizesToArray(
-    bool deterministic, ::google::protobuf::uint8* target) const {
-  (void)deterministic; // Unused
+::PROTOBUF_NAMESPACE_ID::uint8* PReturnResult_PU256::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
   // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PReturnResult.PU256)
-  ::google::protobuf::uint32 cached_has_bits = 0;
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   (void) cached_has_bits;
 
   // bytes data = 1;
   if (this->data().size() > 0) {
-    target =
-      ::google::protobuf::internal::WireFormatLite::WriteBytesToArray(
-        1, this->data(), target);
+    target = stream->WriteBytesMaybeAliased(
+        1, this->_internal_data(), target);
   }
 
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    target = ::google::protobuf::internal::WireFormat::SerializeUnknownFieldsToArray(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()), target);
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
   }
   // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PReturnResult.PU256)
   return target;
@@ -380,32 +650,35 @@ size_t PReturnResult_PU256::ByteSizeLong() const {
 // @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PReturnResult.PU256)
   size_t total_size = 0;
 
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    total_size +=
-      ::google::protobuf::internal::WireFormat::ComputeUnknownFieldsSize(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()));
-  }
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
   // bytes data = 1;
   if (this->data().size() > 0) {
     total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::BytesSize(
-        this->data());
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_data());
   }
 
-  int cached_size = ::google::protobuf::internal::ToCachedSize(total_size);
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
+  }
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
   SetCachedSize(cached_size);
   return total_size;
 }
 
-void PReturnResult_PU256::MergeFrom(const ::google::protobuf::Message& from) {
+void PReturnResult_PU256::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
 // @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PReturnResult.PU256)
   GOOGLE_DCHECK_NE(&from, this);
   const PReturnResult_PU256* source =
-      ::google::protobuf::internal::DynamicCastToGenerated<const PReturnResult_PU256>(
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PReturnResult_PU256>(
           &from);
-  if (source == NULL) {
+  if (source == nullptr) {
   // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PReturnResult.PU256)
-    ::google::protobuf::internal::ReflectionOps::Merge(from, this);
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
   } else {
   // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PReturnResult.PU256)
     MergeFrom(*source);
@@ -416,16 +689,16 @@ void PReturnResult_PU256::MergeFrom(const PReturnResult_PU256& from) {
 // @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PReturnResult.PU256)
   GOOGLE_DCHECK_NE(&from, this);
   _internal_metadata_.MergeFrom(from._internal_metadata_);
-  ::google::protobuf::uint32 cached_has_bits = 0;
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   (void) cached_has_bits;
 
   if (from.data().size() > 0) {
 
-    data_.AssignWithDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), from.data_);
+    data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.data_);
   }
 }
 
-void PReturnResult_PU256::CopyFrom(const ::google::protobuf::Message& from) {
+void PReturnResult_PU256::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
 // @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PReturnResult.PU256)
   if (&from == this) return;
   Clear();
@@ -443,20 +716,15 @@ bool PReturnResult_PU256::IsInitialized() const {
   return true;
 }
 
-void PReturnResult_PU256::Swap(PReturnResult_PU256* other) {
-  if (other == this) return;
-  InternalSwap(other);
-}
 void PReturnResult_PU256::InternalSwap(PReturnResult_PU256* other) {
   using std::swap;
-  data_.Swap(&other->data_, &::google::protobuf::internal::GetEmptyStringAlreadyInited(),
-    GetArenaNoVirtual());
   _internal_metadata_.Swap(&other->_internal_metadata_);
+  data_.Swap(&other->data_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
 }
 
-::google::protobuf::Metadata PReturnResult_PU256::GetMetadata() const {
-  protobuf_protobuf_5ftypes_2fpparameters_2eproto::protobuf_AssignDescriptorsOnce();
-  return ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::file_level_metadata[kIndexInFileMessages];
+::PROTOBUF_NAMESPACE_ID::Metadata PReturnResult_PU256::GetMetadata() const {
+  return GetMetadataStatic();
 }
 
 
@@ -466,45 +734,47 @@ void PReturnResult_PLog::InitAsDefaultInstance() {
   ::top::tvm_engine::parameters::_PReturnResult_PLog_default_instance_._instance.get_mutable()->address_ = const_cast< ::top::tvm_engine::basic::PAddress*>(
       ::top::tvm_engine::basic::PAddress::internal_default_instance());
 }
+class PReturnResult_PLog::_Internal {
+ public:
+  static const ::top::tvm_engine::basic::PAddress& address(const PReturnResult_PLog* msg);
+};
+
+const ::top::tvm_engine::basic::PAddress&
+PReturnResult_PLog::_Internal::address(const PReturnResult_PLog* msg) {
+  return *msg->address_;
+}
 void PReturnResult_PLog::clear_address() {
-  if (GetArenaNoVirtual() == NULL && address_ != NULL) {
+  if (GetArenaNoVirtual() == nullptr && address_ != nullptr) {
     delete address_;
   }
-  address_ = NULL;
+  address_ = nullptr;
 }
-#if !defined(_MSC_VER) || _MSC_VER >= 1900
-const int PReturnResult_PLog::kAddressFieldNumber;
-const int PReturnResult_PLog::kTopicsFieldNumber;
-const int PReturnResult_PLog::kDataFieldNumber;
-#endif  // !defined(_MSC_VER) || _MSC_VER >= 1900
-
 PReturnResult_PLog::PReturnResult_PLog()
-  : ::google::protobuf::Message(), _internal_metadata_(NULL) {
-  ::google::protobuf::internal::InitSCC(
-      &protobuf_protobuf_5ftypes_2fpparameters_2eproto::scc_info_PReturnResult_PLog.base);
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
   SharedCtor();
   // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PReturnResult.PLog)
 }
 PReturnResult_PLog::PReturnResult_PLog(const PReturnResult_PLog& from)
-  : ::google::protobuf::Message(),
-      _internal_metadata_(NULL),
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr),
       topics_(from.topics_) {
   _internal_metadata_.MergeFrom(from._internal_metadata_);
-  data_.UnsafeSetDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-  if (from.data().size() > 0) {
-    data_.AssignWithDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), from.data_);
+  data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_data().empty()) {
+    data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.data_);
   }
-  if (from.has_address()) {
+  if (from._internal_has_address()) {
     address_ = new ::top::tvm_engine::basic::PAddress(*from.address_);
   } else {
-    address_ = NULL;
+    address_ = nullptr;
   }
   // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PReturnResult.PLog)
 }
 
 void PReturnResult_PLog::SharedCtor() {
-  data_.UnsafeSetDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-  address_ = NULL;
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&scc_info_PReturnResult_PLog_protobuf_5ftypes_2fpparameters_2eproto.base);
+  data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  address_ = nullptr;
 }
 
 PReturnResult_PLog::~PReturnResult_PLog() {
@@ -513,171 +783,119 @@ PReturnResult_PLog::~PReturnResult_PLog() {
 }
 
 void PReturnResult_PLog::SharedDtor() {
-  data_.DestroyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  data_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
   if (this != internal_default_instance()) delete address_;
 }
 
 void PReturnResult_PLog::SetCachedSize(int size) const {
   _cached_size_.Set(size);
 }
-const ::google::protobuf::Descriptor* PReturnResult_PLog::descriptor() {
-  ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::protobuf_AssignDescriptorsOnce();
-  return ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::file_level_metadata[kIndexInFileMessages].descriptor;
-}
-
 const PReturnResult_PLog& PReturnResult_PLog::default_instance() {
-  ::google::protobuf::internal::InitSCC(&protobuf_protobuf_5ftypes_2fpparameters_2eproto::scc_info_PReturnResult_PLog.base);
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PReturnResult_PLog_protobuf_5ftypes_2fpparameters_2eproto.base);
   return *internal_default_instance();
 }
 
 
 void PReturnResult_PLog::Clear() {
 // @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PReturnResult.PLog)
-  ::google::protobuf::uint32 cached_has_bits = 0;
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   // Prevent compiler warnings about cached_has_bits being unused
   (void) cached_has_bits;
 
   topics_.Clear();
-  data_.ClearToEmptyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-  if (GetArenaNoVirtual() == NULL && address_ != NULL) {
+  data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (GetArenaNoVirtual() == nullptr && address_ != nullptr) {
     delete address_;
   }
-  address_ = NULL;
+  address_ = nullptr;
   _internal_metadata_.Clear();
 }
 
-bool PReturnResult_PLog::MergePartialFromCodedStream(
-    ::google::protobuf::io::CodedInputStream* input) {
-#define DO_(EXPRESSION) if (!GOOGLE_PREDICT_TRUE(EXPRESSION)) goto failure
-  ::google::protobuf::uint32 tag;
-  // @@protoc_insertion_point(parse_start:top.tvm_engine.parameters.PReturnResult.PLog)
-  for (;;) {
-    ::std::pair<::google::protobuf::uint32, bool> p = input->ReadTagWithCutoffNoLastTag(127u);
-    tag = p.first;
-    if (!p.second) goto handle_unusual;
-    switch (::google::protobuf::internal::WireFormatLite::GetTagFieldNumber(tag)) {
+const char* PReturnResult_PLog::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
       // .top.tvm_engine.basic.PAddress address = 1;
-      case 1: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(10u /* 10 & 0xFF */)) {
-          DO_(::google::protobuf::internal::WireFormatLite::ReadMessage(
-               input, mutable_address()));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 10)) {
+          ptr = ctx->ParseMessage(_internal_mutable_address(), ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
       // repeated .top.tvm_engine.parameters.PReturnResult.PU256 topics = 2;
-      case 2: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(18u /* 18 & 0xFF */)) {
-          DO_(::google::protobuf::internal::WireFormatLite::ReadMessage(
-                input, add_topics()));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
+      case 2:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 18)) {
+          ptr -= 1;
+          do {
+            ptr += 1;
+            ptr = ctx->ParseMessage(_internal_add_topics(), ptr);
+            CHK_(ptr);
+            if (!ctx->DataAvailable(ptr)) break;
+          } while (::PROTOBUF_NAMESPACE_ID::internal::ExpectTag<18>(ptr));
+        } else goto handle_unusual;
+        continue;
       // bytes data = 3;
-      case 3: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(26u /* 26 & 0xFF */)) {
-          DO_(::google::protobuf::internal::WireFormatLite::ReadBytes(
-                input, this->mutable_data()));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
+      case 3:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 26)) {
+          auto str = _internal_mutable_data();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
       default: {
       handle_unusual:
-        if (tag == 0) {
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
           goto success;
         }
-        DO_(::google::protobuf::internal::WireFormat::SkipField(
-              input, tag, _internal_metadata_.mutable_unknown_fields()));
-        break;
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
       }
-    }
-  }
+    }  // switch
+  }  // while
 success:
-  // @@protoc_insertion_point(parse_success:top.tvm_engine.parameters.PReturnResult.PLog)
-  return true;
+  return ptr;
 failure:
-  // @@protoc_insertion_point(parse_failure:top.tvm_engine.parameters.PReturnResult.PLog)
-  return false;
-#undef DO_
-}
-
-void PReturnResult_PLog::SerializeWithCachedSizes(
-    ::google::protobuf::io::CodedOutputStream* output) const {
-  // @@protoc_insertion_point(serialize_start:top.tvm_engine.parameters.PReturnResult.PLog)
-  ::google::protobuf::uint32 cached_has_bits = 0;
-  (void) cached_has_bits;
-
-  // .top.tvm_engine.basic.PAddress address = 1;
-  if (this->has_address()) {
-    ::google::protobuf::internal::WireFormatLite::WriteMessageMaybeToArray(
-      1, this->_internal_address(), output);
-  }
-
-  // repeated .top.tvm_engine.parameters.PReturnResult.PU256 topics = 2;
-  for (unsigned int i = 0,
-      n = static_cast<unsigned int>(this->topics_size()); i < n; i++) {
-    ::google::protobuf::internal::WireFormatLite::WriteMessageMaybeToArray(
-      2,
-      this->topics(static_cast<int>(i)),
-      output);
-  }
-
-  // bytes data = 3;
-  if (this->data().size() > 0) {
-    ::google::protobuf::internal::WireFormatLite::WriteBytesMaybeAliased(
-      3, this->data(), output);
-  }
-
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    ::google::protobuf::internal::WireFormat::SerializeUnknownFields(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()), output);
-  }
-  // @@protoc_insertion_point(serialize_end:top.tvm_engine.parameters.PReturnResult.PLog)
+  ptr = nullptr;
+  goto success;
+#undef CHK_
 }
 
-::google::protobuf::uint8* PReturnResult_PLog::InternalSerializeWithCachedSizesToArray(
-    bool deterministic, ::google::protobuf::uint8* target) const {
-  (void)deterministic; // Unused
+::PROTOBUF_NAMESPACE_ID::uint8* PReturnResult_PLog::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
   // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PReturnResult.PLog)
-  ::google::protobuf::uint32 cached_has_bits = 0;
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   (void) cached_has_bits;
 
   // .top.tvm_engine.basic.PAddress address = 1;
   if (this->has_address()) {
-    target = ::google::protobuf::internal::WireFormatLite::
-      InternalWriteMessageToArray(
-        1, this->_internal_address(), deterministic, target);
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(
+        1, _Internal::address(this), target, stream);
   }
 
   // repeated .top.tvm_engine.parameters.PReturnResult.PU256 topics = 2;
   for (unsigned int i = 0,
-      n = static_cast<unsigned int>(this->topics_size()); i < n; i++) {
-    target = ::google::protobuf::internal::WireFormatLite::
-      InternalWriteMessageToArray(
-        2, this->topics(static_cast<int>(i)), deterministic, target);
+      n = static_cast<unsigned int>(this->_internal_topics_size()); i < n; i++) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(2, this->_internal_topics(i), target, stream);
   }
 
   // bytes data = 3;
   if (this->data().size() > 0) {
-    target =
-      ::google::protobuf::internal::WireFormatLite::WriteBytesToArray(
-        3, this->data(), target);
+    target = stream->WriteBytesMaybeAliased(
+        3, this->_internal_data(), target);
   }
 
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    target = ::google::protobuf::internal::WireFormat::SerializeUnknownFieldsToArray(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()), target);
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
   }
   // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PReturnResult.PLog)
   return target;
@@ -687,50 +905,49 @@ size_t PReturnResult_PLog::ByteSizeLong() const {
 // @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PReturnResult.PLog)
   size_t total_size = 0;
 
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    total_size +=
-      ::google::protobuf::internal::WireFormat::ComputeUnknownFieldsSize(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()));
-  }
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
   // repeated .top.tvm_engine.parameters.PReturnResult.PU256 topics = 2;
-  {
-    unsigned int count = static_cast<unsigned int>(this->topics_size());
-    total_size += 1UL * count;
-    for (unsigned int i = 0; i < count; i++) {
-      total_size +=
-        ::google::protobuf::internal::WireFormatLite::MessageSize(
-          this->topics(static_cast<int>(i)));
-    }
+  total_size += 1UL * this->_internal_topics_size();
+  for (const auto& msg : this->topics_) {
+    total_size +=
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(msg);
   }
 
   // bytes data = 3;
   if (this->data().size() > 0) {
     total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::BytesSize(
-        this->data());
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_data());
   }
 
   // .top.tvm_engine.basic.PAddress address = 1;
   if (this->has_address()) {
     total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::MessageSize(
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(
         *address_);
   }
 
-  int cached_size = ::google::protobuf::internal::ToCachedSize(total_size);
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
+  }
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
   SetCachedSize(cached_size);
   return total_size;
 }
 
-void PReturnResult_PLog::MergeFrom(const ::google::protobuf::Message& from) {
+void PReturnResult_PLog::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
 // @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PReturnResult.PLog)
   GOOGLE_DCHECK_NE(&from, this);
   const PReturnResult_PLog* source =
-      ::google::protobuf::internal::DynamicCastToGenerated<const PReturnResult_PLog>(
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PReturnResult_PLog>(
           &from);
-  if (source == NULL) {
+  if (source == nullptr) {
   // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PReturnResult.PLog)
-    ::google::protobuf::internal::ReflectionOps::Merge(from, this);
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
   } else {
   // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PReturnResult.PLog)
     MergeFrom(*source);
@@ -741,20 +958,20 @@ void PReturnResult_PLog::MergeFrom(const PReturnResult_PLog& from) {
 // @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PReturnResult.PLog)
   GOOGLE_DCHECK_NE(&from, this);
   _internal_metadata_.MergeFrom(from._internal_metadata_);
-  ::google::protobuf::uint32 cached_has_bits = 0;
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   (void) cached_has_bits;
 
   topics_.MergeFrom(from.topics_);
   if (from.data().size() > 0) {
 
-    data_.AssignWithDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), from.data_);
+    data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.data_);
   }
   if (from.has_address()) {
-    mutable_address()->::top::tvm_engine::basic::PAddress::MergeFrom(from.address());
+    _internal_mutable_address()->::top::tvm_engine::basic::PAddress::MergeFrom(from._internal_address());
   }
 }
 
-void PReturnResult_PLog::CopyFrom(const ::google::protobuf::Message& from) {
+void PReturnResult_PLog::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
 // @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PReturnResult.PLog)
   if (&from == this) return;
   Clear();
@@ -772,51 +989,71 @@ bool PReturnResult_PLog::IsInitialized() const {
   return true;
 }
 
-void PReturnResult_PLog::Swap(PReturnResult_PLog* other) {
-  if (other == this) return;
-  InternalSwap(other);
-}
 void PReturnResult_PLog::InternalSwap(PReturnResult_PLog* other) {
   using std::swap;
-  CastToBase(&topics_)->InternalSwap(CastToBase(&other->topics_));
-  data_.Swap(&other->data_, &::google::protobuf::internal::GetEmptyStringAlreadyInited(),
+  _internal_metadata_.Swap(&other->_internal_metadata_);
+  topics_.InternalSwap(&other->topics_);
+  data_.Swap(&other->data_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
     GetArenaNoVirtual());
   swap(address_, other->address_);
-  _internal_metadata_.Swap(&other->_internal_metadata_);
 }
 
-::google::protobuf::Metadata PReturnResult_PLog::GetMetadata() const {
-  protobuf_protobuf_5ftypes_2fpparameters_2eproto::protobuf_AssignDescriptorsOnce();
-  return ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::file_level_metadata[kIndexInFileMessages];
+::PROTOBUF_NAMESPACE_ID::Metadata PReturnResult_PLog::GetMetadata() const {
+  return GetMetadataStatic();
 }
 
 
 // ===================================================================
 
 void PReturnResult::InitAsDefaultInstance() {
+  ::top::tvm_engine::parameters::_PReturnResult_default_instance_._instance.get_mutable()->contract_address_ = const_cast< ::top::tvm_engine::basic::PAddress*>(
+      ::top::tvm_engine::basic::PAddress::internal_default_instance());
 }
-#if !defined(_MSC_VER) || _MSC_VER >= 1900
-const int PReturnResult::kStatusFieldNumber;
-const int PReturnResult::kStatusDataFieldNumber;
-const int PReturnResult::kGasUsedFieldNumber;
-const int PReturnResult::kLogsFieldNumber;
-#endif  // !defined(_MSC_VER) || _MSC_VER >= 1900
+class PReturnResult::_Internal {
+ public:
+  static const ::top::tvm_engine::basic::PAddress& contract_address(const PReturnResult* msg);
+};
 
+const ::top::tvm_engine::basic::PAddress&
+PReturnResult::_Internal::contract_address(const PReturnResult* msg) {
+  return *msg->contract_address_;
+}
+void PReturnResult::clear_contract_address() {
+  if (GetArenaNoVirtual() == nullptr && contract_address_ != nullptr) {
+    delete contract_address_;
+  }
+  contract_address_ = nullptr;
+}
 PReturnResult::PReturnResult()
-  : ::google::protobuf::Message(), _internal_metadata_(NULL) {
-  ::google::protobuf::internal::InitSCC(
-      &protobuf_protobuf_5ftypes_2fpparameters_2eproto::scc_info_PReturnResult.base);
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
   SharedCtor();
   // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PReturnResult)
 }
 PReturnResult::PReturnResult(const PReturnResult& from)
-  : ::google::protobuf::Message(),
-      _internal_metadata_(NULL),
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr),
       logs_(from.logs_) {
   _internal_metadata_.MergeFrom(from._internal_metadata_);
-  status_data_.UnsafeSetDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-  if (from.status_data().size() > 0) {
-    status_data_.AssignWithDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), from.status_data_);
+  status_data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_status_data().empty()) {
+    status_data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.status_data_);
+  }
+  code_hash_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_code_hash().empty()) {
+    code_hash_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.code_hash_);
+  }
+  salt_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_salt().empty()) {
+    salt_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.salt_);
+  }
+  error_message_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_error_message().empty()) {
+    error_message_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.error_message_);
+  }
+  if (from._internal_has_contract_address()) {
+    contract_address_ = new ::top::tvm_engine::basic::PAddress(*from.contract_address_);
+  } else {
+    contract_address_ = nullptr;
   }
   ::memcpy(&gas_used_, &from.gas_used_,
     static_cast<size_t>(reinterpret_cast<char*>(&status_) -
@@ -825,10 +1062,14 @@ PReturnResult::PReturnResult(const PReturnResult& from)
 }
 
 void PReturnResult::SharedCtor() {
-  status_data_.UnsafeSetDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-  ::memset(&gas_used_, 0, static_cast<size_t>(
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&scc_info_PReturnResult_protobuf_5ftypes_2fpparameters_2eproto.base);
+  status_data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  code_hash_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  salt_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  ::memset(&contract_address_, 0, static_cast<size_t>(
       reinterpret_cast<char*>(&status_) -
-      reinterpret_cast<char*>(&gas_used_)) + sizeof(status_));
+      reinterpret_cast<char*>(&contract_address_)) + sizeof(status_));
 }
 
 PReturnResult::~PReturnResult() {
@@ -837,192 +1078,229 @@ PReturnResult::~PReturnResult() {
 }
 
 void PReturnResult::SharedDtor() {
-  status_data_.DestroyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  status_data_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  code_hash_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  salt_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (this != internal_default_instance()) delete contract_address_;
 }
 
 void PReturnResult::SetCachedSize(int size) const {
   _cached_size_.Set(size);
 }
-const ::google::protobuf::Descriptor* PReturnResult::descriptor() {
-  ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::protobuf_AssignDescriptorsOnce();
-  return ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::file_level_metadata[kIndexInFileMessages].descriptor;
-}
-
 const PReturnResult& PReturnResult::default_instance() {
-  ::google::protobuf::internal::InitSCC(&protobuf_protobuf_5ftypes_2fpparameters_2eproto::scc_info_PReturnResult.base);
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PReturnResult_protobuf_5ftypes_2fpparameters_2eproto.base);
   return *internal_default_instance();
 }
 
 
 void PReturnResult::Clear() {
 // @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PReturnResult)
-  ::google::protobuf::uint32 cached_has_bits = 0;
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   // Prevent compiler warnings about cached_has_bits being unused
   (void) cached_has_bits;
 
   logs_.Clear();
-  status_data_.ClearToEmptyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  status_data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  code_hash_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  salt_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (GetArenaNoVirtual() == nullptr && contract_address_ != nullptr) {
+    delete contract_address_;
+  }
+  contract_address_ = nullptr;
   ::memset(&gas_used_, 0, static_cast<size_t>(
       reinterpret_cast<char*>(&status_) -
       reinterpret_cast<char*>(&gas_used_)) + sizeof(status_));
   _internal_metadata_.Clear();
 }
 
-bool PReturnResult::MergePartialFromCodedStream(
-    ::google::protobuf::io::CodedInputStream* input) {
-#define DO_(EXPRESSION) if (!GOOGLE_PREDICT_TRUE(EXPRESSION)) goto failure
-  ::google::protobuf::uint32 tag;
-  // @@protoc_insertion_point(parse_start:top.tvm_engine.parameters.PReturnResult)
-  for (;;) {
-    ::std::pair<::google::protobuf::uint32, bool> p = input->ReadTagWithCutoffNoLastTag(127u);
-    tag = p.first;
-    if (!p.second) goto handle_unusual;
-    switch (::google::protobuf::internal::WireFormatLite::GetTagFieldNumber(tag)) {
-      // uint32 status = 1;
-      case 1: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(8u /* 8 & 0xFF */)) {
-
-          DO_((::google::protobuf::internal::WireFormatLite::ReadPrimitive<
-                   ::google::protobuf::uint32, ::google::protobuf::internal::WireFormatLite::TYPE_UINT32>(
-                 input, &status_)));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
+const char* PReturnResult::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
+      // .top.tvm_engine.parameters.PStatusCode status = 1;
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 8)) {
+          ::PROTOBUF_NAMESPACE_ID::uint64 val = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+          _internal_set_status(static_cast<::top::tvm_engine::parameters::PStatusCode>(val));
+        } else goto handle_unusual;
+        continue;
       // bytes status_data = 2;
-      case 2: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(18u /* 18 & 0xFF */)) {
-          DO_(::google::protobuf::internal::WireFormatLite::ReadBytes(
-                input, this->mutable_status_data()));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
+      case 2:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 18)) {
+          auto str = _internal_mutable_status_data();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
       // uint64 gas_used = 3;
-      case 3: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(24u /* 24 & 0xFF */)) {
-
-          DO_((::google::protobuf::internal::WireFormatLite::ReadPrimitive<
-                   ::google::protobuf::uint64, ::google::protobuf::internal::WireFormatLite::TYPE_UINT64>(
-                 input, &gas_used_)));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
+      case 3:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 24)) {
+          gas_used_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
       // repeated .top.tvm_engine.parameters.PReturnResult.PLog logs = 4;
-      case 4: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(34u /* 34 & 0xFF */)) {
-          DO_(::google::protobuf::internal::WireFormatLite::ReadMessage(
-                input, add_logs()));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
+      case 4:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 34)) {
+          ptr -= 1;
+          do {
+            ptr += 1;
+            ptr = ctx->ParseMessage(_internal_add_logs(), ptr);
+            CHK_(ptr);
+            if (!ctx->DataAvailable(ptr)) break;
+          } while (::PROTOBUF_NAMESPACE_ID::internal::ExpectTag<34>(ptr));
+        } else goto handle_unusual;
+        continue;
+      // uint64 effective_gas_price = 5;
+      case 5:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 40)) {
+          effective_gas_price_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // .top.tvm_engine.basic.PAddress contract_address = 6;
+      case 6:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 50)) {
+          ptr = ctx->ParseMessage(_internal_mutable_contract_address(), ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // bytes code_hash = 7;
+      case 7:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 58)) {
+          auto str = _internal_mutable_code_hash();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // uint64 code_size = 8;
+      case 8:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 64)) {
+          code_size_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // bytes salt = 9;
+      case 9:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 74)) {
+          auto str = _internal_mutable_salt();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // string error_message = 10;
+      case 10:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 82)) {
+          auto str = _internal_mutable_error_message();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(::PROTOBUF_NAMESPACE_ID::internal::VerifyUTF8(str, "top.tvm_engine.parameters.PReturnResult.error_message"));
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
       default: {
       handle_unusual:
-        if (tag == 0) {
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
           goto success;
         }
-        DO_(::google::protobuf::internal::WireFormat::SkipField(
-              input, tag, _internal_metadata_.mutable_unknown_fields()));
-        break;
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
       }
-    }
-  }
+    }  // switch
+  }  // while
 success:
-  // @@protoc_insertion_point(parse_success:top.tvm_engine.parameters.PReturnResult)
-  return true;
+  return ptr;
 failure:
-  // @@protoc_insertion_point(parse_failure:top.tvm_engine.parameters.PReturnResult)
-  return false;
-#undef DO_
+  ptr = nullptr;
+  goto success;
+#undef CHK_
 }
 
-void PReturnResult::SerializeWithCachedSizes(
-    ::google::protobuf::io::CodedOutputStream* output) const {
-  // @@protoc_insertion_point(serialize_start:top.tvm_engine.parameters.PReturnResult)
-  ::google::protobuf::uint32 cached_has_bits = 0;
+::PROTOBUF_NAMESPACE_ID::uint8* PReturnResult::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
+  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PReturnResult)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   (void) cached_has_bits;
 
-  // uint32 status = 1;
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
   if (this->status() != 0) {
-    ::google::protobuf::internal::WireFormatLite::WriteUInt32(1, this->status(), output);
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteEnumToArray(
+      1, this->_internal_status(), target);
   }
 
   // bytes status_data = 2;
   if (this->status_data().size() > 0) {
-    ::google::protobuf::internal::WireFormatLite::WriteBytesMaybeAliased(
-      2, this->status_data(), output);
+    target = stream->WriteBytesMaybeAliased(
+        2, this->_internal_status_data(), target);
   }
 
   // uint64 gas_used = 3;
   if (this->gas_used() != 0) {
-    ::google::protobuf::internal::WireFormatLite::WriteUInt64(3, this->gas_used(), output);
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(3, this->_internal_gas_used(), target);
   }
 
   // repeated .top.tvm_engine.parameters.PReturnResult.PLog logs = 4;
   for (unsigned int i = 0,
-      n = static_cast<unsigned int>(this->logs_size()); i < n; i++) {
-    ::google::protobuf::internal::WireFormatLite::WriteMessageMaybeToArray(
-      4,
-      this->logs(static_cast<int>(i)),
-      output);
+      n = static_cast<unsigned int>(this->_internal_logs_size()); i < n; i++) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(4, this->_internal_logs(i), target, stream);
   }
 
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    ::google::protobuf::internal::WireFormat::SerializeUnknownFields(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()), output);
+  // uint64 effective_gas_price = 5;
+  if (this->effective_gas_price() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(5, this->_internal_effective_gas_price(), target);
   }
-  // @@protoc_insertion_point(serialize_end:top.tvm_engine.parameters.PReturnResult)
-}
 
-::google::protobuf::uint8* PReturnResult::InternalSerializeWithCachedSizesToArray(
-    bool deterministic, ::google::protobuf::uint8* target) const {
-  (void)deterministic; // Unused
-  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PReturnResult)
-  ::google::protobuf::uint32 cached_has_bits = 0;
-  (void) cached_has_bits;
+  // .top.tvm_engine.basic.PAddress contract_address = 6;
+  if (this->has_contract_address()) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(
+        6, _Internal::contract_address(this), target, stream);
+  }
 
-  // uint32 status = 1;
-  if (this->status() != 0) {
-    target = ::google::protobuf::internal::WireFormatLite::WriteUInt32ToArray(1, this->status(), target);
+  // bytes code_hash = 7;
+  if (this->code_hash().size() > 0) {
+    target = stream->WriteBytesMaybeAliased(
+        7, this->_internal_code_hash(), target);
   }
 
-  // bytes status_data = 2;
-  if (this->status_data().size() > 0) {
-    target =
-      ::google::protobuf::internal::WireFormatLite::WriteBytesToArray(
-        2, this->status_data(), target);
+  // uint64 code_size = 8;
+  if (this->code_size() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(8, this->_internal_code_size(), target);
   }
 
-  // uint64 gas_used = 3;
-  if (this->gas_used() != 0) {
-    target = ::google::protobuf::internal::WireFormatLite::WriteUInt64ToArray(3, this->gas_used(), target);
+  // bytes salt = 9;
+  if (this->salt().size() > 0) {
+    target = stream->WriteBytesMaybeAliased(
+        9, this->_internal_salt(), target);
   }
 
-  // repeated .top.tvm_engine.parameters.PReturnResult.PLog logs = 4;
-  for (unsigned int i = 0,
-      n = static_cast<unsigned int>(this->logs_size()); i < n; i++) {
-    target = ::google::protobuf::internal::WireFormatLite::
-      InternalWriteMessageToArray(
-        4, this->logs(static_cast<int>(i)), deterministic, target);
+  // string error_message = 10;
+  if (this->error_message().size() > 0) {
+    ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::VerifyUtf8String(
+      this->_internal_error_message().data(), static_cast<int>(this->_internal_error_message().length()),
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::SERIALIZE,
+      "top.tvm_engine.parameters.PReturnResult.error_message");
+    target = stream->WriteStringMaybeAliased(
+        10, this->_internal_error_message(), target);
   }
 
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    target = ::google::protobuf::internal::WireFormat::SerializeUnknownFieldsToArray(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()), target);
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
   }
   // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PReturnResult)
   return target;
@@ -1032,57 +1310,97 @@ size_t PReturnResult::ByteSizeLong() const {
 // @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PReturnResult)
   size_t total_size = 0;
 
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    total_size +=
-      ::google::protobuf::internal::WireFormat::ComputeUnknownFieldsSize(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()));
-  }
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
   // repeated .top.tvm_engine.parameters.PReturnResult.PLog logs = 4;
-  {
-    unsigned int count = static_cast<unsigned int>(this->logs_size());
-    total_size += 1UL * count;
-    for (unsigned int i = 0; i < count; i++) {
-      total_size +=
-        ::google::protobuf::internal::WireFormatLite::MessageSize(
-          this->logs(static_cast<int>(i)));
-    }
+  total_size += 1UL * this->_internal_logs_size();
+  for (const auto& msg : this->logs_) {
+    total_size +=
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(msg);
   }
 
   // bytes status_data = 2;
   if (this->status_data().size() > 0) {
     total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::BytesSize(
-        this->status_data());
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_status_data());
+  }
+
+  // bytes code_hash = 7;
+  if (this->code_hash().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_code_hash());
+  }
+
+  // bytes salt = 9;
+  if (this->salt().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_salt());
+  }
+
+  // string error_message = 10;
+  if (this->error_message().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::StringSize(
+        this->_internal_error_message());
+  }
+
+  // .top.tvm_engine.basic.PAddress contract_address = 6;
+  if (this->has_contract_address()) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(
+        *contract_address_);
   }
 
   // uint64 gas_used = 3;
   if (this->gas_used() != 0) {
     total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::UInt64Size(
-        this->gas_used());
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_gas_used());
+  }
+
+  // uint64 effective_gas_price = 5;
+  if (this->effective_gas_price() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_effective_gas_price());
   }
 
-  // uint32 status = 1;
+  // uint64 code_size = 8;
+  if (this->code_size() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_code_size());
+  }
+
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
   if (this->status() != 0) {
     total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::UInt32Size(
-        this->status());
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::EnumSize(this->_internal_status());
   }
 
-  int cached_size = ::google::protobuf::internal::ToCachedSize(total_size);
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
+  }
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
   SetCachedSize(cached_size);
   return total_size;
 }
 
-void PReturnResult::MergeFrom(const ::google::protobuf::Message& from) {
+void PReturnResult::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
 // @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PReturnResult)
   GOOGLE_DCHECK_NE(&from, this);
   const PReturnResult* source =
-      ::google::protobuf::internal::DynamicCastToGenerated<const PReturnResult>(
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PReturnResult>(
           &from);
-  if (source == NULL) {
+  if (source == nullptr) {
   // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PReturnResult)
-    ::google::protobuf::internal::ReflectionOps::Merge(from, this);
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
   } else {
   // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PReturnResult)
     MergeFrom(*source);
@@ -1093,23 +1411,44 @@ void PReturnResult::MergeFrom(const PReturnResult& from) {
 // @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PReturnResult)
   GOOGLE_DCHECK_NE(&from, this);
   _internal_metadata_.MergeFrom(from._internal_metadata_);
-  ::google::protobuf::uint32 cached_has_bits = 0;
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   (void) cached_has_bits;
 
   logs_.MergeFrom(from.logs_);
   if (from.status_data().size() > 0) {
 
-    status_data_.AssignWithDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), from.status_data_);
+    status_data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.status_data_);
   }
-  if (from.gas_used() != 0) {
-    set_gas_used(from.gas_used());
+  if (from.code_hash().size() > 0) {
+
+    code_hash_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.code_hash_);
   }
-  if (from.status() != 0) {
-    set_status(from.status());
+  if (from.salt().size() > 0) {
+
+    salt_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.salt_);
+  }
+  if (from.error_message().size() > 0) {
+
+    error_message_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.error_message_);
+  }
+  if (from.has_contract_address()) {
+    _internal_mutable_contract_address()->::top::tvm_engine::basic::PAddress::MergeFrom(from._internal_contract_address());
+  }
+  if (from.gas_used() != 0) {
+    _internal_set_gas_used(from._internal_gas_used());
+  }
+  if (from.effective_gas_price() != 0) {
+    _internal_set_effective_gas_price(from._internal_effective_gas_price());
+  }
+  if (from.code_size() != 0) {
+    _internal_set_code_size(from._internal_code_size());
+  }
+  if (from.status() != 0) {
+    _internal_set_status(from._internal_status());
   }
 }
 
-void PReturnResult::CopyFrom(const ::google::protobuf::Message& from) {
+void PReturnResult::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
 // @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PReturnResult)
   if (&from == this) return;
   Clear();
@@ -1127,444 +1466,2455 @@ bool PReturnResult::IsInitialized() const {
   return true;
 }
 
-void PReturnResult::Swap(PReturnResult* other) {
-  if (other == this) return;
-  InternalSwap(other);
-}
 void PReturnResult::InternalSwap(PReturnResult* other) {
   using std::swap;
-  CastToBase(&logs_)->InternalSwap(CastToBase(&other->logs_));
-  status_data_.Swap(&other->status_data_, &::google::protobuf::internal::GetEmptyStringAlreadyInited(),
+  _internal_metadata_.Swap(&other->_internal_metadata_);
+  logs_.InternalSwap(&other->logs_);
+  status_data_.Swap(&other->status_data_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  code_hash_.Swap(&other->code_hash_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
     GetArenaNoVirtual());
+  salt_.Swap(&other->salt_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  error_message_.Swap(&other->error_message_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  swap(contract_address_, other->contract_address_);
   swap(gas_used_, other->gas_used_);
+  swap(effective_gas_price_, other->effective_gas_price_);
+  swap(code_size_, other->code_size_);
   swap(status_, other->status_);
-  _internal_metadata_.Swap(&other->_internal_metadata_);
 }
 
-::google::protobuf::Metadata PReturnResult::GetMetadata() const {
-  protobuf_protobuf_5ftypes_2fpparameters_2eproto::protobuf_AssignDescriptorsOnce();
-  return ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::file_level_metadata[kIndexInFileMessages];
+::PROTOBUF_NAMESPACE_ID::Metadata PReturnResult::GetMetadata() const {
+  return GetMetadataStatic();
 }
 
 
 // ===================================================================
 
-void PCallArgs::InitAsDefaultInstance() {
-  ::top::tvm_engine::parameters::_PCallArgs_default_instance_._instance.get_mutable()->sender_address_ = const_cast< ::top::tvm_engine::basic::PAddress*>(
-      ::top::tvm_engine::basic::PAddress::internal_default_instance());
-  ::top::tvm_engine::parameters::_PCallArgs_default_instance_._instance.get_mutable()->recver_address_ = const_cast< ::top::tvm_engine::basic::PAddress*>(
-      ::top::tvm_engine::basic::PAddress::internal_default_instance());
-}
-void PCallArgs::clear_sender_address() {
-  if (GetArenaNoVirtual() == NULL && sender_address_ != NULL) {
-    delete sender_address_;
-  }
-  sender_address_ = NULL;
+void PStateDiffResult::InitAsDefaultInstance() {
+  ::top::tvm_engine::parameters::_PStateDiffResult_default_instance_._instance.get_mutable()->result_ = const_cast< ::top::tvm_engine::parameters::PReturnResult*>(
+      ::top::tvm_engine::parameters::PReturnResult::internal_default_instance());
 }
-void PCallArgs::clear_recver_address() {
-  if (GetArenaNoVirtual() == NULL && recver_address_ != NULL) {
-    delete recver_address_;
-  }
-  recver_address_ = NULL;
-}
-#if !defined(_MSC_VER) || _MSC_VER >= 1900
-const int PCallArgs::kSenderAddressFieldNumber;
-const int PCallArgs::kRecverAddressFieldNumber;
-const int PCallArgs::kValueFieldNumber;
-const int PCallArgs::kInputFieldNumber;
-const int PCallArgs::kGasLimitFieldNumber;
-#endif  // !defined(_MSC_VER) || _MSC_VER >= 1900
+class PStateDiffResult::_Internal {
+ public:
+  static const ::top::tvm_engine::parameters::PReturnResult& result(const PStateDiffResult* msg);
+};
 
-PCallArgs::PCallArgs()
-  : ::google::protobuf::Message(), _internal_metadata_(NULL) {
-  ::google::protobuf::internal::InitSCC(
-      &protobuf_protobuf_5ftypes_2fpparameters_2eproto::scc_info_PCallArgs.base);
+const ::top::tvm_engine::parameters::PReturnResult&
+PStateDiffResult::_Internal::result(const PStateDiffResult* msg) {
+  return *msg->result_;
+}
+PStateDiffResult::PStateDiffResult()
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
   SharedCtor();
-  // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PCallArgs)
+  // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PStateDiffResult)
 }
-PCallArgs::PCallArgs(const PCallArgs& from)
-  : ::google::protobuf::Message(),
-      _internal_metadata_(NULL) {
+PStateDiffResult::PStateDiffResult(const PStateDiffResult& from)
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr) {
   _internal_metadata_.MergeFrom(from._internal_metadata_);
-  input_.UnsafeSetDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-  if (from.input().size() > 0) {
-    input_.AssignWithDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), from.input_);
-  }
-  if (from.has_sender_address()) {
-    sender_address_ = new ::top::tvm_engine::basic::PAddress(*from.sender_address_);
-  } else {
-    sender_address_ = NULL;
+  state_diff_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_state_diff().empty()) {
+    state_diff_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.state_diff_);
   }
-  if (from.has_recver_address()) {
-    recver_address_ = new ::top::tvm_engine::basic::PAddress(*from.recver_address_);
+  if (from._internal_has_result()) {
+    result_ = new ::top::tvm_engine::parameters::PReturnResult(*from.result_);
   } else {
-    recver_address_ = NULL;
+    result_ = nullptr;
   }
-  ::memcpy(&value_, &from.value_,
-    static_cast<size_t>(reinterpret_cast<char*>(&gas_limit_) -
-    reinterpret_cast<char*>(&value_)) + sizeof(gas_limit_));
-  // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PCallArgs)
+  // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PStateDiffResult)
 }
 
-void PCallArgs::SharedCtor() {
-  input_.UnsafeSetDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-  ::memset(&sender_address_, 0, static_cast<size_t>(
-      reinterpret_cast<char*>(&gas_limit_) -
-      reinterpret_cast<char*>(&sender_address_)) + sizeof(gas_limit_));
+void PStateDiffResult::SharedCtor() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&scc_info_PStateDiffResult_protobuf_5ftypes_2fpparameters_2eproto.base);
+  state_diff_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  result_ = nullptr;
 }
 
-PCallArgs::~PCallArgs() {
-  // @@protoc_insertion_point(destructor:top.tvm_engine.parameters.PCallArgs)
+PStateDiffResult::~PStateDiffResult() {
+  // @@protoc_insertion_point(destructor:top.tvm_engine.parameters.PStateDiffResult)
   SharedDtor();
 }
 
-void PCallArgs::SharedDtor() {
-  input_.DestroyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-  if (this != internal_default_instance()) delete sender_address_;
-  if (this != internal_default_instance()) delete recver_address_;
+void PStateDiffResult::SharedDtor() {
+  state_diff_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (this != internal_default_instance()) delete result_;
 }
 
-void PCallArgs::SetCachedSize(int size) const {
+void PStateDiffResult::SetCachedSize(int size) const {
   _cached_size_.Set(size);
 }
-const ::google::protobuf::Descriptor* PCallArgs::descriptor() {
-  ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::protobuf_AssignDescriptorsOnce();
-  return ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::file_level_metadata[kIndexInFileMessages].descriptor;
-}
-
-const PCallArgs& PCallArgs::default_instance() {
-  ::google::protobuf::internal::InitSCC(&protobuf_protobuf_5ftypes_2fpparameters_2eproto::scc_info_PCallArgs.base);
+const PStateDiffResult& PStateDiffResult::default_instance() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PStateDiffResult_protobuf_5ftypes_2fpparameters_2eproto.base);
   return *internal_default_instance();
 }
 
 
-void PCallArgs::Clear() {
-// @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PCallArgs)
-  ::google::protobuf::uint32 cached_has_bits = 0;
+void PStateDiffResult::Clear() {
+// @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PStateDiffResult)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   // Prevent compiler warnings about cached_has_bits being unused
   (void) cached_has_bits;
 
-  input_.ClearToEmptyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-  if (GetArenaNoVirtual() == NULL && sender_address_ != NULL) {
-    delete sender_address_;
-  }
-  sender_address_ = NULL;
-  if (GetArenaNoVirtual() == NULL && recver_address_ != NULL) {
-    delete recver_address_;
+  state_diff_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (GetArenaNoVirtual() == nullptr && result_ != nullptr) {
+    delete result_;
   }
-  recver_address_ = NULL;
-  ::memset(&value_, 0, static_cast<size_t>(
-      reinterpret_cast<char*>(&gas_limit_) -
-      reinterpret_cast<char*>(&value_)) + sizeof(gas_limit_));
+  result_ = nullptr;
   _internal_metadata_.Clear();
 }
 
-bool PCallArgs::MergePartialFromCodedStream(
-    ::google::protobuf::io::CodedInputStream* input) {
-#define DO_(EXPRESSION) if (!GOOGLE_PREDICT_TRUE(EXPRESSION)) goto failure
-  ::google::protobuf::uint32 tag;
-  // @@protoc_insertion_point(parse_start:top.tvm_engine.parameters.PCallArgs)
-  for (;;) {
-    ::std::pair<::google::protobuf::uint32, bool> p = input->ReadTagWithCutoffNoLastTag(127u);
-    tag = p.first;
-    if (!p.second) goto handle_unusual;
-    switch (::google::protobuf::internal::WireFormatLite::GetTagFieldNumber(tag)) {
-      // .top.tvm_engine.basic.PAddress sender_address = 1;
-      case 1: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(10u /* 10 & 0xFF */)) {
-          DO_(::google::protobuf::internal::WireFormatLite::ReadMessage(
-               input, mutable_sender_address()));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
-      // .top.tvm_engine.basic.PAddress recver_address = 2;
-      case 2: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(18u /* 18 & 0xFF */)) {
-          DO_(::google::protobuf::internal::WireFormatLite::ReadMessage(
-               input, mutable_recver_address()));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
-      // uint64 value = 3;
-      case 3: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(24u /* 24 & 0xFF */)) {
-
-          DO_((::google::protobuf::internal::WireFormatLite::ReadPrimitive<
-                   ::google::protobuf::uint64, ::google::protobuf::internal::WireFormatLite::TYPE_UINT64>(
-                 input, &value_)));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
-      // bytes input = 4;
-      case 4: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(34u /* 34 & 0xFF */)) {
-          DO_(::google::protobuf::internal::WireFormatLite::ReadBytes(
-                input, this->mutable_input()));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
-      // uint64 gas_limit = 5;
-      case 5: {
-        if (static_cast< ::google::protobuf::uint8>(tag) ==
-            static_cast< ::google::protobuf::uint8>(40u /* 40 & 0xFF */)) {
-
-          DO_((::google::protobuf::internal::WireFormatLite::ReadPrimitive<
-                   ::google::protobuf::uint64, ::google::protobuf::internal::WireFormatLite::TYPE_UINT64>(
-                 input, &gas_limit_)));
-        } else {
-          goto handle_unusual;
-        }
-        break;
-      }
-
+const char* PStateDiffResult::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
+      // .top.tvm_engine.parameters.PReturnResult result = 1;
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 10)) {
+          ptr = ctx->ParseMessage(_internal_mutable_result(), ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // string state_diff = 2;
+      case 2:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 18)) {
+          auto str = _internal_mutable_state_diff();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(::PROTOBUF_NAMESPACE_ID::internal::VerifyUTF8(str, "top.tvm_engine.parameters.PStateDiffResult.state_diff"));
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
       default: {
       handle_unusual:
-        if (tag == 0) {
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
           goto success;
         }
-        DO_(::google::protobuf::internal::WireFormat::SkipField(
-              input, tag, _internal_metadata_.mutable_unknown_fields()));
-        break;
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
       }
-    }
-  }
+    }  // switch
+  }  // while
 success:
-  // @@protoc_insertion_point(parse_success:top.tvm_engine.parameters.PCallArgs)
-  return true;
+  return ptr;
 failure:
-  // @@protoc_insertion_point(parse_failure:top.tvm_engine.parameters.PCallArgs)
-  return false;
-#undef DO_
+  ptr = nullptr;
+  goto success;
+#undef CHK_
 }
 
-void PCallArgs::SerializeWithCachedSizes(
-    ::google::protobuf::io::CodedOutputStream* output) const {
-  // @@protoc_insertion_point(serialize_start:top.tvm_engine.parameters.PCallArgs)
-  ::google::protobuf::uint32 cached_has_bits = 0;
+::PROTOBUF_NAMESPACE_ID::uint8* PStateDiffResult::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
+  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PStateDiffResult)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   (void) cached_has_bits;
 
-  // .top.tvm_engine.basic.PAddress sender_address = 1;
-  if (this->has_sender_address()) {
-    ::google::protobuf::internal::WireFormatLite::WriteMessageMaybeToArray(
-      1, this->_internal_sender_address(), output);
+  // .top.tvm_engine.parameters.PReturnResult result = 1;
+  if (this->has_result()) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(
+        1, _Internal::result(this), target, stream);
   }
 
-  // .top.tvm_engine.basic.PAddress recver_address = 2;
-  if (this->has_recver_address()) {
-    ::google::protobuf::internal::WireFormatLite::WriteMessageMaybeToArray(
-      2, this->_internal_recver_address(), output);
+  // string state_diff = 2;
+  if (this->state_diff().size() > 0) {
+    ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::VerifyUtf8String(
+      this->_internal_state_diff().data(), static_cast<int>(this->_internal_state_diff().length()),
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::SERIALIZE,
+      "top.tvm_engine.parameters.PStateDiffResult.state_diff");
+    target = stream->WriteStringMaybeAliased(
+        2, this->_internal_state_diff(), target);
   }
 
-  // uint64 value = 3;
-  if (this->value() != 0) {
-    ::google::protobuf::internal::WireFormatLite::WriteUInt64(3, this->value(), output);
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
   }
+  // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PStateDiffResult)
+  return target;
+}
 
-  // bytes input = 4;
-  if (this->input().size() > 0) {
-    ::google::protobuf::internal::WireFormatLite::WriteBytesMaybeAliased(
-      4, this->input(), output);
+size_t PStateDiffResult::ByteSizeLong() const {
+// @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PStateDiffResult)
+  size_t total_size = 0;
+
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  // string state_diff = 2;
+  if (this->state_diff().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::StringSize(
+        this->_internal_state_diff());
   }
 
-  // uint64 gas_limit = 5;
-  if (this->gas_limit() != 0) {
-    ::google::protobuf::internal::WireFormatLite::WriteUInt64(5, this->gas_limit(), output);
+  // .top.tvm_engine.parameters.PReturnResult result = 1;
+  if (this->has_result()) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(
+        *result_);
   }
 
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    ::google::protobuf::internal::WireFormat::SerializeUnknownFields(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()), output);
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
   }
-  // @@protoc_insertion_point(serialize_end:top.tvm_engine.parameters.PCallArgs)
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
+  SetCachedSize(cached_size);
+  return total_size;
 }
 
-::google::protobuf::uint8* PCallArgs::InternalSerializeWithCachedSizesToArray(
-    bool deterministic, ::google::protobuf::uint8* target) const {
-  (void)deterministic; // Unused
-  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PCallArgs)
-  ::google::protobuf::uint32 cached_has_bits = 0;
+void PStateDiffResult::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PStateDiffResult)
+  GOOGLE_DCHECK_NE(&from, this);
+  const PStateDiffResult* source =
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PStateDiffResult>(
+          &from);
+  if (source == nullptr) {
+  // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PStateDiffResult)
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
+  } else {
+  // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PStateDiffResult)
+    MergeFrom(*source);
+  }
+}
+
+void PStateDiffResult::MergeFrom(const PStateDiffResult& from) {
+// @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PStateDiffResult)
+  GOOGLE_DCHECK_NE(&from, this);
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   (void) cached_has_bits;
 
-  // .top.tvm_engine.basic.PAddress sender_address = 1;
-  if (this->has_sender_address()) {
-    target = ::google::protobuf::internal::WireFormatLite::
-      InternalWriteMessageToArray(
-        1, this->_internal_sender_address(), deterministic, target);
+  if (from.state_diff().size() > 0) {
+
+    state_diff_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.state_diff_);
   }
+  if (from.has_result()) {
+    _internal_mutable_result()->::top::tvm_engine::parameters::PReturnResult::MergeFrom(from._internal_result());
+  }
+}
 
-  // .top.tvm_engine.basic.PAddress recver_address = 2;
-  if (this->has_recver_address()) {
-    target = ::google::protobuf::internal::WireFormatLite::
-      InternalWriteMessageToArray(
-        2, this->_internal_recver_address(), deterministic, target);
+void PStateDiffResult::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PStateDiffResult)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+void PStateDiffResult::CopyFrom(const PStateDiffResult& from) {
+// @@protoc_insertion_point(class_specific_copy_from_start:top.tvm_engine.parameters.PStateDiffResult)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+bool PStateDiffResult::IsInitialized() const {
+  return true;
+}
+
+void PStateDiffResult::InternalSwap(PStateDiffResult* other) {
+  using std::swap;
+  _internal_metadata_.Swap(&other->_internal_metadata_);
+  state_diff_.Swap(&other->state_diff_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  swap(result_, other->result_);
+}
+
+::PROTOBUF_NAMESPACE_ID::Metadata PStateDiffResult::GetMetadata() const {
+  return GetMetadataStatic();
+}
+
+
+// ===================================================================
+
+void PCallArgs_PAccessListItem::InitAsDefaultInstance() {
+  ::top::tvm_engine::parameters::_PCallArgs_PAccessListItem_default_instance_._instance.get_mutable()->address_ = const_cast< ::top::tvm_engine::basic::PAddress*>(
+      ::top::tvm_engine::basic::PAddress::internal_default_instance());
+}
+class PCallArgs_PAccessListItem::_Internal {
+ public:
+  static const ::top::tvm_engine::basic::PAddress& address(const PCallArgs_PAccessListItem* msg);
+};
+
+const ::top::tvm_engine::basic::PAddress&
+PCallArgs_PAccessListItem::_Internal::address(const PCallArgs_PAccessListItem* msg) {
+  return *msg->address_;
+}
+void PCallArgs_PAccessListItem::clear_address() {
+  if (GetArenaNoVirtual() == nullptr && address_ != nullptr) {
+    delete address_;
+  }
+  address_ = nullptr;
+}
+PCallArgs_PAccessListItem::PCallArgs_PAccessListItem()
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
+  SharedCtor();
+  // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
+}
+PCallArgs_PAccessListItem::PCallArgs_PAccessListItem(const PCallArgs_PAccessListItem& from)
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr),
+      storage_keys_(from.storage_keys_) {
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  if (from._internal_has_address()) {
+    address_ = new ::top::tvm_engine::basic::PAddress(*from.address_);
+  } else {
+    address_ = nullptr;
   }
+  // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
+}
 
-  // uint64 value = 3;
-  if (this->value() != 0) {
-    target = ::google::protobuf::internal::WireFormatLite::WriteUInt64ToArray(3, this->value(), target);
+void PCallArgs_PAccessListItem::SharedCtor() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&scc_info_PCallArgs_PAccessListItem_protobuf_5ftypes_2fpparameters_2eproto.base);
+  address_ = nullptr;
+}
+
+PCallArgs_PAccessListItem::~PCallArgs_PAccessListItem() {
+  // @@protoc_insertion_point(destructor:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
+  SharedDtor();
+}
+
+void PCallArgs_PAccessListItem::SharedDtor() {
+  if (this != internal_default_instance()) delete address_;
+}
+
+void PCallArgs_PAccessListItem::SetCachedSize(int size) const {
+  _cached_size_.Set(size);
+}
+const PCallArgs_PAccessListItem& PCallArgs_PAccessListItem::default_instance() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PCallArgs_PAccessListItem_protobuf_5ftypes_2fpparameters_2eproto.base);
+  return *internal_default_instance();
+}
+
+
+void PCallArgs_PAccessListItem::Clear() {
+// @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  storage_keys_.Clear();
+  if (GetArenaNoVirtual() == nullptr && address_ != nullptr) {
+    delete address_;
   }
+  address_ = nullptr;
+  _internal_metadata_.Clear();
+}
 
-  // bytes input = 4;
-  if (this->input().size() > 0) {
-    target =
-      ::google::protobuf::internal::WireFormatLite::WriteBytesToArray(
-        4, this->input(), target);
+const char* PCallArgs_PAccessListItem::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
+      // .top.tvm_engine.basic.PAddress address = 1;
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 10)) {
+          ptr = ctx->ParseMessage(_internal_mutable_address(), ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // repeated bytes storage_keys = 2;
+      case 2:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 18)) {
+          ptr -= 1;
+          do {
+            ptr += 1;
+            auto str = _internal_add_storage_keys();
+            ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+            CHK_(ptr);
+            if (!ctx->DataAvailable(ptr)) break;
+          } while (::PROTOBUF_NAMESPACE_ID::internal::ExpectTag<18>(ptr));
+        } else goto handle_unusual;
+        continue;
+      default: {
+      handle_unusual:
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
+          goto success;
+        }
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
+      }
+    }  // switch
+  }  // while
+success:
+  return ptr;
+failure:
+  ptr = nullptr;
+  goto success;
+#undef CHK_
+}
+
+::PROTOBUF_NAMESPACE_ID::uint8* PCallArgs_PAccessListItem::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
+  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  // .top.tvm_engine.basic.PAddress address = 1;
+  if (this->has_address()) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(
+        1, _Internal::address(this), target, stream);
   }
 
-  // uint64 gas_limit = 5;
-  if (this->gas_limit() != 0) {
-    target = ::google::protobuf::internal::WireFormatLite::WriteUInt64ToArray(5, this->gas_limit(), target);
+  // repeated bytes storage_keys = 2;
+  for (int i = 0, n = this->_internal_storage_keys_size(); i < n; i++) {
+    const auto& s = this->_internal_storage_keys(i);
+    target = stream->WriteBytes(2, s, target);
   }
 
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    target = ::google::protobuf::internal::WireFormat::SerializeUnknownFieldsToArray(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()), target);
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
   }
-  // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PCallArgs)
+  // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
   return target;
 }
 
-size_t PCallArgs::ByteSizeLong() const {
-// @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PCallArgs)
+size_t PCallArgs_PAccessListItem::ByteSizeLong() const {
+// @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
   size_t total_size = 0;
 
-  if ((_internal_metadata_.have_unknown_fields() &&  ::google::protobuf::internal::GetProto3PreserveUnknownsDefault())) {
-    total_size +=
-      ::google::protobuf::internal::WireFormat::ComputeUnknownFieldsSize(
-        (::google::protobuf::internal::GetProto3PreserveUnknownsDefault()   ? _internal_metadata_.unknown_fields()   : _internal_metadata_.default_instance()));
-  }
-  // bytes input = 4;
-  if (this->input().size() > 0) {
-    total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::BytesSize(
-        this->input());
-  }
-
-  // .top.tvm_engine.basic.PAddress sender_address = 1;
-  if (this->has_sender_address()) {
-    total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::MessageSize(
-        *sender_address_);
-  }
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
 
-  // .top.tvm_engine.basic.PAddress recver_address = 2;
-  if (this->has_recver_address()) {
-    total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::MessageSize(
-        *recver_address_);
+  // repeated bytes storage_keys = 2;
+  total_size += 1 *
+      ::PROTOBUF_NAMESPACE_ID::internal::FromIntSize(storage_keys_.size());
+  for (int i = 0, n = storage_keys_.size(); i < n; i++) {
+    total_size += ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+      storage_keys_.Get(i));
   }
 
-  // uint64 value = 3;
-  if (this->value() != 0) {
+  // .top.tvm_engine.basic.PAddress address = 1;
+  if (this->has_address()) {
     total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::UInt64Size(
-        this->value());
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(
+        *address_);
   }
 
-  // uint64 gas_limit = 5;
-  if (this->gas_limit() != 0) {
-    total_size += 1 +
-      ::google::protobuf::internal::WireFormatLite::UInt64Size(
-        this->gas_limit());
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
   }
-
-  int cached_size = ::google::protobuf::internal::ToCachedSize(total_size);
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
   SetCachedSize(cached_size);
   return total_size;
 }
 
-void PCallArgs::MergeFrom(const ::google::protobuf::Message& from) {
-// @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PCallArgs)
+void PCallArgs_PAccessListItem::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
   GOOGLE_DCHECK_NE(&from, this);
-  const PCallArgs* source =
-      ::google::protobuf::internal::DynamicCastToGenerated<const PCallArgs>(
+  const PCallArgs_PAccessListItem* source =
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PCallArgs_PAccessListItem>(
           &from);
-  if (source == NULL) {
-  // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PCallArgs)
-    ::google::protobuf::internal::ReflectionOps::Merge(from, this);
+  if (source == nullptr) {
+  // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
   } else {
-  // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PCallArgs)
+  // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
     MergeFrom(*source);
   }
 }
 
-void PCallArgs::MergeFrom(const PCallArgs& from) {
-// @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PCallArgs)
+void PCallArgs_PAccessListItem::MergeFrom(const PCallArgs_PAccessListItem& from) {
+// @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
   GOOGLE_DCHECK_NE(&from, this);
   _internal_metadata_.MergeFrom(from._internal_metadata_);
-  ::google::protobuf::uint32 cached_has_bits = 0;
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
   (void) cached_has_bits;
 
-  if (from.input().size() > 0) {
-
-    input_.AssignWithDefault(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), from.input_);
-  }
-  if (from.has_sender_address()) {
-    mutable_sender_address()->::top::tvm_engine::basic::PAddress::MergeFrom(from.sender_address());
-  }
-  if (from.has_recver_address()) {
-    mutable_recver_address()->::top::tvm_engine::basic::PAddress::MergeFrom(from.recver_address());
-  }
-  if (from.value() != 0) {
-    set_value(from.value());
-  }
-  if (from.gas_limit() != 0) {
-    set_gas_limit(from.gas_limit());
+  storage_keys_.MergeFrom(from.storage_keys_);
+  if (from.has_address()) {
+    _internal_mutable_address()->::top::tvm_engine::basic::PAddress::MergeFrom(from._internal_address());
   }
 }
 
-void PCallArgs::CopyFrom(const ::google::protobuf::Message& from) {
-// @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PCallArgs)
+void PCallArgs_PAccessListItem::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
   if (&from == this) return;
   Clear();
   MergeFrom(from);
 }
 
-void PCallArgs::CopyFrom(const PCallArgs& from) {
-// @@protoc_insertion_point(class_specific_copy_from_start:top.tvm_engine.parameters.PCallArgs)
+void PCallArgs_PAccessListItem::CopyFrom(const PCallArgs_PAccessListItem& from) {
+// @@protoc_insertion_point(class_specific_copy_from_start:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
   if (&from == this) return;
   Clear();
   MergeFrom(from);
 }
 
-bool PCallArgs::IsInitialized() const {
+bool PCallArgs_PAccessListItem::IsInitialized() const {
   return true;
 }
 
-void PCallArgs::Swap(PCallArgs* other) {
-  if (other == this) return;
-  InternalSwap(other);
-}
-void PCallArgs::InternalSwap(PCallArgs* other) {
+void PCallArgs_PAccessListItem::InternalSwap(PCallArgs_PAccessListItem* other) {
   using std::swap;
-  input_.Swap(&other->input_, &::google::protobuf::internal::GetEmptyStringAlreadyInited(),
-    GetArenaNoVirtual());
-  swap(sender_address_, other->sender_address_);
-  swap(recver_address_, other->recver_address_);
-  swap(value_, other->value_);
-  swap(gas_limit_, other->gas_limit_);
   _internal_metadata_.Swap(&other->_internal_metadata_);
+  storage_keys_.InternalSwap(&other->storage_keys_);
+  swap(address_, other->address_);
+}
+
+::PROTOBUF_NAMESPACE_ID::Metadata PCallArgs_PAccessListItem::GetMetadata() const {
+  return GetMetadataStatic();
+}
+
+
+// ===================================================================
+
+void PCallArgs::InitAsDefaultInstance() {
+  ::top::tvm_engine::parameters::_PCallArgs_default_instance_._instance.get_mutable()->sender_address_ = const_cast< ::top::tvm_engine::basic::PAddress*>(
+      ::top::tvm_engine::basic::PAddress::internal_default_instance());
+  ::top::tvm_engine::parameters::_PCallArgs_default_instance_._instance.get_mutable()->recver_address_ = const_cast< ::top::tvm_engine::basic::PAddress*>(
+      ::top::tvm_engine::basic::PAddress::internal_default_instance());
+  ::top::tvm_engine::parameters::_PCallArgs_default_instance_._instance.get_mutable()->nonce_ = const_cast< ::top::tvm_engine::parameters::PReturnResult_PU256*>(
+      ::top::tvm_engine::parameters::PReturnResult_PU256::internal_default_instance());
+}
+class PCallArgs::_Internal {
+ public:
+  static const ::top::tvm_engine::basic::PAddress& sender_address(const PCallArgs* msg);
+  static const ::top::tvm_engine::basic::PAddress& recver_address(const PCallArgs* msg);
+  static const ::top::tvm_engine::parameters::PReturnResult_PU256& nonce(const PCallArgs* msg);
+};
+
+const ::top::tvm_engine::basic::PAddress&
+PCallArgs::_Internal::sender_address(const PCallArgs* msg) {
+  return *msg->sender_address_;
+}
+const ::top::tvm_engine::basic::PAddress&
+PCallArgs::_Internal::recver_address(const PCallArgs* msg) {
+  return *msg->recver_address_;
+}
+const ::top::tvm_engine::parameters::PReturnResult_PU256&
+PCallArgs::_Internal::nonce(const PCallArgs* msg) {
+  return *msg->nonce_;
+}
+void PCallArgs::clear_sender_address() {
+  if (GetArenaNoVirtual() == nullptr && sender_address_ != nullptr) {
+    delete sender_address_;
+  }
+  sender_address_ = nullptr;
+}
+void PCallArgs::clear_recver_address() {
+  if (GetArenaNoVirtual() == nullptr && recver_address_ != nullptr) {
+    delete recver_address_;
+  }
+  recver_address_ = nullptr;
+}
+PCallArgs::PCallArgs()
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
+  SharedCtor();
+  // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PCallArgs)
+}
+PCallArgs::PCallArgs(const PCallArgs& from)
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr),
+      access_list_(from.access_list_) {
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  input_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_input().empty()) {
+    input_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.input_);
+  }
+  salt_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_salt().empty()) {
+    salt_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.salt_);
+  }
+  if (from._internal_has_sender_address()) {
+    sender_address_ = new ::top::tvm_engine::basic::PAddress(*from.sender_address_);
+  } else {
+    sender_address_ = nullptr;
+  }
+  if (from._internal_has_recver_address()) {
+    recver_address_ = new ::top::tvm_engine::basic::PAddress(*from.recver_address_);
+  } else {
+    recver_address_ = nullptr;
+  }
+  if (from._internal_has_nonce()) {
+    nonce_ = new ::top::tvm_engine::parameters::PReturnResult_PU256(*from.nonce_);
+  } else {
+    nonce_ = nullptr;
+  }
+  ::memcpy(&value_, &from.value_,
+    static_cast<size_t>(reinterpret_cast<char*>(&chain_id_) -
+    reinterpret_cast<char*>(&value_)) + sizeof(chain_id_));
+  // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PCallArgs)
+}
+
+void PCallArgs::SharedCtor() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&scc_info_PCallArgs_protobuf_5ftypes_2fpparameters_2eproto.base);
+  input_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  salt_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  ::memset(&sender_address_, 0, static_cast<size_t>(
+      reinterpret_cast<char*>(&chain_id_) -
+      reinterpret_cast<char*>(&sender_address_)) + sizeof(chain_id_));
+}
+
+PCallArgs::~PCallArgs() {
+  // @@protoc_insertion_point(destructor:top.tvm_engine.parameters.PCallArgs)
+  SharedDtor();
+}
+
+void PCallArgs::SharedDtor() {
+  input_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  salt_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (this != internal_default_instance()) delete sender_address_;
+  if (this != internal_default_instance()) delete recver_address_;
+  if (this != internal_default_instance()) delete nonce_;
+}
+
+void PCallArgs::SetCachedSize(int size) const {
+  _cached_size_.Set(size);
+}
+const PCallArgs& PCallArgs::default_instance() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PCallArgs_protobuf_5ftypes_2fpparameters_2eproto.base);
+  return *internal_default_instance();
+}
+
+
+void PCallArgs::Clear() {
+// @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PCallArgs)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  access_list_.Clear();
+  input_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  salt_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (GetArenaNoVirtual() == nullptr && sender_address_ != nullptr) {
+    delete sender_address_;
+  }
+  sender_address_ = nullptr;
+  if (GetArenaNoVirtual() == nullptr && recver_address_ != nullptr) {
+    delete recver_address_;
+  }
+  recver_address_ = nullptr;
+  if (GetArenaNoVirtual() == nullptr && nonce_ != nullptr) {
+    delete nonce_;
+  }
+  nonce_ = nullptr;
+  ::memset(&value_, 0, static_cast<size_t>(
+      reinterpret_cast<char*>(&chain_id_) -
+      reinterpret_cast<char*>(&value_)) + sizeof(chain_id_));
+  _internal_metadata_.Clear();
+}
+
+const char* PCallArgs::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
+      // .top.tvm_engine.basic.PAddress sender_address = 1;
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 10)) {
+          ptr = ctx->ParseMessage(_internal_mutable_sender_address(), ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // .top.tvm_engine.basic.PAddress recver_address = 2;
+      case 2:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 18)) {
+          ptr = ctx->ParseMessage(_internal_mutable_recver_address(), ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // uint64 value = 3;
+      case 3:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 24)) {
+          value_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // bytes input = 4;
+      case 4:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 34)) {
+          auto str = _internal_mutable_input();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // uint64 gas_limit = 5;
+      case 5:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 40)) {
+          gas_limit_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // uint64 gas_price = 6;
+      case 6:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 48)) {
+          gas_price_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // uint64 max_fee_per_gas = 7;
+      case 7:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 56)) {
+          max_fee_per_gas_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // uint64 max_priority_fee_per_gas = 8;
+      case 8:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 64)) {
+          max_priority_fee_per_gas_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // repeated .top.tvm_engine.parameters.PCallArgs.PAccessListItem access_list = 9;
+      case 9:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 74)) {
+          ptr -= 1;
+          do {
+            ptr += 1;
+            ptr = ctx->ParseMessage(_internal_add_access_list(), ptr);
+            CHK_(ptr);
+            if (!ctx->DataAvailable(ptr)) break;
+          } while (::PROTOBUF_NAMESPACE_ID::internal::ExpectTag<74>(ptr));
+        } else goto handle_unusual;
+        continue;
+      // .top.tvm_engine.parameters.PReturnResult.PU256 nonce = 10;
+      case 10:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 82)) {
+          ptr = ctx->ParseMessage(_internal_mutable_nonce(), ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // uint64 chain_id = 11;
+      case 11:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 88)) {
+          chain_id_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // bytes salt = 12;
+      case 12:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 98)) {
+          auto str = _internal_mutable_salt();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      default: {
+      handle_unusual:
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
+          goto success;
+        }
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
+      }
+    }  // switch
+  }  // while
+success:
+  return ptr;
+failure:
+  ptr = nullptr;
+  goto success;
+#undef CHK_
+}
+
+::PROTOBUF_NAMESPACE_ID::uint8* PCallArgs::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
+  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PCallArgs)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  // .top.tvm_engine.basic.PAddress sender_address = 1;
+  if (this->has_sender_address()) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(
+        1, _Internal::sender_address(this), target, stream);
+  }
+
+  // .top.tvm_engine.basic.PAddress recver_address = 2;
+  if (this->has_recver_address()) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(
+        2, _Internal::recver_address(this), target, stream);
+  }
+
+  // uint64 value = 3;
+  if (this->value() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(3, this->_internal_value(), target);
+  }
+
+  // bytes input = 4;
+  if (this->input().size() > 0) {
+    target = stream->WriteBytesMaybeAliased(
+        4, this->_internal_input(), target);
+  }
+
+  // uint64 gas_limit = 5;
+  if (this->gas_limit() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(5, this->_internal_gas_limit(), target);
+  }
+
+  // uint64 gas_price = 6;
+  if (this->gas_price() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(6, this->_internal_gas_price(), target);
+  }
+
+  // uint64 max_fee_per_gas = 7;
+  if (this->max_fee_per_gas() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(7, this->_internal_max_fee_per_gas(), target);
+  }
+
+  // uint64 max_priority_fee_per_gas = 8;
+  if (this->max_priority_fee_per_gas() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(8, this->_internal_max_priority_fee_per_gas(), target);
+  }
+
+  // repeated .top.tvm_engine.parameters.PCallArgs.PAccessListItem access_list = 9;
+  for (unsigned int i = 0,
+      n = static_cast<unsigned int>(this->_internal_access_list_size()); i < n; i++) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(9, this->_internal_access_list(i), target, stream);
+  }
+
+  // .top.tvm_engine.parameters.PReturnResult.PU256 nonce = 10;
+  if (this->has_nonce()) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(
+        10, _Internal::nonce(this), target, stream);
+  }
+
+  // uint64 chain_id = 11;
+  if (this->chain_id() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(11, this->_internal_chain_id(), target);
+  }
+
+  // bytes salt = 12;
+  if (this->salt().size() > 0) {
+    target = stream->WriteBytesMaybeAliased(
+        12, this->_internal_salt(), target);
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
+  }
+  // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PCallArgs)
+  return target;
+}
+
+size_t PCallArgs::ByteSizeLong() const {
+// @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PCallArgs)
+  size_t total_size = 0;
+
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  // repeated .top.tvm_engine.parameters.PCallArgs.PAccessListItem access_list = 9;
+  total_size += 1UL * this->_internal_access_list_size();
+  for (const auto& msg : this->access_list_) {
+    total_size +=
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(msg);
+  }
+
+  // bytes input = 4;
+  if (this->input().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_input());
+  }
+
+  // bytes salt = 12;
+  if (this->salt().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_salt());
+  }
+
+  // .top.tvm_engine.basic.PAddress sender_address = 1;
+  if (this->has_sender_address()) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(
+        *sender_address_);
+  }
+
+  // .top.tvm_engine.basic.PAddress recver_address = 2;
+  if (this->has_recver_address()) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(
+        *recver_address_);
+  }
+
+  // .top.tvm_engine.parameters.PReturnResult.PU256 nonce = 10;
+  if (this->has_nonce()) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(
+        *nonce_);
+  }
+
+  // uint64 value = 3;
+  if (this->value() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_value());
+  }
+
+  // uint64 gas_limit = 5;
+  if (this->gas_limit() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_gas_limit());
+  }
+
+  // uint64 gas_price = 6;
+  if (this->gas_price() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_gas_price());
+  }
+
+  // uint64 max_fee_per_gas = 7;
+  if (this->max_fee_per_gas() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_max_fee_per_gas());
+  }
+
+  // uint64 max_priority_fee_per_gas = 8;
+  if (this->max_priority_fee_per_gas() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_max_priority_fee_per_gas());
+  }
+
+  // uint64 chain_id = 11;
+  if (this->chain_id() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_chain_id());
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
+  }
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
+  SetCachedSize(cached_size);
+  return total_size;
+}
+
+void PCallArgs::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PCallArgs)
+  GOOGLE_DCHECK_NE(&from, this);
+  const PCallArgs* source =
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PCallArgs>(
+          &from);
+  if (source == nullptr) {
+  // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PCallArgs)
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
+  } else {
+  // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PCallArgs)
+    MergeFrom(*source);
+  }
+}
+
+void PCallArgs::MergeFrom(const PCallArgs& from) {
+// @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PCallArgs)
+  GOOGLE_DCHECK_NE(&from, this);
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  access_list_.MergeFrom(from.access_list_);
+  if (from.input().size() > 0) {
+
+    input_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.input_);
+  }
+  if (from.salt().size() > 0) {
+
+    salt_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.salt_);
+  }
+  if (from.has_sender_address()) {
+    _internal_mutable_sender_address()->::top::tvm_engine::basic::PAddress::MergeFrom(from._internal_sender_address());
+  }
+  if (from.has_recver_address()) {
+    _internal_mutable_recver_address()->::top::tvm_engine::basic::PAddress::MergeFrom(from._internal_recver_address());
+  }
+  if (from.has_nonce()) {
+    _internal_mutable_nonce()->::top::tvm_engine::parameters::PReturnResult_PU256::MergeFrom(from._internal_nonce());
+  }
+  if (from.value() != 0) {
+    _internal_set_value(from._internal_value());
+  }
+  if (from.gas_limit() != 0) {
+    _internal_set_gas_limit(from._internal_gas_limit());
+  }
+  if (from.gas_price() != 0) {
+    _internal_set_gas_price(from._internal_gas_price());
+  }
+  if (from.max_fee_per_gas() != 0) {
+    _internal_set_max_fee_per_gas(from._internal_max_fee_per_gas());
+  }
+  if (from.max_priority_fee_per_gas() != 0) {
+    _internal_set_max_priority_fee_per_gas(from._internal_max_priority_fee_per_gas());
+  }
+  if (from.chain_id() != 0) {
+    _internal_set_chain_id(from._internal_chain_id());
+  }
+}
+
+void PCallArgs::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PCallArgs)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+void PCallArgs::CopyFrom(const PCallArgs& from) {
+// @@protoc_insertion_point(class_specific_copy_from_start:top.tvm_engine.parameters.PCallArgs)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+bool PCallArgs::IsInitialized() const {
+  return true;
+}
+
+void PCallArgs::InternalSwap(PCallArgs* other) {
+  using std::swap;
+  _internal_metadata_.Swap(&other->_internal_metadata_);
+  access_list_.InternalSwap(&other->access_list_);
+  input_.Swap(&other->input_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  salt_.Swap(&other->salt_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  swap(sender_address_, other->sender_address_);
+  swap(recver_address_, other->recver_address_);
+  swap(nonce_, other->nonce_);
+  swap(value_, other->value_);
+  swap(gas_limit_, other->gas_limit_);
+  swap(gas_price_, other->gas_price_);
+  swap(max_fee_per_gas_, other->max_fee_per_gas_);
+  swap(max_priority_fee_per_gas_, other->max_priority_fee_per_gas_);
+  swap(chain_id_, other->chain_id_);
+}
+
+::PROTOBUF_NAMESPACE_ID::Metadata PCallArgs::GetMetadata() const {
+  return GetMetadataStatic();
+}
+
+
+// ===================================================================
+
+void PEstimateGasResult::InitAsDefaultInstance() {
+}
+class PEstimateGasResult::_Internal {
+ public:
+};
+
+PEstimateGasResult::PEstimateGasResult()
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
+  SharedCtor();
+  // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PEstimateGasResult)
+}
+PEstimateGasResult::PEstimateGasResult(const PEstimateGasResult& from)
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr) {
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  status_data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_status_data().empty()) {
+    status_data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.status_data_);
+  }
+  error_message_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_error_message().empty()) {
+    error_message_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.error_message_);
+  }
+  ::memcpy(&gas_used_, &from.gas_used_,
+    static_cast<size_t>(reinterpret_cast<char*>(&status_) -
+    reinterpret_cast<char*>(&gas_used_)) + sizeof(status_));
+  // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PEstimateGasResult)
+}
+
+void PEstimateGasResult::SharedCtor() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&scc_info_PEstimateGasResult_protobuf_5ftypes_2fpparameters_2eproto.base);
+  status_data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  ::memset(&gas_used_, 0, static_cast<size_t>(
+      reinterpret_cast<char*>(&status_) -
+      reinterpret_cast<char*>(&gas_used_)) + sizeof(status_));
+}
+
+PEstimateGasResult::~PEstimateGasResult() {
+  // @@protoc_insertion_point(destructor:top.tvm_engine.parameters.PEstimateGasResult)
+  SharedDtor();
+}
+
+void PEstimateGasResult::SharedDtor() {
+  status_data_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+
+void PEstimateGasResult::SetCachedSize(int size) const {
+  _cached_size_.Set(size);
+}
+const PEstimateGasResult& PEstimateGasResult::default_instance() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PEstimateGasResult_protobuf_5ftypes_2fpparameters_2eproto.base);
+  return *internal_default_instance();
+}
+
+
+void PEstimateGasResult::Clear() {
+// @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PEstimateGasResult)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  status_data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  ::memset(&gas_used_, 0, static_cast<size_t>(
+      reinterpret_cast<char*>(&status_) -
+      reinterpret_cast<char*>(&gas_used_)) + sizeof(status_));
+  _internal_metadata_.Clear();
+}
+
+const char* PEstimateGasResult::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
+      // .top.tvm_engine.parameters.PStatusCode status = 1;
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 8)) {
+          ::PROTOBUF_NAMESPACE_ID::uint64 val = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+          _internal_set_status(static_cast<::top::tvm_engine::parameters::PStatusCode>(val));
+        } else goto handle_unusual;
+        continue;
+      // bytes status_data = 2;
+      case 2:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 18)) {
+          auto str = _internal_mutable_status_data();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // uint64 gas_used = 3;
+      case 3:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 24)) {
+          gas_used_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // uint64 gas_limit = 4;
+      case 4:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 32)) {
+          gas_limit_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // string error_message = 5;
+      case 5:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 42)) {
+          auto str = _internal_mutable_error_message();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(::PROTOBUF_NAMESPACE_ID::internal::VerifyUTF8(str, "top.tvm_engine.parameters.PEstimateGasResult.error_message"));
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      default: {
+      handle_unusual:
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
+          goto success;
+        }
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
+      }
+    }  // switch
+  }  // while
+success:
+  return ptr;
+failure:
+  ptr = nullptr;
+  goto success;
+#undef CHK_
+}
+
+::PROTOBUF_NAMESPACE_ID::uint8* PEstimateGasResult::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
+  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PEstimateGasResult)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
+  if (this->status() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteEnumToArray(
+      1, this->_internal_status(), target);
+  }
+
+  // bytes status_data = 2;
+  if (this->status_data().size() > 0) {
+    target = stream->WriteBytesMaybeAliased(
+        2, this->_internal_status_data(), target);
+  }
+
+  // uint64 gas_used = 3;
+  if (this->gas_used() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(3, this->_internal_gas_used(), target);
+  }
+
+  // uint64 gas_limit = 4;
+  if (this->gas_limit() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(4, this->_internal_gas_limit(), target);
+  }
+
+  // string error_message = 5;
+  if (this->error_message().size() > 0) {
+    ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::VerifyUtf8String(
+      this->_internal_error_message().data(), static_cast<int>(this->_internal_error_message().length()),
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::SERIALIZE,
+      "top.tvm_engine.parameters.PEstimateGasResult.error_message");
+    target = stream->WriteStringMaybeAliased(
+        5, this->_internal_error_message(), target);
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
+  }
+  // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PEstimateGasResult)
+  return target;
+}
+
+size_t PEstimateGasResult::ByteSizeLong() const {
+// @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PEstimateGasResult)
+  size_t total_size = 0;
+
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  // bytes status_data = 2;
+  if (this->status_data().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_status_data());
+  }
+
+  // string error_message = 5;
+  if (this->error_message().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::StringSize(
+        this->_internal_error_message());
+  }
+
+  // uint64 gas_used = 3;
+  if (this->gas_used() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_gas_used());
+  }
+
+  // uint64 gas_limit = 4;
+  if (this->gas_limit() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_gas_limit());
+  }
+
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
+  if (this->status() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::EnumSize(this->_internal_status());
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
+  }
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
+  SetCachedSize(cached_size);
+  return total_size;
+}
+
+void PEstimateGasResult::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PEstimateGasResult)
+  GOOGLE_DCHECK_NE(&from, this);
+  const PEstimateGasResult* source =
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PEstimateGasResult>(
+          &from);
+  if (source == nullptr) {
+  // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PEstimateGasResult)
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
+  } else {
+  // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PEstimateGasResult)
+    MergeFrom(*source);
+  }
+}
+
+void PEstimateGasResult::MergeFrom(const PEstimateGasResult& from) {
+// @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PEstimateGasResult)
+  GOOGLE_DCHECK_NE(&from, this);
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  if (from.status_data().size() > 0) {
+
+    status_data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.status_data_);
+  }
+  if (from.error_message().size() > 0) {
+
+    error_message_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.error_message_);
+  }
+  if (from.gas_used() != 0) {
+    _internal_set_gas_used(from._internal_gas_used());
+  }
+  if (from.gas_limit() != 0) {
+    _internal_set_gas_limit(from._internal_gas_limit());
+  }
+  if (from.status() != 0) {
+    _internal_set_status(from._internal_status());
+  }
+}
+
+void PEstimateGasResult::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PEstimateGasResult)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+void PEstimateGasResult::CopyFrom(const PEstimateGasResult& from) {
+// @@protoc_insertion_point(class_specific_copy_from_start:top.tvm_engine.parameters.PEstimateGasResult)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+bool PEstimateGasResult::IsInitialized() const {
+  return true;
+}
+
+void PEstimateGasResult::InternalSwap(PEstimateGasResult* other) {
+  using std::swap;
+  _internal_metadata_.Swap(&other->_internal_metadata_);
+  status_data_.Swap(&other->status_data_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  error_message_.Swap(&other->error_message_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  swap(gas_used_, other->gas_used_);
+  swap(gas_limit_, other->gas_limit_);
+  swap(status_, other->status_);
+}
+
+::PROTOBUF_NAMESPACE_ID::Metadata PEstimateGasResult::GetMetadata() const {
+  return GetMetadataStatic();
+}
+
+
+// ===================================================================
+
+void PAccessListResult::InitAsDefaultInstance() {
+}
+class PAccessListResult::_Internal {
+ public:
+};
+
+PAccessListResult::PAccessListResult()
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
+  SharedCtor();
+  // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PAccessListResult)
+}
+PAccessListResult::PAccessListResult(const PAccessListResult& from)
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr),
+      access_list_(from.access_list_) {
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  status_data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_status_data().empty()) {
+    status_data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.status_data_);
+  }
+  error_message_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_error_message().empty()) {
+    error_message_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.error_message_);
+  }
+  ::memcpy(&gas_used_, &from.gas_used_,
+    static_cast<size_t>(reinterpret_cast<char*>(&status_) -
+    reinterpret_cast<char*>(&gas_used_)) + sizeof(status_));
+  // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PAccessListResult)
+}
+
+void PAccessListResult::SharedCtor() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&scc_info_PAccessListResult_protobuf_5ftypes_2fpparameters_2eproto.base);
+  status_data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  ::memset(&gas_used_, 0, static_cast<size_t>(
+      reinterpret_cast<char*>(&status_) -
+      reinterpret_cast<char*>(&gas_used_)) + sizeof(status_));
+}
+
+PAccessListResult::~PAccessListResult() {
+  // @@protoc_insertion_point(destructor:top.tvm_engine.parameters.PAccessListResult)
+  SharedDtor();
+}
+
+void PAccessListResult::SharedDtor() {
+  status_data_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+
+void PAccessListResult::SetCachedSize(int size) const {
+  _cached_size_.Set(size);
+}
+const PAccessListResult& PAccessListResult::default_instance() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PAccessListResult_protobuf_5ftypes_2fpparameters_2eproto.base);
+  return *internal_default_instance();
+}
+
+
+void PAccessListResult::Clear() {
+// @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PAccessListResult)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  access_list_.Clear();
+  status_data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  ::memset(&gas_used_, 0, static_cast<size_t>(
+      reinterpret_cast<char*>(&status_) -
+      reinterpret_cast<char*>(&gas_used_)) + sizeof(status_));
+  _internal_metadata_.Clear();
+}
+
+const char* PAccessListResult::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
+      // .top.tvm_engine.parameters.PStatusCode status = 1;
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 8)) {
+          ::PROTOBUF_NAMESPACE_ID::uint64 val = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+          _internal_set_status(static_cast<::top::tvm_engine::parameters::PStatusCode>(val));
+        } else goto handle_unusual;
+        continue;
+      // bytes status_data = 2;
+      case 2:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 18)) {
+          auto str = _internal_mutable_status_data();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // uint64 gas_used = 3;
+      case 3:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 24)) {
+          gas_used_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // repeated .top.tvm_engine.parameters.PCallArgs.PAccessListItem access_list = 4;
+      case 4:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 34)) {
+          ptr -= 1;
+          do {
+            ptr += 1;
+            ptr = ctx->ParseMessage(_internal_add_access_list(), ptr);
+            CHK_(ptr);
+            if (!ctx->DataAvailable(ptr)) break;
+          } while (::PROTOBUF_NAMESPACE_ID::internal::ExpectTag<34>(ptr));
+        } else goto handle_unusual;
+        continue;
+      // string error_message = 5;
+      case 5:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 42)) {
+          auto str = _internal_mutable_error_message();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(::PROTOBUF_NAMESPACE_ID::internal::VerifyUTF8(str, "top.tvm_engine.parameters.PAccessListResult.error_message"));
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      default: {
+      handle_unusual:
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
+          goto success;
+        }
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
+      }
+    }  // switch
+  }  // while
+success:
+  return ptr;
+failure:
+  ptr = nullptr;
+  goto success;
+#undef CHK_
+}
+
+::PROTOBUF_NAMESPACE_ID::uint8* PAccessListResult::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
+  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PAccessListResult)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
+  if (this->status() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteEnumToArray(
+      1, this->_internal_status(), target);
+  }
+
+  // bytes status_data = 2;
+  if (this->status_data().size() > 0) {
+    target = stream->WriteBytesMaybeAliased(
+        2, this->_internal_status_data(), target);
+  }
+
+  // uint64 gas_used = 3;
+  if (this->gas_used() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(3, this->_internal_gas_used(), target);
+  }
+
+  // repeated .top.tvm_engine.parameters.PCallArgs.PAccessListItem access_list = 4;
+  for (unsigned int i = 0,
+      n = static_cast<unsigned int>(this->_internal_access_list_size()); i < n; i++) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(4, this->_internal_access_list(i), target, stream);
+  }
+
+  // string error_message = 5;
+  if (this->error_message().size() > 0) {
+    ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::VerifyUtf8String(
+      this->_internal_error_message().data(), static_cast<int>(this->_internal_error_message().length()),
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::SERIALIZE,
+      "top.tvm_engine.parameters.PAccessListResult.error_message");
+    target = stream->WriteStringMaybeAliased(
+        5, this->_internal_error_message(), target);
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
+  }
+  // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PAccessListResult)
+  return target;
+}
+
+size_t PAccessListResult::ByteSizeLong() const {
+// @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PAccessListResult)
+  size_t total_size = 0;
+
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  // repeated .top.tvm_engine.parameters.PCallArgs.PAccessListItem access_list = 4;
+  total_size += 1UL * this->_internal_access_list_size();
+  for (const auto& msg : this->access_list_) {
+    total_size +=
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(msg);
+  }
+
+  // bytes status_data = 2;
+  if (this->status_data().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_status_data());
+  }
+
+  // string error_message = 5;
+  if (this->error_message().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::StringSize(
+        this->_internal_error_message());
+  }
+
+  // uint64 gas_used = 3;
+  if (this->gas_used() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_gas_used());
+  }
+
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
+  if (this->status() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::EnumSize(this->_internal_status());
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
+  }
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
+  SetCachedSize(cached_size);
+  return total_size;
+}
+
+void PAccessListResult::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PAccessListResult)
+  GOOGLE_DCHECK_NE(&from, this);
+  const PAccessListResult* source =
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PAccessListResult>(
+          &from);
+  if (source == nullptr) {
+  // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PAccessListResult)
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
+  } else {
+  // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PAccessListResult)
+    MergeFrom(*source);
+  }
+}
+
+void PAccessListResult::MergeFrom(const PAccessListResult& from) {
+// @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PAccessListResult)
+  GOOGLE_DCHECK_NE(&from, this);
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  access_list_.MergeFrom(from.access_list_);
+  if (from.status_data().size() > 0) {
+
+    status_data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.status_data_);
+  }
+  if (from.error_message().size() > 0) {
+
+    error_message_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.error_message_);
+  }
+  if (from.gas_used() != 0) {
+    _internal_set_gas_used(from._internal_gas_used());
+  }
+  if (from.status() != 0) {
+    _internal_set_status(from._internal_status());
+  }
+}
+
+void PAccessListResult::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PAccessListResult)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+void PAccessListResult::CopyFrom(const PAccessListResult& from) {
+// @@protoc_insertion_point(class_specific_copy_from_start:top.tvm_engine.parameters.PAccessListResult)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+bool PAccessListResult::IsInitialized() const {
+  return true;
+}
+
+void PAccessListResult::InternalSwap(PAccessListResult* other) {
+  using std::swap;
+  _internal_metadata_.Swap(&other->_internal_metadata_);
+  access_list_.InternalSwap(&other->access_list_);
+  status_data_.Swap(&other->status_data_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  error_message_.Swap(&other->error_message_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  swap(gas_used_, other->gas_used_);
+  swap(status_, other->status_);
+}
+
+::PROTOBUF_NAMESPACE_ID::Metadata PAccessListResult::GetMetadata() const {
+  return GetMetadataStatic();
+}
+
+
+// ===================================================================
+
+void PDeployAddressResult::InitAsDefaultInstance() {
+  ::top::tvm_engine::parameters::_PDeployAddressResult_default_instance_._instance.get_mutable()->contract_address_ = const_cast< ::top::tvm_engine::basic::PAddress*>(
+      ::top::tvm_engine::basic::PAddress::internal_default_instance());
+}
+class PDeployAddressResult::_Internal {
+ public:
+  static const ::top::tvm_engine::basic::PAddress& contract_address(const PDeployAddressResult* msg);
+};
+
+const ::top::tvm_engine::basic::PAddress&
+PDeployAddressResult::_Internal::contract_address(const PDeployAddressResult* msg) {
+  return *msg->contract_address_;
+}
+void PDeployAddressResult::clear_contract_address() {
+  if (GetArenaNoVirtual() == nullptr && contract_address_ != nullptr) {
+    delete contract_address_;
+  }
+  contract_address_ = nullptr;
+}
+PDeployAddressResult::PDeployAddressResult()
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
+  SharedCtor();
+  // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PDeployAddressResult)
+}
+PDeployAddressResult::PDeployAddressResult(const PDeployAddressResult& from)
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr) {
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  status_data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_status_data().empty()) {
+    status_data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.status_data_);
+  }
+  salt_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_salt().empty()) {
+    salt_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.salt_);
+  }
+  error_message_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (!from._internal_error_message().empty()) {
+    error_message_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.error_message_);
+  }
+  if (from._internal_has_contract_address()) {
+    contract_address_ = new ::top::tvm_engine::basic::PAddress(*from.contract_address_);
+  } else {
+    contract_address_ = nullptr;
+  }
+  status_ = from.status_;
+  // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PDeployAddressResult)
+}
+
+void PDeployAddressResult::SharedCtor() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&scc_info_PDeployAddressResult_protobuf_5ftypes_2fpparameters_2eproto.base);
+  status_data_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  salt_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.UnsafeSetDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  ::memset(&contract_address_, 0, static_cast<size_t>(
+      reinterpret_cast<char*>(&status_) -
+      reinterpret_cast<char*>(&contract_address_)) + sizeof(status_));
+}
+
+PDeployAddressResult::~PDeployAddressResult() {
+  // @@protoc_insertion_point(destructor:top.tvm_engine.parameters.PDeployAddressResult)
+  SharedDtor();
+}
+
+void PDeployAddressResult::SharedDtor() {
+  status_data_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  salt_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.DestroyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (this != internal_default_instance()) delete contract_address_;
+}
+
+void PDeployAddressResult::SetCachedSize(int size) const {
+  _cached_size_.Set(size);
+}
+const PDeployAddressResult& PDeployAddressResult::default_instance() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PDeployAddressResult_protobuf_5ftypes_2fpparameters_2eproto.base);
+  return *internal_default_instance();
+}
+
+
+void PDeployAddressResult::Clear() {
+// @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PDeployAddressResult)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  status_data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  salt_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  error_message_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+  if (GetArenaNoVirtual() == nullptr && contract_address_ != nullptr) {
+    delete contract_address_;
+  }
+  contract_address_ = nullptr;
+  status_ = 0;
+  _internal_metadata_.Clear();
+}
+
+const char* PDeployAddressResult::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
+      // .top.tvm_engine.parameters.PStatusCode status = 1;
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 8)) {
+          ::PROTOBUF_NAMESPACE_ID::uint64 val = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+          _internal_set_status(static_cast<::top::tvm_engine::parameters::PStatusCode>(val));
+        } else goto handle_unusual;
+        continue;
+      // bytes status_data = 2;
+      case 2:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 18)) {
+          auto str = _internal_mutable_status_data();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // .top.tvm_engine.basic.PAddress contract_address = 3;
+      case 3:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 26)) {
+          ptr = ctx->ParseMessage(_internal_mutable_contract_address(), ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // bytes salt = 4;
+      case 4:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 34)) {
+          auto str = _internal_mutable_salt();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // string error_message = 5;
+      case 5:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 42)) {
+          auto str = _internal_mutable_error_message();
+          ptr = ::PROTOBUF_NAMESPACE_ID::internal::InlineGreedyStringParser(str, ptr, ctx);
+          CHK_(::PROTOBUF_NAMESPACE_ID::internal::VerifyUTF8(str, "top.tvm_engine.parameters.PDeployAddressResult.error_message"));
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      default: {
+      handle_unusual:
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
+          goto success;
+        }
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
+      }
+    }  // switch
+  }  // while
+success:
+  return ptr;
+failure:
+  ptr = nullptr;
+  goto success;
+#undef CHK_
+}
+
+::PROTOBUF_NAMESPACE_ID::uint8* PDeployAddressResult::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
+  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PDeployAddressResult)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
+  if (this->status() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteEnumToArray(
+      1, this->_internal_status(), target);
+  }
+
+  // bytes status_data = 2;
+  if (this->status_data().size() > 0) {
+    target = stream->WriteBytesMaybeAliased(
+        2, this->_internal_status_data(), target);
+  }
+
+  // .top.tvm_engine.basic.PAddress contract_address = 3;
+  if (this->has_contract_address()) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(
+        3, _Internal::contract_address(this), target, stream);
+  }
+
+  // bytes salt = 4;
+  if (this->salt().size() > 0) {
+    target = stream->WriteBytesMaybeAliased(
+        4, this->_internal_salt(), target);
+  }
+
+  // string error_message = 5;
+  if (this->error_message().size() > 0) {
+    ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::VerifyUtf8String(
+      this->_internal_error_message().data(), static_cast<int>(this->_internal_error_message().length()),
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::SERIALIZE,
+      "top.tvm_engine.parameters.PDeployAddressResult.error_message");
+    target = stream->WriteStringMaybeAliased(
+        5, this->_internal_error_message(), target);
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
+  }
+  // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PDeployAddressResult)
+  return target;
+}
+
+size_t PDeployAddressResult::ByteSizeLong() const {
+// @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PDeployAddressResult)
+  size_t total_size = 0;
+
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  // bytes status_data = 2;
+  if (this->status_data().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_status_data());
+  }
+
+  // bytes salt = 4;
+  if (this->salt().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::BytesSize(
+        this->_internal_salt());
+  }
+
+  // string error_message = 5;
+  if (this->error_message().size() > 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::StringSize(
+        this->_internal_error_message());
+  }
+
+  // .top.tvm_engine.basic.PAddress contract_address = 3;
+  if (this->has_contract_address()) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(
+        *contract_address_);
+  }
+
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
+  if (this->status() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::EnumSize(this->_internal_status());
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
+  }
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
+  SetCachedSize(cached_size);
+  return total_size;
+}
+
+void PDeployAddressResult::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PDeployAddressResult)
+  GOOGLE_DCHECK_NE(&from, this);
+  const PDeployAddressResult* source =
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PDeployAddressResult>(
+          &from);
+  if (source == nullptr) {
+  // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PDeployAddressResult)
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
+  } else {
+  // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PDeployAddressResult)
+    MergeFrom(*source);
+  }
+}
+
+void PDeployAddressResult::MergeFrom(const PDeployAddressResult& from) {
+// @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PDeployAddressResult)
+  GOOGLE_DCHECK_NE(&from, this);
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  if (from.status_data().size() > 0) {
+
+    status_data_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.status_data_);
+  }
+  if (from.salt().size() > 0) {
+
+    salt_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.salt_);
+  }
+  if (from.error_message().size() > 0) {
+
+    error_message_.AssignWithDefault(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), from.error_message_);
+  }
+  if (from.has_contract_address()) {
+    _internal_mutable_contract_address()->::top::tvm_engine::basic::PAddress::MergeFrom(from._internal_contract_address());
+  }
+  if (from.status() != 0) {
+    _internal_set_status(from._internal_status());
+  }
+}
+
+void PDeployAddressResult::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PDeployAddressResult)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+void PDeployAddressResult::CopyFrom(const PDeployAddressResult& from) {
+// @@protoc_insertion_point(class_specific_copy_from_start:top.tvm_engine.parameters.PDeployAddressResult)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+bool PDeployAddressResult::IsInitialized() const {
+  return true;
+}
+
+void PDeployAddressResult::InternalSwap(PDeployAddressResult* other) {
+  using std::swap;
+  _internal_metadata_.Swap(&other->_internal_metadata_);
+  status_data_.Swap(&other->status_data_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  salt_.Swap(&other->salt_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  error_message_.Swap(&other->error_message_, &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+    GetArenaNoVirtual());
+  swap(contract_address_, other->contract_address_);
+  swap(status_, other->status_);
+}
+
+::PROTOBUF_NAMESPACE_ID::Metadata PDeployAddressResult::GetMetadata() const {
+  return GetMetadataStatic();
+}
+
+
+// ===================================================================
+
+void PForkSchedule_PActivation::InitAsDefaultInstance() {
+}
+class PForkSchedule_PActivation::_Internal {
+ public:
+};
+
+PForkSchedule_PActivation::PForkSchedule_PActivation()
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
+  SharedCtor();
+  // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PForkSchedule.PActivation)
+}
+PForkSchedule_PActivation::PForkSchedule_PActivation(const PForkSchedule_PActivation& from)
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr) {
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  ::memcpy(&height_, &from.height_,
+    static_cast<size_t>(reinterpret_cast<char*>(&hardfork_) -
+    reinterpret_cast<char*>(&height_)) + sizeof(hardfork_));
+  // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PForkSchedule.PActivation)
+}
+
+void PForkSchedule_PActivation::SharedCtor() {
+  ::memset(&height_, 0, static_cast<size_t>(
+      reinterpret_cast<char*>(&hardfork_) -
+      reinterpret_cast<char*>(&height_)) + sizeof(hardfork_));
+}
+
+PForkSchedule_PActivation::~PForkSchedule_PActivation() {
+  // @@protoc_insertion_point(destructor:top.tvm_engine.parameters.PForkSchedule.PActivation)
+  SharedDtor();
+}
+
+void PForkSchedule_PActivation::SharedDtor() {
+}
+
+void PForkSchedule_PActivation::SetCachedSize(int size) const {
+  _cached_size_.Set(size);
+}
+const PForkSchedule_PActivation& PForkSchedule_PActivation::default_instance() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PForkSchedule_PActivation_protobuf_5ftypes_2fpparameters_2eproto.base);
+  return *internal_default_instance();
+}
+
+
+void PForkSchedule_PActivation::Clear() {
+// @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PForkSchedule.PActivation)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  ::memset(&height_, 0, static_cast<size_t>(
+      reinterpret_cast<char*>(&hardfork_) -
+      reinterpret_cast<char*>(&height_)) + sizeof(hardfork_));
+  _internal_metadata_.Clear();
+}
+
+const char* PForkSchedule_PActivation::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
+      // uint64 height = 1;
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 8)) {
+          height_ = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+        } else goto handle_unusual;
+        continue;
+      // .top.tvm_engine.parameters.PHardfork hardfork = 2;
+      case 2:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 16)) {
+          ::PROTOBUF_NAMESPACE_ID::uint64 val = ::PROTOBUF_NAMESPACE_ID::internal::ReadVarint(&ptr);
+          CHK_(ptr);
+          _internal_set_hardfork(static_cast<::top::tvm_engine::parameters::PHardfork>(val));
+        } else goto handle_unusual;
+        continue;
+      default: {
+      handle_unusual:
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
+          goto success;
+        }
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
+      }
+    }  // switch
+  }  // while
+success:
+  return ptr;
+failure:
+  ptr = nullptr;
+  goto success;
+#undef CHK_
+}
+
+::PROTOBUF_NAMESPACE_ID::uint8* PForkSchedule_PActivation::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
+  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PForkSchedule.PActivation)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  // uint64 height = 1;
+  if (this->height() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteUInt64ToArray(1, this->_internal_height(), target);
+  }
+
+  // .top.tvm_engine.parameters.PHardfork hardfork = 2;
+  if (this->hardfork() != 0) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::WriteEnumToArray(
+      2, this->_internal_hardfork(), target);
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
+  }
+  // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PForkSchedule.PActivation)
+  return target;
+}
+
+size_t PForkSchedule_PActivation::ByteSizeLong() const {
+// @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PForkSchedule.PActivation)
+  size_t total_size = 0;
+
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  // uint64 height = 1;
+  if (this->height() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::UInt64Size(
+        this->_internal_height());
+  }
+
+  // .top.tvm_engine.parameters.PHardfork hardfork = 2;
+  if (this->hardfork() != 0) {
+    total_size += 1 +
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::EnumSize(this->_internal_hardfork());
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
+  }
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
+  SetCachedSize(cached_size);
+  return total_size;
+}
+
+void PForkSchedule_PActivation::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PForkSchedule.PActivation)
+  GOOGLE_DCHECK_NE(&from, this);
+  const PForkSchedule_PActivation* source =
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PForkSchedule_PActivation>(
+          &from);
+  if (source == nullptr) {
+  // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PForkSchedule.PActivation)
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
+  } else {
+  // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PForkSchedule.PActivation)
+    MergeFrom(*source);
+  }
+}
+
+void PForkSchedule_PActivation::MergeFrom(const PForkSchedule_PActivation& from) {
+// @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PForkSchedule.PActivation)
+  GOOGLE_DCHECK_NE(&from, this);
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  if (from.height() != 0) {
+    _internal_set_height(from._internal_height());
+  }
+  if (from.hardfork() != 0) {
+    _internal_set_hardfork(from._internal_hardfork());
+  }
+}
+
+void PForkSchedule_PActivation::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PForkSchedule.PActivation)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+void PForkSchedule_PActivation::CopyFrom(const PForkSchedule_PActivation& from) {
+// @@protoc_insertion_point(class_specific_copy_from_start:top.tvm_engine.parameters.PForkSchedule.PActivation)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+bool PForkSchedule_PActivation::IsInitialized() const {
+  return true;
+}
+
+void PForkSchedule_PActivation::InternalSwap(PForkSchedule_PActivation* other) {
+  using std::swap;
+  _internal_metadata_.Swap(&other->_internal_metadata_);
+  swap(height_, other->height_);
+  swap(hardfork_, other->hardfork_);
+}
+
+::PROTOBUF_NAMESPACE_ID::Metadata PForkSchedule_PActivation::GetMetadata() const {
+  return GetMetadataStatic();
+}
+
+
+// ===================================================================
+
+void PForkSchedule::InitAsDefaultInstance() {
+}
+class PForkSchedule::_Internal {
+ public:
+};
+
+PForkSchedule::PForkSchedule()
+  : ::PROTOBUF_NAMESPACE_ID::Message(), _internal_metadata_(nullptr) {
+  SharedCtor();
+  // @@protoc_insertion_point(constructor:top.tvm_engine.parameters.PForkSchedule)
+}
+PForkSchedule::PForkSchedule(const PForkSchedule& from)
+  : ::PROTOBUF_NAMESPACE_ID::Message(),
+      _internal_metadata_(nullptr),
+      activations_(from.activations_) {
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  // @@protoc_insertion_point(copy_constructor:top.tvm_engine.parameters.PForkSchedule)
+}
+
+void PForkSchedule::SharedCtor() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&scc_info_PForkSchedule_protobuf_5ftypes_2fpparameters_2eproto.base);
+}
+
+PForkSchedule::~PForkSchedule() {
+  // @@protoc_insertion_point(destructor:top.tvm_engine.parameters.PForkSchedule)
+  SharedDtor();
+}
+
+void PForkSchedule::SharedDtor() {
+}
+
+void PForkSchedule::SetCachedSize(int size) const {
+  _cached_size_.Set(size);
+}
+const PForkSchedule& PForkSchedule::default_instance() {
+  ::PROTOBUF_NAMESPACE_ID::internal::InitSCC(&::scc_info_PForkSchedule_protobuf_5ftypes_2fpparameters_2eproto.base);
+  return *internal_default_instance();
+}
+
+
+void PForkSchedule::Clear() {
+// @@protoc_insertion_point(message_clear_start:top.tvm_engine.parameters.PForkSchedule)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  activations_.Clear();
+  _internal_metadata_.Clear();
+}
+
+const char* PForkSchedule::_InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) {
+#define CHK_(x) if (PROTOBUF_PREDICT_FALSE(!(x))) goto failure
+  while (!ctx->Done(&ptr)) {
+    ::PROTOBUF_NAMESPACE_ID::uint32 tag;
+    ptr = ::PROTOBUF_NAMESPACE_ID::internal::ReadTag(ptr, &tag);
+    CHK_(ptr);
+    switch (tag >> 3) {
+      // repeated .top.tvm_engine.parameters.PForkSchedule.PActivation activations = 1;
+      case 1:
+        if (PROTOBUF_PREDICT_TRUE(static_cast<::PROTOBUF_NAMESPACE_ID::uint8>(tag) == 10)) {
+          ptr -= 1;
+          do {
+            ptr += 1;
+            ptr = ctx->ParseMessage(_internal_add_activations(), ptr);
+            CHK_(ptr);
+            if (!ctx->DataAvailable(ptr)) break;
+          } while (::PROTOBUF_NAMESPACE_ID::internal::ExpectTag<10>(ptr));
+        } else goto handle_unusual;
+        continue;
+      default: {
+      handle_unusual:
+        if ((tag & 7) == 4 || tag == 0) {
+          ctx->SetLastTag(tag);
+          goto success;
+        }
+        ptr = UnknownFieldParse(tag, &_internal_metadata_, ptr, ctx);
+        CHK_(ptr != nullptr);
+        continue;
+      }
+    }  // switch
+  }  // while
+success:
+  return ptr;
+failure:
+  ptr = nullptr;
+  goto success;
+#undef CHK_
+}
+
+::PROTOBUF_NAMESPACE_ID::uint8* PForkSchedule::_InternalSerialize(
+    ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const {
+  // @@protoc_insertion_point(serialize_to_array_start:top.tvm_engine.parameters.PForkSchedule)
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  // repeated .top.tvm_engine.parameters.PForkSchedule.PActivation activations = 1;
+  for (unsigned int i = 0,
+      n = static_cast<unsigned int>(this->_internal_activations_size()); i < n; i++) {
+    target = stream->EnsureSpace(target);
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::
+      InternalWriteMessage(1, this->_internal_activations(i), target, stream);
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    target = ::PROTOBUF_NAMESPACE_ID::internal::WireFormat::InternalSerializeUnknownFieldsToArray(
+        _internal_metadata_.unknown_fields(), target, stream);
+  }
+  // @@protoc_insertion_point(serialize_to_array_end:top.tvm_engine.parameters.PForkSchedule)
+  return target;
+}
+
+size_t PForkSchedule::ByteSizeLong() const {
+// @@protoc_insertion_point(message_byte_size_start:top.tvm_engine.parameters.PForkSchedule)
+  size_t total_size = 0;
+
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  // Prevent compiler warnings about cached_has_bits being unused
+  (void) cached_has_bits;
+
+  // repeated .top.tvm_engine.parameters.PForkSchedule.PActivation activations = 1;
+  total_size += 1UL * this->_internal_activations_size();
+  for (const auto& msg : this->activations_) {
+    total_size +=
+      ::PROTOBUF_NAMESPACE_ID::internal::WireFormatLite::MessageSize(msg);
+  }
+
+  if (PROTOBUF_PREDICT_FALSE(_internal_metadata_.have_unknown_fields())) {
+    return ::PROTOBUF_NAMESPACE_ID::internal::ComputeUnknownFieldsSize(
+        _internal_metadata_, total_size, &_cached_size_);
+  }
+  int cached_size = ::PROTOBUF_NAMESPACE_ID::internal::ToCachedSize(total_size);
+  SetCachedSize(cached_size);
+  return total_size;
+}
+
+void PForkSchedule::MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_merge_from_start:top.tvm_engine.parameters.PForkSchedule)
+  GOOGLE_DCHECK_NE(&from, this);
+  const PForkSchedule* source =
+      ::PROTOBUF_NAMESPACE_ID::DynamicCastToGenerated<PForkSchedule>(
+          &from);
+  if (source == nullptr) {
+  // @@protoc_insertion_point(generalized_merge_from_cast_fail:top.tvm_engine.parameters.PForkSchedule)
+    ::PROTOBUF_NAMESPACE_ID::internal::ReflectionOps::Merge(from, this);
+  } else {
+  // @@protoc_insertion_point(generalized_merge_from_cast_success:top.tvm_engine.parameters.PForkSchedule)
+    MergeFrom(*source);
+  }
+}
+
+void PForkSchedule::MergeFrom(const PForkSchedule& from) {
+// @@protoc_insertion_point(class_specific_merge_from_start:top.tvm_engine.parameters.PForkSchedule)
+  GOOGLE_DCHECK_NE(&from, this);
+  _internal_metadata_.MergeFrom(from._internal_metadata_);
+  ::PROTOBUF_NAMESPACE_ID::uint32 cached_has_bits = 0;
+  (void) cached_has_bits;
+
+  activations_.MergeFrom(from.activations_);
+}
+
+void PForkSchedule::CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) {
+// @@protoc_insertion_point(generalized_copy_from_start:top.tvm_engine.parameters.PForkSchedule)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+void PForkSchedule::CopyFrom(const PForkSchedule& from) {
+// @@protoc_insertion_point(class_specific_copy_from_start:top.tvm_engine.parameters.PForkSchedule)
+  if (&from == this) return;
+  Clear();
+  MergeFrom(from);
+}
+
+bool PForkSchedule::IsInitialized() const {
+  return true;
+}
+
+void PForkSchedule::InternalSwap(PForkSchedule* other) {
+  using std::swap;
+  _internal_metadata_.Swap(&other->_internal_metadata_);
+  activations_.InternalSwap(&other->activations_);
 }
 
-::google::protobuf::Metadata PCallArgs::GetMetadata() const {
-  protobuf_protobuf_5ftypes_2fpparameters_2eproto::protobuf_AssignDescriptorsOnce();
-  return ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::file_level_metadata[kIndexInFileMessages];
+::PROTOBUF_NAMESPACE_ID::Metadata PForkSchedule::GetMetadata() const {
+  return GetMetadataStatic();
 }
 
 
@@ -1572,21 +3922,41 @@ void PCallArgs::InternalSwap(PCallArgs* other) {
 }  // namespace parameters
 }  // namespace tvm_engine
 }  // namespace top
-namespace google {
-namespace protobuf {
-template<> GOOGLE_PROTOBUF_ATTRIBUTE_NOINLINE ::top::tvm_engine::parameters::PReturnResult_PU256* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PReturnResult_PU256 >(Arena* arena) {
+PROTOBUF_NAMESPACE_OPEN
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PReturnResult_PU256* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PReturnResult_PU256 >(Arena* arena) {
   return Arena::CreateInternal< ::top::tvm_engine::parameters::PReturnResult_PU256 >(arena);
 }
-template<> GOOGLE_PROTOBUF_ATTRIBUTE_NOINLINE ::top::tvm_engine::parameters::PReturnResult_PLog* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PReturnResult_PLog >(Arena* arena) {
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PReturnResult_PLog* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PReturnResult_PLog >(Arena* arena) {
   return Arena::CreateInternal< ::top::tvm_engine::parameters::PReturnResult_PLog >(arena);
 }
-template<> GOOGLE_PROTOBUF_ATTRIBUTE_NOINLINE ::top::tvm_engine::parameters::PReturnResult* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PReturnResult >(Arena* arena) {
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PReturnResult* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PReturnResult >(Arena* arena) {
   return Arena::CreateInternal< ::top::tvm_engine::parameters::PReturnResult >(arena);
 }
-template<> GOOGLE_PROTOBUF_ATTRIBUTE_NOINLINE ::top::tvm_engine::parameters::PCallArgs* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PCallArgs >(Arena* arena) {
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PStateDiffResult* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PStateDiffResult >(Arena* arena) {
+  return Arena::CreateInternal< ::top::tvm_engine::parameters::PStateDiffResult >(arena);
+}
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem >(Arena* arena) {
+  return Arena::CreateInternal< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem >(arena);
+}
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PCallArgs* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PCallArgs >(Arena* arena) {
   return Arena::CreateInternal< ::top::tvm_engine::parameters::PCallArgs >(arena);
 }
-}  // namespace protobuf
-}  // namespace google
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PEstimateGasResult* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PEstimateGasResult >(Arena* arena) {
+  return Arena::CreateInternal< ::top::tvm_engine::parameters::PEstimateGasResult >(arena);
+}
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PAccessListResult* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PAccessListResult >(Arena* arena) {
+  return Arena::CreateInternal< ::top::tvm_engine::parameters::PAccessListResult >(arena);
+}
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PDeployAddressResult* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PDeployAddressResult >(Arena* arena) {
+  return Arena::CreateInternal< ::top::tvm_engine::parameters::PDeployAddressResult >(arena);
+}
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PForkSchedule_PActivation* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PForkSchedule_PActivation >(Arena* arena) {
+  return Arena::CreateInternal< ::top::tvm_engine::parameters::PForkSchedule_PActivation >(arena);
+}
+template<> PROTOBUF_NOINLINE ::top::tvm_engine::parameters::PForkSchedule* Arena::CreateMaybeMessage< ::top::tvm_engine::parameters::PForkSchedule >(Arena* arena) {
+  return Arena::CreateInternal< ::top::tvm_engine::parameters::PForkSchedule >(arena);
+}
+PROTOBUF_NAMESPACE_CLOSE
 
 // @@protoc_insertion_point(global_scope)
+#include <google/protobuf/port_undef.inc>
@@ -1,24 +1,25 @@
 // Generated by the protocol buffer compiler.  DO NOT EDIT!
 // source: protobuf_types/pparameters.proto
 
-#ifndef PROTOBUF_INCLUDED_protobuf_5ftypes_2fpparameters_2eproto
-#define PROTOBUF_INCLUDED_protobuf_5ftypes_2fpparameters_2eproto
+#ifndef GOOGLE_PROTOBUF_INCLUDED_protobuf_5ftypes_2fpparameters_2eproto
+#define GOOGLE_PROTOBUF_INCLUDED_protobuf_5ftypes_2fpparameters_2eproto
 
+#include <limits>
 #include <string>
 
-#include <google/protobuf/stubs/common.h>
-
-#if GOOGLE_PROTOBUF_VERSION < 3006001
+#include <google/protobuf/port_def.inc>
+#if PROTOBUF_VERSION < 3011000
 #error This file was generated by a newer version of protoc which is
-#error incompatible with your Protocol Buffer headers.  Please update
+#error incompatible with your Protocol Buffer headers. Please update
 #error your headers.
 #endif
-#if 3006001 < GOOGLE_PROTOBUF_MIN_PROTOC_VERSION
+#if 3011004 < PROTOBUF_MIN_PROTOC_VERSION
 #error This file was generated by an older version of protoc which is
-#error incompatible with your Protocol Buffer headers.  Please
+#error incompatible with your Protocol Buffer headers. Please
 #error regenerate this file with a newer version of protoc.
 #endif
 
+#include <google/protobuf/port_undef.inc>
 #include <google/protobuf/io/coded_stream.h>
 #include <google/protobuf/arena.h>
 #include <google/protobuf/arenastring.h>
@@ -26,32 +27,59 @@
 #include <google/protobuf/generated_message_util.h>
 #include <google/protobuf/inlined_string_field.h>
 #include <google/protobuf/metadata.h>
+#include <google/protobuf/generated_message_reflection.h>
 #include <google/protobuf/message.h>
 #include <google/protobuf/repeated_field.h>  // IWYU pragma: export
 #include <google/protobuf/extension_set.h>  // IWYU pragma: export
+#include <google/protobuf/generated_enum_reflection.h>
 #include <google/protobuf/unknown_field_set.h>
 #include "protobuf_types/pbasic.pb.h"
 // @@protoc_insertion_point(includes)
-#define PROTOBUF_INTERNAL_EXPORT_protobuf_protobuf_5ftypes_2fpparameters_2eproto 
+#include <google/protobuf/port_def.inc>
+#define PROTOBUF_INTERNAL_EXPORT_protobuf_5ftypes_2fpparameters_2eproto
+PROTOBUF_NAMESPACE_OPEN
+namespace internal {
+class AnyMetadata;
+}  // namespace internal
+PROTOBUF_NAMESPACE_CLOSE
 
-namespace protobuf_protobuf_5ftypes_2fpparameters_2eproto {
 // Internal implementation detail -- do not use these members.
-struct TableStruct {
-  static const ::google::protobuf::internal::ParseTableField entries[];
-  static const ::google::protobuf::internal::AuxillaryParseTableField aux[];
-  static const ::google::protobuf::internal::ParseTable schema[4];
-  static const ::google::protobuf::internal::FieldMetadata field_metadata[];
-  static const ::google::protobuf::internal::SerializationTable serialization_table[];
-  static const ::google::protobuf::uint32 offsets[];
+struct TableStruct_protobuf_5ftypes_2fpparameters_2eproto {
+  static const ::PROTOBUF_NAMESPACE_ID::internal::ParseTableField entries[]
+    PROTOBUF_SECTION_VARIABLE(protodesc_cold);
+  static const ::PROTOBUF_NAMESPACE_ID::internal::AuxillaryParseTableField aux[]
+    PROTOBUF_SECTION_VARIABLE(protodesc_cold);
+  static const ::PROTOBUF_NAMESPACE_ID::internal::ParseTable schema[11]
+    PROTOBUF_SECTION_VARIABLE(protodesc_cold);
+  static const ::PROTOBUF_NAMESPACE_ID::internal::FieldMetadata field_metadata[];
+  static const ::PROTOBUF_NAMESPACE_ID::internal::SerializationTable serialization_table[];
+  static const ::PROTOBUF_NAMESPACE_ID::uint32 offsets[];
 };
-void AddDescriptors();
-}  // namespace protobuf_protobuf_5ftypes_2fpparameters_2eproto
+extern const ::PROTOBUF_NAMESPACE_ID::internal::DescriptorTable descriptor_table_protobuf_5ftypes_2fpparameters_2eproto;
 namespace top {
 namespace tvm_engine {
 namespace parameters {
+class PAccessListResult;
+class PAccessListResultDefaultTypeInternal;
+extern PAccessListResultDefaultTypeInternal _PAccessListResult_default_instance_;
 class PCallArgs;
 class PCallArgsDefaultTypeInternal;
 extern PCallArgsDefaultTypeInternal _PCallArgs_default_instance_;
+class PCallArgs_PAccessListItem;
+class PCallArgs_PAccessListItemDefaultTypeInternal;
+extern PCallArgs_PAccessListItemDefaultTypeInternal _PCallArgs_PAccessListItem_default_instance_;
+class PDeployAddressResult;
+class PDeployAddressResultDefaultTypeInternal;
+extern PDeployAddressResultDefaultTypeInternal _PDeployAddressResult_default_instance_;
+class PEstimateGasResult;
+class PEstimateGasResultDefaultTypeInternal;
+extern PEstimateGasResultDefaultTypeInternal _PEstimateGasResult_default_instance_;
+class PForkSchedule;
+class PForkScheduleDefaultTypeInternal;
+extern PForkScheduleDefaultTypeInternal _PForkSchedule_default_instance_;
+class PForkSchedule_PActivation;
+class PForkSchedule_PActivationDefaultTypeInternal;
+extern PForkSchedule_PActivationDefaultTypeInternal _PForkSchedule_PActivation_default_instance_;
 class PReturnResult;
 class PReturnResultDefaultTypeInternal;
 extern PReturnResultDefaultTypeInternal _PReturnResult_default_instance_;
@@ -61,40 +89,129 @@ extern PReturnResult_PLogDefaultTypeInternal _PReturnResult_PLog_default_instanc
 class PReturnResult_PU256;
 class PReturnResult_PU256DefaultTypeInternal;
 extern PReturnResult_PU256DefaultTypeInternal _PReturnResult_PU256_default_instance_;
+class PStateDiffResult;
+class PStateDiffResultDefaultTypeInternal;
+extern PStateDiffResultDefaultTypeInternal _PStateDiffResult_default_instance_;
 }  // namespace parameters
 }  // namespace tvm_engine
 }  // namespace top
-namespace google {
-namespace protobuf {
+PROTOBUF_NAMESPACE_OPEN
+template<> ::top::tvm_engine::parameters::PAccessListResult* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PAccessListResult>(Arena*);
 template<> ::top::tvm_engine::parameters::PCallArgs* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PCallArgs>(Arena*);
+template<> ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PCallArgs_PAccessListItem>(Arena*);
+template<> ::top::tvm_engine::parameters::PDeployAddressResult* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PDeployAddressResult>(Arena*);
+template<> ::top::tvm_engine::parameters::PEstimateGasResult* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PEstimateGasResult>(Arena*);
+template<> ::top::tvm_engine::parameters::PForkSchedule* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PForkSchedule>(Arena*);
+template<> ::top::tvm_engine::parameters::PForkSchedule_PActivation* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PForkSchedule_PActivation>(Arena*);
 template<> ::top::tvm_engine::parameters::PReturnResult* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PReturnResult>(Arena*);
 template<> ::top::tvm_engine::parameters::PReturnResult_PLog* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PReturnResult_PLog>(Arena*);
 template<> ::top::tvm_engine::parameters::PReturnResult_PU256* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PReturnResult_PU256>(Arena*);
-}  // namespace protobuf
-}  // namespace google
+template<> ::top::tvm_engine::parameters::PStateDiffResult* Arena::CreateMaybeMessage<::top::tvm_engine::parameters::PStateDiffResult>(Arena*);
+PROTOBUF_NAMESPACE_CLOSE
 namespace top {
 namespace tvm_engine {
 namespace parameters {
 
+enum PStatusCode : int {
+  SUCCEED = 0,
+  REVERT = 1,
+  OUT_OF_GAS = 2,
+  OUT_OF_FUND = 3,
+  OUT_OF_OFFSET = 4,
+  NONCE_TOO_LOW = 100,
+  NONCE_TOO_HIGH = 101,
+  CHAIN_ID_MISMATCH = 102,
+  INTRINSIC_GAS_TOO_LOW = 103,
+  INSUFFICIENT_FUNDS = 104,
+  INIT_CODE_TOO_LARGE = 105,
+  FEE_CAP_TOO_LOW = 106,
+  TIP_ABOVE_FEE_CAP = 107,
+  SALT_TABLE_MISMATCH = 108,
+  MALFORMED_ARGS = 109,
+  STACK_UNDERFLOW = 200,
+  STACK_OVERFLOW = 201,
+  INVALID_JUMP = 202,
+  INVALID_RANGE = 203,
+  DESIGNATED_INVALID = 204,
+  CALL_TOO_DEEP = 205,
+  CREATE_COLLISION = 206,
+  CREATE_CONTRACT_LIMIT = 207,
+  INVALID_CODE = 208,
+  PC_UNDERFLOW = 209,
+  CREATE_EMPTY = 210,
+  EVM_ERROR_OTHER = 299,
+  NOT_SUPPORTED = 300,
+  UNHANDLED_INTERRUPT = 301,
+  CALL_ERROR_AS_FATAL = 302,
+  EVM_FATAL_OTHER = 399,
+  CROSS_TABLE_CREATE = 400,
+  PStatusCode_INT_MIN_SENTINEL_DO_NOT_USE_ = std::numeric_limits<::PROTOBUF_NAMESPACE_ID::int32>::min(),
+  PStatusCode_INT_MAX_SENTINEL_DO_NOT_USE_ = std::numeric_limits<::PROTOBUF_NAMESPACE_ID::int32>::max()
+};
+bool PStatusCode_IsValid(int value);
+constexpr PStatusCode PStatusCode_MIN = SUCCEED;
+constexpr PStatusCode PStatusCode_MAX = CROSS_TABLE_CREATE;
+constexpr int PStatusCode_ARRAYSIZE = PStatusCode_MAX + 1;
+
+const ::PROTOBUF_NAMESPACE_ID::EnumDescriptor* PStatusCode_descriptor();
+template<typename T>
+inline const std::string& PStatusCode_Name(T enum_t_value) {
+  static_assert(::std::is_same<T, PStatusCode>::value ||
+    ::std::is_integral<T>::value,
+    "Incorrect type passed to function PStatusCode_Name.");
+  return ::PROTOBUF_NAMESPACE_ID::internal::NameOfEnum(
+    PStatusCode_descriptor(), enum_t_value);
+}
+inline bool PStatusCode_Parse(
+    const std::string& name, PStatusCode* value) {
+  return ::PROTOBUF_NAMESPACE_ID::internal::ParseNamedEnum<PStatusCode>(
+    PStatusCode_descriptor(), name, value);
+}
+enum PHardfork : int {
+  LONDON = 0,
+  FRONTIER = 1,
+  ISTANBUL = 2,
+  BERLIN = 3,
+  PHardfork_INT_MIN_SENTINEL_DO_NOT_USE_ = std::numeric_limits<::PROTOBUF_NAMESPACE_ID::int32>::min(),
+  PHardfork_INT_MAX_SENTINEL_DO_NOT_USE_ = std::numeric_limits<::PROTOBUF_NAMESPACE_ID::int32>::max()
+};
+bool PHardfork_IsValid(int value);
+constexpr PHardfork PHardfork_MIN = LONDON;
+constexpr PHardfork PHardfork_MAX = BERLIN;
+constexpr int PHardfork_ARRAYSIZE = PHardfork_MAX + 1;
+
+const ::PROTOBUF_NAMESPACE_ID::EnumDescriptor* PHardfork_descriptor();
+template<typename T>
+inline const std::string& PHardfork_Name(T enum_t_value) {
+  static_assert(::std::is_same<T, PHardfork>::value ||
+    ::std::is_integral<T>::value,
+    "Incorrect type passed to function PHardfork_Name.");
+  return ::PROTOBUF_NAMESPACE_ID::internal::NameOfEnum(
+    PHardfork_descriptor(), enum_t_value);
+}
+inline bool PHardfork_Parse(
+    const std::string& name, PHardfork* value) {
+  return ::PROTOBUF_NAMESPACE_ID::internal::ParseNamedEnum<PHardfork>(
+    PHardfork_descriptor(), name, value);
+}
 // ===================================================================
 
-class PReturnResult_PU256 : public ::google::protobuf::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PReturnResult.PU256) */ {
+class PReturnResult_PU256 :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PReturnResult.PU256) */ {
  public:
   PReturnResult_PU256();
   virtual ~PReturnResult_PU256();
 
   PReturnResult_PU256(const PReturnResult_PU256& from);
-
-  inline PReturnResult_PU256& operator=(const PReturnResult_PU256& from) {
-    CopyFrom(from);
-    return *this;
-  }
-  #if LANG_CXX11
   PReturnResult_PU256(PReturnResult_PU256&& from) noexcept
     : PReturnResult_PU256() {
     *this = ::std::move(from);
   }
 
+  inline PReturnResult_PU256& operator=(const PReturnResult_PU256& from) {
+    CopyFrom(from);
+    return *this;
+  }
   inline PReturnResult_PU256& operator=(PReturnResult_PU256&& from) noexcept {
     if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
       if (this != &from) InternalSwap(&from);
@@ -103,8 +220,16 @@ class PReturnResult_PU256 : public ::google::protobuf::Message /* @@protoc_inser
     }
     return *this;
   }
-  #endif
-  static const ::google::protobuf::Descriptor* descriptor();
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
   static const PReturnResult_PU256& default_instance();
 
   static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
@@ -115,97 +240,113 @@ class PReturnResult_PU256 : public ::google::protobuf::Message /* @@protoc_inser
   static constexpr int kIndexInFileMessages =
     0;
 
-  void Swap(PReturnResult_PU256* other);
   friend void swap(PReturnResult_PU256& a, PReturnResult_PU256& b) {
     a.Swap(&b);
   }
+  inline void Swap(PReturnResult_PU256* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
 
   // implements Message ----------------------------------------------
 
   inline PReturnResult_PU256* New() const final {
-    return CreateMaybeMessage<PReturnResult_PU256>(NULL);
+    return CreateMaybeMessage<PReturnResult_PU256>(nullptr);
   }
 
-  PReturnResult_PU256* New(::google::protobuf::Arena* arena) const final {
+  PReturnResult_PU256* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
     return CreateMaybeMessage<PReturnResult_PU256>(arena);
   }
-  void CopyFrom(const ::google::protobuf::Message& from) final;
-  void MergeFrom(const ::google::protobuf::Message& from) final;
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
   void CopyFrom(const PReturnResult_PU256& from);
   void MergeFrom(const PReturnResult_PU256& from);
-  void Clear() final;
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
   bool IsInitialized() const final;
 
   size_t ByteSizeLong() const final;
-  bool MergePartialFromCodedStream(
-      ::google::protobuf::io::CodedInputStream* input) final;
-  void SerializeWithCachedSizes(
-      ::google::protobuf::io::CodedOutputStream* output) const final;
-  ::google::protobuf::uint8* InternalSerializeWithCachedSizesToArray(
-      bool deterministic, ::google::protobuf::uint8* target) const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
   int GetCachedSize() const final { return _cached_size_.Get(); }
 
   private:
-  void SharedCtor();
-  void SharedDtor();
+  inline void SharedCtor();
+  inline void SharedDtor();
   void SetCachedSize(int size) const final;
   void InternalSwap(PReturnResult_PU256* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PReturnResult.PU256";
+  }
   private:
-  inline ::google::protobuf::Arena* GetArenaNoVirtual() const {
-    return NULL;
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
   }
   inline void* MaybeArenaPtr() const {
-    return NULL;
+    return nullptr;
   }
   public:
 
-  ::google::protobuf::Metadata GetMetadata() const final;
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
 
   // nested types ----------------------------------------------------
 
   // accessors -------------------------------------------------------
 
+  enum : int {
+    kDataFieldNumber = 1,
+  };
   // bytes data = 1;
   void clear_data();
-  static const int kDataFieldNumber = 1;
-  const ::std::string& data() const;
-  void set_data(const ::std::string& value);
-  #if LANG_CXX11
-  void set_data(::std::string&& value);
-  #endif
+  const std::string& data() const;
+  void set_data(const std::string& value);
+  void set_data(std::string&& value);
   void set_data(const char* value);
   void set_data(const void* value, size_t size);
-  ::std::string* mutable_data();
-  ::std::string* release_data();
-  void set_allocated_data(::std::string* data);
+  std::string* mutable_data();
+  std::string* release_data();
+  void set_allocated_data(std::string* data);
+  private:
+  const std::string& _internal_data() const;
+  void _internal_set_data(const std::string& value);
+  std::string* _internal_mutable_data();
+  public:
 
   // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PReturnResult.PU256)
  private:
+  class _Internal;
 
-  ::google::protobuf::internal::InternalMetadataWithArena _internal_metadata_;
-  ::google::protobuf::internal::ArenaStringPtr data_;
-  mutable ::google::protobuf::internal::CachedSize _cached_size_;
-  friend struct ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::TableStruct;
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr data_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
 };
 // -------------------------------------------------------------------
 
-class PReturnResult_PLog : public ::google::protobuf::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PReturnResult.PLog) */ {
+class PReturnResult_PLog :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PReturnResult.PLog) */ {
  public:
   PReturnResult_PLog();
   virtual ~PReturnResult_PLog();
 
   PReturnResult_PLog(const PReturnResult_PLog& from);
-
-  inline PReturnResult_PLog& operator=(const PReturnResult_PLog& from) {
-    CopyFrom(from);
-    return *this;
-  }
-  #if LANG_CXX11
   PReturnResult_PLog(PReturnResult_PLog&& from) noexcept
     : PReturnResult_PLog() {
     *this = ::std::move(from);
   }
 
+  inline PReturnResult_PLog& operator=(const PReturnResult_PLog& from) {
+    CopyFrom(from);
+    return *this;
+  }
   inline PReturnResult_PLog& operator=(PReturnResult_PLog&& from) noexcept {
     if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
       if (this != &from) InternalSwap(&from);
@@ -214,8 +355,16 @@ class PReturnResult_PLog : public ::google::protobuf::Message /* @@protoc_insert
     }
     return *this;
   }
-  #endif
-  static const ::google::protobuf::Descriptor* descriptor();
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
   static const PReturnResult_PLog& default_instance();
 
   static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
@@ -226,123 +375,150 @@ class PReturnResult_PLog : public ::google::protobuf::Message /* @@protoc_insert
   static constexpr int kIndexInFileMessages =
     1;
 
-  void Swap(PReturnResult_PLog* other);
   friend void swap(PReturnResult_PLog& a, PReturnResult_PLog& b) {
     a.Swap(&b);
   }
+  inline void Swap(PReturnResult_PLog* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
 
   // implements Message ----------------------------------------------
 
   inline PReturnResult_PLog* New() const final {
-    return CreateMaybeMessage<PReturnResult_PLog>(NULL);
+    return CreateMaybeMessage<PReturnResult_PLog>(nullptr);
   }
 
-  PReturnResult_PLog* New(::google::protobuf::Arena* arena) const final {
+  PReturnResult_PLog* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
     return CreateMaybeMessage<PReturnResult_PLog>(arena);
   }
-  void CopyFrom(const ::google::protobuf::Message& from) final;
-  void MergeFrom(const ::google::protobuf::Message& from) final;
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
   void CopyFrom(const PReturnResult_PLog& from);
   void MergeFrom(const PReturnResult_PLog& from);
-  void Clear() final;
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
   bool IsInitialized() const final;
 
   size_t ByteSizeLong() const final;
-  bool MergePartialFromCodedStream(
-      ::google::protobuf::io::CodedInputStream* input) final;
-  void SerializeWithCachedSizes(
-      ::google::protobuf::io::CodedOutputStream* output) const final;
-  ::google::protobuf::uint8* InternalSerializeWithCachedSizesToArray(
-      bool deterministic, ::google::protobuf::uint8* target) const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
   int GetCachedSize() const final { return _cached_size_.Get(); }
 
   private:
-  void SharedCtor();
-  void SharedDtor();
+  inline void SharedCtor();
+  inline void SharedDtor();
   void SetCachedSize(int size) const final;
   void InternalSwap(PReturnResult_PLog* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PReturnResult.PLog";
+  }
   private:
-  inline ::google::protobuf::Arena* GetArenaNoVirtual() const {
-    return NULL;
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
   }
   inline void* MaybeArenaPtr() const {
-    return NULL;
+    return nullptr;
   }
   public:
 
-  ::google::protobuf::Metadata GetMetadata() const final;
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
 
   // nested types ----------------------------------------------------
 
   // accessors -------------------------------------------------------
 
+  enum : int {
+    kTopicsFieldNumber = 2,
+    kDataFieldNumber = 3,
+    kAddressFieldNumber = 1,
+  };
   // repeated .top.tvm_engine.parameters.PReturnResult.PU256 topics = 2;
   int topics_size() const;
+  private:
+  int _internal_topics_size() const;
+  public:
   void clear_topics();
-  static const int kTopicsFieldNumber = 2;
   ::top::tvm_engine::parameters::PReturnResult_PU256* mutable_topics(int index);
-  ::google::protobuf::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PU256 >*
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PU256 >*
       mutable_topics();
+  private:
+  const ::top::tvm_engine::parameters::PReturnResult_PU256& _internal_topics(int index) const;
+  ::top::tvm_engine::parameters::PReturnResult_PU256* _internal_add_topics();
+  public:
   const ::top::tvm_engine::parameters::PReturnResult_PU256& topics(int index) const;
   ::top::tvm_engine::parameters::PReturnResult_PU256* add_topics();
-  const ::google::protobuf::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PU256 >&
+  const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PU256 >&
       topics() const;
 
   // bytes data = 3;
   void clear_data();
-  static const int kDataFieldNumber = 3;
-  const ::std::string& data() const;
-  void set_data(const ::std::string& value);
-  #if LANG_CXX11
-  void set_data(::std::string&& value);
-  #endif
+  const std::string& data() const;
+  void set_data(const std::string& value);
+  void set_data(std::string&& value);
   void set_data(const char* value);
   void set_data(const void* value, size_t size);
-  ::std::string* mutable_data();
-  ::std::string* release_data();
-  void set_allocated_data(::std::string* data);
+  std::string* mutable_data();
+  std::string* release_data();
+  void set_allocated_data(std::string* data);
+  private:
+  const std::string& _internal_data() const;
+  void _internal_set_data(const std::string& value);
+  std::string* _internal_mutable_data();
+  public:
 
   // .top.tvm_engine.basic.PAddress address = 1;
   bool has_address() const;
-  void clear_address();
-  static const int kAddressFieldNumber = 1;
   private:
-  const ::top::tvm_engine::basic::PAddress& _internal_address() const;
+  bool _internal_has_address() const;
   public:
+  void clear_address();
   const ::top::tvm_engine::basic::PAddress& address() const;
   ::top::tvm_engine::basic::PAddress* release_address();
   ::top::tvm_engine::basic::PAddress* mutable_address();
   void set_allocated_address(::top::tvm_engine::basic::PAddress* address);
+  private:
+  const ::top::tvm_engine::basic::PAddress& _internal_address() const;
+  ::top::tvm_engine::basic::PAddress* _internal_mutable_address();
+  public:
 
   // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PReturnResult.PLog)
  private:
+  class _Internal;
 
-  ::google::protobuf::internal::InternalMetadataWithArena _internal_metadata_;
-  ::google::protobuf::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PU256 > topics_;
-  ::google::protobuf::internal::ArenaStringPtr data_;
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PU256 > topics_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr data_;
   ::top::tvm_engine::basic::PAddress* address_;
-  mutable ::google::protobuf::internal::CachedSize _cached_size_;
-  friend struct ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::TableStruct;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
 };
 // -------------------------------------------------------------------
 
-class PReturnResult : public ::google::protobuf::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PReturnResult) */ {
+class PReturnResult :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PReturnResult) */ {
  public:
   PReturnResult();
   virtual ~PReturnResult();
 
   PReturnResult(const PReturnResult& from);
-
-  inline PReturnResult& operator=(const PReturnResult& from) {
-    CopyFrom(from);
-    return *this;
-  }
-  #if LANG_CXX11
   PReturnResult(PReturnResult&& from) noexcept
     : PReturnResult() {
     *this = ::std::move(from);
   }
 
+  inline PReturnResult& operator=(const PReturnResult& from) {
+    CopyFrom(from);
+    return *this;
+  }
   inline PReturnResult& operator=(PReturnResult&& from) noexcept {
     if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
       if (this != &from) InternalSwap(&from);
@@ -351,8 +527,16 @@ class PReturnResult : public ::google::protobuf::Message /* @@protoc_insertion_p
     }
     return *this;
   }
-  #endif
-  static const ::google::protobuf::Descriptor* descriptor();
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
   static const PReturnResult& default_instance();
 
   static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
@@ -363,51 +547,62 @@ class PReturnResult : public ::google::protobuf::Message /* @@protoc_insertion_p
   static constexpr int kIndexInFileMessages =
     2;
 
-  void Swap(PReturnResult* other);
   friend void swap(PReturnResult& a, PReturnResult& b) {
     a.Swap(&b);
   }
+  inline void Swap(PReturnResult* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
 
   // implements Message ----------------------------------------------
 
   inline PReturnResult* New() const final {
-    return CreateMaybeMessage<PReturnResult>(NULL);
+    return CreateMaybeMessage<PReturnResult>(nullptr);
   }
 
-  PReturnResult* New(::google::protobuf::Arena* arena) const final {
+  PReturnResult* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
     return CreateMaybeMessage<PReturnResult>(arena);
   }
-  void CopyFrom(const ::google::protobuf::Message& from) final;
-  void MergeFrom(const ::google::protobuf::Message& from) final;
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
   void CopyFrom(const PReturnResult& from);
   void MergeFrom(const PReturnResult& from);
-  void Clear() final;
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
   bool IsInitialized() const final;
 
   size_t ByteSizeLong() const final;
-  bool MergePartialFromCodedStream(
-      ::google::protobuf::io::CodedInputStream* input) final;
-  void SerializeWithCachedSizes(
-      ::google::protobuf::io::CodedOutputStream* output) const final;
-  ::google::protobuf::uint8* InternalSerializeWithCachedSizesToArray(
-      bool deterministic, ::google::protobuf::uint8* target) const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
   int GetCachedSize() const final { return _cached_size_.Get(); }
 
   private:
-  void SharedCtor();
-  void SharedDtor();
+  inline void SharedCtor();
+  inline void SharedDtor();
   void SetCachedSize(int size) const final;
   void InternalSwap(PReturnResult* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PReturnResult";
+  }
   private:
-  inline ::google::protobuf::Arena* GetArenaNoVirtual() const {
-    return NULL;
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
   }
   inline void* MaybeArenaPtr() const {
-    return NULL;
+    return nullptr;
   }
   public:
 
-  ::google::protobuf::Metadata GetMetadata() const final;
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
 
   // nested types ----------------------------------------------------
 
@@ -416,75 +611,188 @@ class PReturnResult : public ::google::protobuf::Message /* @@protoc_insertion_p
 
   // accessors -------------------------------------------------------
 
+  enum : int {
+    kLogsFieldNumber = 4,
+    kStatusDataFieldNumber = 2,
+    kCodeHashFieldNumber = 7,
+    kSaltFieldNumber = 9,
+    kErrorMessageFieldNumber = 10,
+    kContractAddressFieldNumber = 6,
+    kGasUsedFieldNumber = 3,
+    kEffectiveGasPriceFieldNumber = 5,
+    kCodeSizeFieldNumber = 8,
+    kStatusFieldNumber = 1,
+  };
   // repeated .top.tvm_engine.parameters.PReturnResult.PLog logs = 4;
   int logs_size() const;
+  private:
+  int _internal_logs_size() const;
+  public:
   void clear_logs();
-  static const int kLogsFieldNumber = 4;
   ::top::tvm_engine::parameters::PReturnResult_PLog* mutable_logs(int index);
-  ::google::protobuf::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PLog >*
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PLog >*
       mutable_logs();
+  private:
+  const ::top::tvm_engine::parameters::PReturnResult_PLog& _internal_logs(int index) const;
+  ::top::tvm_engine::parameters::PReturnResult_PLog* _internal_add_logs();
+  public:
   const ::top::tvm_engine::parameters::PReturnResult_PLog& logs(int index) const;
   ::top::tvm_engine::parameters::PReturnResult_PLog* add_logs();
-  const ::google::protobuf::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PLog >&
+  const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PLog >&
       logs() const;
 
   // bytes status_data = 2;
   void clear_status_data();
-  static const int kStatusDataFieldNumber = 2;
-  const ::std::string& status_data() const;
-  void set_status_data(const ::std::string& value);
-  #if LANG_CXX11
-  void set_status_data(::std::string&& value);
-  #endif
+  const std::string& status_data() const;
+  void set_status_data(const std::string& value);
+  void set_status_data(std::string&& value);
   void set_status_data(const char* value);
   void set_status_data(const void* value, size_t size);
-  ::std::string* mutable_status_data();
-  ::std::string* release_status_data();
-  void set_allocated_status_data(::std::string* status_data);
+  std::string* mutable_status_data();
+  std::string* release_status_data();
+  void set_allocated_status_data(std::string* status_data);
+  private:
+  const std::string& _internal_status_data() const;
+  void _internal_set_status_data(const std::string& value);
+  std::string* _internal_mutable_status_data();
+  public:
+
+  // bytes code_hash = 7;
+  void clear_code_hash();
+  const std::string& code_hash() const;
+  void set_code_hash(const std::string& value);
+  void set_code_hash(std::string&& value);
+  void set_code_hash(const char* value);
+  void set_code_hash(const void* value, size_t size);
+  std::string* mutable_code_hash();
+  std::string* release_code_hash();
+  void set_allocated_code_hash(std::string* code_hash);
+  private:
+  const std::string& _internal_code_hash() const;
+  void _internal_set_code_hash(const std::string& value);
+  std::string* _internal_mutable_code_hash();
+  public:
+
+  // bytes salt = 9;
+  void clear_salt();
+  const std::string& salt() const;
+  void set_salt(const std::string& value);
+  void set_salt(std::string&& value);
+  void set_salt(const char* value);
+  void set_salt(const void* value, size_t size);
+  std::string* mutable_salt();
+  std::string* release_salt();
+  void set_allocated_salt(std::string* salt);
+  private:
+  const std::string& _internal_salt() const;
+  void _internal_set_salt(const std::string& value);
+  std::string* _internal_mutable_salt();
+  public:
+
+  // string error_message = 10;
+  void clear_error_message();
+  const std::string& error_message() const;
+  void set_error_message(const std::string& value);
+  void set_error_message(std::string&& value);
+  void set_error_message(const char* value);
+  void set_error_message(const char* value, size_t size);
+  std::string* mutable_error_message();
+  std::string* release_error_message();
+  void set_allocated_error_message(std::string* error_message);
+  private:
+  const std::string& _internal_error_message() const;
+  void _internal_set_error_message(const std::string& value);
+  std::string* _internal_mutable_error_message();
+  public:
+
+  // .top.tvm_engine.basic.PAddress contract_address = 6;
+  bool has_contract_address() const;
+  private:
+  bool _internal_has_contract_address() const;
+  public:
+  void clear_contract_address();
+  const ::top::tvm_engine::basic::PAddress& contract_address() const;
+  ::top::tvm_engine::basic::PAddress* release_contract_address();
+  ::top::tvm_engine::basic::PAddress* mutable_contract_address();
+  void set_allocated_contract_address(::top::tvm_engine::basic::PAddress* contract_address);
+  private:
+  const ::top::tvm_engine::basic::PAddress& _internal_contract_address() const;
+  ::top::tvm_engine::basic::PAddress* _internal_mutable_contract_address();
+  public:
 
   // uint64 gas_used = 3;
   void clear_gas_used();
-  static const int kGasUsedFieldNumber = 3;
-  ::google::protobuf::uint64 gas_used() const;
-  void set_gas_used(::google::protobuf::uint64 value);
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_used() const;
+  void set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_gas_used() const;
+  void _internal_set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // uint64 effective_gas_price = 5;
+  void clear_effective_gas_price();
+  ::PROTOBUF_NAMESPACE_ID::uint64 effective_gas_price() const;
+  void set_effective_gas_price(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_effective_gas_price() const;
+  void _internal_set_effective_gas_price(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // uint64 code_size = 8;
+  void clear_code_size();
+  ::PROTOBUF_NAMESPACE_ID::uint64 code_size() const;
+  void set_code_size(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_code_size() const;
+  void _internal_set_code_size(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
 
-  // uint32 status = 1;
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
   void clear_status();
-  static const int kStatusFieldNumber = 1;
-  ::google::protobuf::uint32 status() const;
-  void set_status(::google::protobuf::uint32 value);
+  ::top::tvm_engine::parameters::PStatusCode status() const;
+  void set_status(::top::tvm_engine::parameters::PStatusCode value);
+  private:
+  ::top::tvm_engine::parameters::PStatusCode _internal_status() const;
+  void _internal_set_status(::top::tvm_engine::parameters::PStatusCode value);
+  public:
 
   // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PReturnResult)
  private:
+  class _Internal;
 
-  ::google::protobuf::internal::InternalMetadataWithArena _internal_metadata_;
-  ::google::protobuf::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PLog > logs_;
-  ::google::protobuf::internal::ArenaStringPtr status_data_;
-  ::google::protobuf::uint64 gas_used_;
-  ::google::protobuf::uint32 status_;
-  mutable ::google::protobuf::internal::CachedSize _cached_size_;
-  friend struct ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::TableStruct;
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PLog > logs_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr status_data_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr code_hash_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr salt_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr error_message_;
+  ::top::tvm_engine::basic::PAddress* contract_address_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_used_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 effective_gas_price_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 code_size_;
+  int status_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
 };
 // -------------------------------------------------------------------
 
-class PCallArgs : public ::google::protobuf::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PCallArgs) */ {
+class PStateDiffResult :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PStateDiffResult) */ {
  public:
-  PCallArgs();
-  virtual ~PCallArgs();
+  PStateDiffResult();
+  virtual ~PStateDiffResult();
 
-  PCallArgs(const PCallArgs& from);
+  PStateDiffResult(const PStateDiffResult& from);
+  PStateDiffResult(PStateDiffResult&& from) noexcept
+    : PStateDiffResult() {
+    *this = ::std::move(from);
+  }
 
-  inline PCallArgs& operator=(const PCallArgs& from) {
+  inline PStateDiffResult& operator=(const PStateDiffResult& from) {
     CopyFrom(from);
     return *this;
   }
-  #if LANG_CXX11
-  PCallArgs(PCallArgs&& from) noexcept
-    : PCallArgs() {
-    *this = ::std::move(from);
-  }
-
-  inline PCallArgs& operator=(PCallArgs&& from) noexcept {
+  inline PStateDiffResult& operator=(PStateDiffResult&& from) noexcept {
     if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
       if (this != &from) InternalSwap(&from);
     } else {
@@ -492,623 +800,3524 @@ class PCallArgs : public ::google::protobuf::Message /* @@protoc_insertion_point
     }
     return *this;
   }
-  #endif
-  static const ::google::protobuf::Descriptor* descriptor();
-  static const PCallArgs& default_instance();
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
+  static const PStateDiffResult& default_instance();
 
   static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
-  static inline const PCallArgs* internal_default_instance() {
-    return reinterpret_cast<const PCallArgs*>(
-               &_PCallArgs_default_instance_);
+  static inline const PStateDiffResult* internal_default_instance() {
+    return reinterpret_cast<const PStateDiffResult*>(
+               &_PStateDiffResult_default_instance_);
   }
   static constexpr int kIndexInFileMessages =
     3;
 
-  void Swap(PCallArgs* other);
-  friend void swap(PCallArgs& a, PCallArgs& b) {
+  friend void swap(PStateDiffResult& a, PStateDiffResult& b) {
     a.Swap(&b);
   }
+  inline void Swap(PStateDiffResult* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
 
   // implements Message ----------------------------------------------
 
-  inline PCallArgs* New() const final {
-    return CreateMaybeMessage<PCallArgs>(NULL);
+  inline PStateDiffResult* New() const final {
+    return CreateMaybeMessage<PStateDiffResult>(nullptr);
   }
 
-  PCallArgs* New(::google::protobuf::Arena* arena) const final {
-    return CreateMaybeMessage<PCallArgs>(arena);
+  PStateDiffResult* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
+    return CreateMaybeMessage<PStateDiffResult>(arena);
   }
-  void CopyFrom(const ::google::protobuf::Message& from) final;
-  void MergeFrom(const ::google::protobuf::Message& from) final;
-  void CopyFrom(const PCallArgs& from);
-  void MergeFrom(const PCallArgs& from);
-  void Clear() final;
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void CopyFrom(const PStateDiffResult& from);
+  void MergeFrom(const PStateDiffResult& from);
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
   bool IsInitialized() const final;
 
   size_t ByteSizeLong() const final;
-  bool MergePartialFromCodedStream(
-      ::google::protobuf::io::CodedInputStream* input) final;
-  void SerializeWithCachedSizes(
-      ::google::protobuf::io::CodedOutputStream* output) const final;
-  ::google::protobuf::uint8* InternalSerializeWithCachedSizesToArray(
-      bool deterministic, ::google::protobuf::uint8* target) const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
   int GetCachedSize() const final { return _cached_size_.Get(); }
 
   private:
-  void SharedCtor();
-  void SharedDtor();
+  inline void SharedCtor();
+  inline void SharedDtor();
   void SetCachedSize(int size) const final;
-  void InternalSwap(PCallArgs* other);
+  void InternalSwap(PStateDiffResult* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PStateDiffResult";
+  }
   private:
-  inline ::google::protobuf::Arena* GetArenaNoVirtual() const {
-    return NULL;
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
   }
   inline void* MaybeArenaPtr() const {
-    return NULL;
+    return nullptr;
   }
   public:
 
-  ::google::protobuf::Metadata GetMetadata() const final;
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
 
   // nested types ----------------------------------------------------
 
   // accessors -------------------------------------------------------
 
-  // bytes input = 4;
-  void clear_input();
-  static const int kInputFieldNumber = 4;
-  const ::std::string& input() const;
-  void set_input(const ::std::string& value);
-  #if LANG_CXX11
-  void set_input(::std::string&& value);
-  #endif
-  void set_input(const char* value);
-  void set_input(const void* value, size_t size);
-  ::std::string* mutable_input();
-  ::std::string* release_input();
-  void set_allocated_input(::std::string* input);
-
-  // .top.tvm_engine.basic.PAddress sender_address = 1;
-  bool has_sender_address() const;
-  void clear_sender_address();
-  static const int kSenderAddressFieldNumber = 1;
+  enum : int {
+    kStateDiffFieldNumber = 2,
+    kResultFieldNumber = 1,
+  };
+  // string state_diff = 2;
+  void clear_state_diff();
+  const std::string& state_diff() const;
+  void set_state_diff(const std::string& value);
+  void set_state_diff(std::string&& value);
+  void set_state_diff(const char* value);
+  void set_state_diff(const char* value, size_t size);
+  std::string* mutable_state_diff();
+  std::string* release_state_diff();
+  void set_allocated_state_diff(std::string* state_diff);
   private:
-  const ::top::tvm_engine::basic::PAddress& _internal_sender_address() const;
+  const std::string& _internal_state_diff() const;
+  void _internal_set_state_diff(const std::string& value);
+  std::string* _internal_mutable_state_diff();
   public:
-  const ::top::tvm_engine::basic::PAddress& sender_address() const;
-  ::top::tvm_engine::basic::PAddress* release_sender_address();
-  ::top::tvm_engine::basic::PAddress* mutable_sender_address();
-  void set_allocated_sender_address(::top::tvm_engine::basic::PAddress* sender_address);
 
-  // .top.tvm_engine.basic.PAddress recver_address = 2;
-  bool has_recver_address() const;
-  void clear_recver_address();
-  static const int kRecverAddressFieldNumber = 2;
+  // .top.tvm_engine.parameters.PReturnResult result = 1;
+  bool has_result() const;
   private:
-  const ::top::tvm_engine::basic::PAddress& _internal_recver_address() const;
+  bool _internal_has_result() const;
+  public:
+  void clear_result();
+  const ::top::tvm_engine::parameters::PReturnResult& result() const;
+  ::top::tvm_engine::parameters::PReturnResult* release_result();
+  ::top::tvm_engine::parameters::PReturnResult* mutable_result();
+  void set_allocated_result(::top::tvm_engine::parameters::PReturnResult* result);
+  private:
+  const ::top::tvm_engine::parameters::PReturnResult& _internal_result() const;
+  ::top::tvm_engine::parameters::PReturnResult* _internal_mutable_result();
   public:
-  const ::top::tvm_engine::basic::PAddress& recver_address() const;
-  ::top::tvm_engine::basic::PAddress* release_recver_address();
-  ::top::tvm_engine::basic::PAddress* mutable_recver_address();
-  void set_allocated_recver_address(::top::tvm_engine::basic::PAddress* recver_address);
-
-  // uint64 value = 3;
-  void clear_value();
-  static const int kValueFieldNumber = 3;
-  ::google::protobuf::uint64 value() const;
-  void set_value(::google::protobuf::uint64 value);
-
-  // uint64 gas_limit = 5;
-  void clear_gas_limit();
-  static const int kGasLimitFieldNumber = 5;
-  ::google::protobuf::uint64 gas_limit() const;
-  void set_gas_limit(::google::protobuf::uint64 value);
 
-  // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PCallArgs)
+  // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PStateDiffResult)
  private:
+  class _Internal;
 
-  ::google::protobuf::internal::InternalMetadataWithArena _internal_metadata_;
-  ::google::protobuf::internal::ArenaStringPtr input_;
-  ::top::tvm_engine::basic::PAddress* sender_address_;
-  ::top::tvm_engine::basic::PAddress* recver_address_;
-  ::google::protobuf::uint64 value_;
-  ::google::protobuf::uint64 gas_limit_;
-  mutable ::google::protobuf::internal::CachedSize _cached_size_;
-  friend struct ::protobuf_protobuf_5ftypes_2fpparameters_2eproto::TableStruct;
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr state_diff_;
+  ::top::tvm_engine::parameters::PReturnResult* result_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
 };
-// ===================================================================
+// -------------------------------------------------------------------
 
+class PCallArgs_PAccessListItem :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PCallArgs.PAccessListItem) */ {
+ public:
+  PCallArgs_PAccessListItem();
+  virtual ~PCallArgs_PAccessListItem();
 
-// ===================================================================
+  PCallArgs_PAccessListItem(const PCallArgs_PAccessListItem& from);
+  PCallArgs_PAccessListItem(PCallArgs_PAccessListItem&& from) noexcept
+    : PCallArgs_PAccessListItem() {
+    *this = ::std::move(from);
+  }
 
-#ifdef __GNUC__
-  #pragma GCC diagnostic push
-  #pragma GCC diagnostic ignored "-Wstrict-aliasing"
-#endif  // __GNUC__
-// PReturnResult_PU256
+  inline PCallArgs_PAccessListItem& operator=(const PCallArgs_PAccessListItem& from) {
+    CopyFrom(from);
+    return *this;
+  }
+  inline PCallArgs_PAccessListItem& operator=(PCallArgs_PAccessListItem&& from) noexcept {
+    if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
+      if (this != &from) InternalSwap(&from);
+    } else {
+      CopyFrom(from);
+    }
+    return *this;
+  }
 
-// bytes data = 1;
-inline void PReturnResult_PU256::clear_data() {
-  data_.ClearToEmptyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-}
-inline const ::std::string& PReturnResult_PU256::data() const {
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.PU256.data)
-  return data_.GetNoArena();
-}
-inline void PReturnResult_PU256::set_data(const ::std::string& value) {
-  
-  data_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), value);
-  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.PU256.data)
-}
-#if LANG_CXX11
-inline void PReturnResult_PU256::set_data(::std::string&& value) {
-  
-  data_.SetNoArena(
-    &::google::protobuf::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
-  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PReturnResult.PU256.data)
-}
-#endif
-inline void PReturnResult_PU256::set_data(const char* value) {
-  GOOGLE_DCHECK(value != NULL);
-  
-  data_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
-  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PReturnResult.PU256.data)
-}
-inline void PReturnResult_PU256::set_data(const void* value, size_t size) {
-  
-  data_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(),
-      ::std::string(reinterpret_cast<const char*>(value), size));
-  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PReturnResult.PU256.data)
-}
-inline ::std::string* PReturnResult_PU256::mutable_data() {
-  
-  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.PU256.data)
-  return data_.MutableNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-}
-inline ::std::string* PReturnResult_PU256::release_data() {
-  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.PU256.data)
-  
-  return data_.ReleaseNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
-}
-inline void PReturnResult_PU256::set_allocated_data(::std::string* data) {
-  if (data != NULL) {
-    
-  } else {
-    
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
   }
-  data_.SetAllocatedNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), data);
-  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.PU256.data)
-}
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
+  static const PCallArgs_PAccessListItem& default_instance();
 
-// -------------------------------------------------------------------
+  static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
+  static inline const PCallArgs_PAccessListItem* internal_default_instance() {
+    return reinterpret_cast<const PCallArgs_PAccessListItem*>(
+               &_PCallArgs_PAccessListItem_default_instance_);
+  }
+  static constexpr int kIndexInFileMessages =
+    4;
 
-// PReturnResult_PLog
+  friend void swap(PCallArgs_PAccessListItem& a, PCallArgs_PAccessListItem& b) {
+    a.Swap(&b);
+  }
+  inline void Swap(PCallArgs_PAccessListItem* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
 
-// .top.tvm_engine.basic.PAddress address = 1;
-inline bool PReturnResult_PLog::has_address() const {
-  return this != internal_default_instance() && address_ != NULL;
-}
-inline const ::top::tvm_engine::basic::PAddress& PReturnResult_PLog::_internal_address() const {
-  return *address_;
-}
-inline const ::top::tvm_engine::basic::PAddress& PReturnResult_PLog::address() const {
-  const ::top::tvm_engine::basic::PAddress* p = address_;
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.PLog.address)
-  return p != NULL ? *p : *reinterpret_cast<const ::top::tvm_engine::basic::PAddress*>(
-      &::top::tvm_engine::basic::_PAddress_default_instance_);
-}
-inline ::top::tvm_engine::basic::PAddress* PReturnResult_PLog::release_address() {
-  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.PLog.address)
-  
-  ::top::tvm_engine::basic::PAddress* temp = address_;
-  address_ = NULL;
-  return temp;
-}
-inline ::top::tvm_engine::basic::PAddress* PReturnResult_PLog::mutable_address() {
+  // implements Message ----------------------------------------------
+
+  inline PCallArgs_PAccessListItem* New() const final {
+    return CreateMaybeMessage<PCallArgs_PAccessListItem>(nullptr);
+  }
+
+  PCallArgs_PAccessListItem* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
+    return CreateMaybeMessage<PCallArgs_PAccessListItem>(arena);
+  }
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void CopyFrom(const PCallArgs_PAccessListItem& from);
+  void MergeFrom(const PCallArgs_PAccessListItem& from);
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
+  bool IsInitialized() const final;
+
+  size_t ByteSizeLong() const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
+  int GetCachedSize() const final { return _cached_size_.Get(); }
+
+  private:
+  inline void SharedCtor();
+  inline void SharedDtor();
+  void SetCachedSize(int size) const final;
+  void InternalSwap(PCallArgs_PAccessListItem* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PCallArgs.PAccessListItem";
+  }
+  private:
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
+  }
+  inline void* MaybeArenaPtr() const {
+    return nullptr;
+  }
+  public:
+
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
+
+  // nested types ----------------------------------------------------
+
+  // accessors -------------------------------------------------------
+
+  enum : int {
+    kStorageKeysFieldNumber = 2,
+    kAddressFieldNumber = 1,
+  };
+  // repeated bytes storage_keys = 2;
+  int storage_keys_size() const;
+  private:
+  int _internal_storage_keys_size() const;
+  public:
+  void clear_storage_keys();
+  const std::string& storage_keys(int index) const;
+  std::string* mutable_storage_keys(int index);
+  void set_storage_keys(int index, const std::string& value);
+  void set_storage_keys(int index, std::string&& value);
+  void set_storage_keys(int index, const char* value);
+  void set_storage_keys(int index, const void* value, size_t size);
+  std::string* add_storage_keys();
+  void add_storage_keys(const std::string& value);
+  void add_storage_keys(std::string&& value);
+  void add_storage_keys(const char* value);
+  void add_storage_keys(const void* value, size_t size);
+  const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField<std::string>& storage_keys() const;
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField<std::string>* mutable_storage_keys();
+  private:
+  const std::string& _internal_storage_keys(int index) const;
+  std::string* _internal_add_storage_keys();
+  public:
+
+  // .top.tvm_engine.basic.PAddress address = 1;
+  bool has_address() const;
+  private:
+  bool _internal_has_address() const;
+  public:
+  void clear_address();
+  const ::top::tvm_engine::basic::PAddress& address() const;
+  ::top::tvm_engine::basic::PAddress* release_address();
+  ::top::tvm_engine::basic::PAddress* mutable_address();
+  void set_allocated_address(::top::tvm_engine::basic::PAddress* address);
+  private:
+  const ::top::tvm_engine::basic::PAddress& _internal_address() const;
+  ::top::tvm_engine::basic::PAddress* _internal_mutable_address();
+  public:
+
+  // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
+ private:
+  class _Internal;
+
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField<std::string> storage_keys_;
+  ::top::tvm_engine::basic::PAddress* address_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
+};
+// -------------------------------------------------------------------
+
+class PCallArgs :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PCallArgs) */ {
+ public:
+  PCallArgs();
+  virtual ~PCallArgs();
+
+  PCallArgs(const PCallArgs& from);
+  PCallArgs(PCallArgs&& from) noexcept
+    : PCallArgs() {
+    *this = ::std::move(from);
+  }
+
+  inline PCallArgs& operator=(const PCallArgs& from) {
+    CopyFrom(from);
+    return *this;
+  }
+  inline PCallArgs& operator=(PCallArgs&& from) noexcept {
+    if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
+      if (this != &from) InternalSwap(&from);
+    } else {
+      CopyFrom(from);
+    }
+    return *this;
+  }
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
+  static const PCallArgs& default_instance();
+
+  static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
+  static inline const PCallArgs* internal_default_instance() {
+    return reinterpret_cast<const PCallArgs*>(
+               &_PCallArgs_default_instance_);
+  }
+  static constexpr int kIndexInFileMessages =
+    5;
+
+  friend void swap(PCallArgs& a, PCallArgs& b) {
+    a.Swap(&b);
+  }
+  inline void Swap(PCallArgs* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
+
+  // implements Message ----------------------------------------------
+
+  inline PCallArgs* New() const final {
+    return CreateMaybeMessage<PCallArgs>(nullptr);
+  }
+
+  PCallArgs* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
+    return CreateMaybeMessage<PCallArgs>(arena);
+  }
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void CopyFrom(const PCallArgs& from);
+  void MergeFrom(const PCallArgs& from);
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
+  bool IsInitialized() const final;
+
+  size_t ByteSizeLong() const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
+  int GetCachedSize() const final { return _cached_size_.Get(); }
+
+  private:
+  inline void SharedCtor();
+  inline void SharedDtor();
+  void SetCachedSize(int size) const final;
+  void InternalSwap(PCallArgs* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PCallArgs";
+  }
+  private:
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
+  }
+  inline void* MaybeArenaPtr() const {
+    return nullptr;
+  }
+  public:
+
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
+
+  // nested types ----------------------------------------------------
+
+  typedef PCallArgs_PAccessListItem PAccessListItem;
+
+  // accessors -------------------------------------------------------
+
+  enum : int {
+    kAccessListFieldNumber = 9,
+    kInputFieldNumber = 4,
+    kSaltFieldNumber = 12,
+    kSenderAddressFieldNumber = 1,
+    kRecverAddressFieldNumber = 2,
+    kNonceFieldNumber = 10,
+    kValueFieldNumber = 3,
+    kGasLimitFieldNumber = 5,
+    kGasPriceFieldNumber = 6,
+    kMaxFeePerGasFieldNumber = 7,
+    kMaxPriorityFeePerGasFieldNumber = 8,
+    kChainIdFieldNumber = 11,
+  };
+  // repeated .top.tvm_engine.parameters.PCallArgs.PAccessListItem access_list = 9;
+  int access_list_size() const;
+  private:
+  int _internal_access_list_size() const;
+  public:
+  void clear_access_list();
+  ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* mutable_access_list(int index);
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem >*
+      mutable_access_list();
+  private:
+  const ::top::tvm_engine::parameters::PCallArgs_PAccessListItem& _internal_access_list(int index) const;
+  ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* _internal_add_access_list();
+  public:
+  const ::top::tvm_engine::parameters::PCallArgs_PAccessListItem& access_list(int index) const;
+  ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* add_access_list();
+  const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem >&
+      access_list() const;
+
+  // bytes input = 4;
+  void clear_input();
+  const std::string& input() const;
+  void set_input(const std::string& value);
+  void set_input(std::string&& value);
+  void set_input(const char* value);
+  void set_input(const void* value, size_t size);
+  std::string* mutable_input();
+  std::string* release_input();
+  void set_allocated_input(std::string* input);
+  private:
+  const std::string& _internal_input() const;
+  void _internal_set_input(const std::string& value);
+  std::string* _internal_mutable_input();
+  public:
+
+  // bytes salt = 12;
+  void clear_salt();
+  const std::string& salt() const;
+  void set_salt(const std::string& value);
+  void set_salt(std::string&& value);
+  void set_salt(const char* value);
+  void set_salt(const void* value, size_t size);
+  std::string* mutable_salt();
+  std::string* release_salt();
+  void set_allocated_salt(std::string* salt);
+  private:
+  const std::string& _internal_salt() const;
+  void _internal_set_salt(const std::string& value);
+  std::string* _internal_mutable_salt();
+  public:
+
+  // .top.tvm_engine.basic.PAddress sender_address = 1;
+  bool has_sender_address() const;
+  private:
+  bool _internal_has_sender_address() const;
+  public:
+  void clear_sender_address();
+  const ::top::tvm_engine::basic::PAddress& sender_address() const;
+  ::top::tvm_engine::basic::PAddress* release_sender_address();
+  ::top::tvm_engine::basic::PAddress* mutable_sender_address();
+  void set_allocated_sender_address(::top::tvm_engine::basic::PAddress* sender_address);
+  private:
+  const ::top::tvm_engine::basic::PAddress& _internal_sender_address() const;
+  ::top::tvm_engine::basic::PAddress* _internal_mutable_sender_address();
+  public:
+
+  // .top.tvm_engine.basic.PAddress recver_address = 2;
+  bool has_recver_address() const;
+  private:
+  bool _internal_has_recver_address() const;
+  public:
+  void clear_recver_address();
+  const ::top::tvm_engine::basic::PAddress& recver_address() const;
+  ::top::tvm_engine::basic::PAddress* release_recver_address();
+  ::top::tvm_engine::basic::PAddress* mutable_recver_address();
+  void set_allocated_recver_address(::top::tvm_engine::basic::PAddress* recver_address);
+  private:
+  const ::top::tvm_engine::basic::PAddress& _internal_recver_address() const;
+  ::top::tvm_engine::basic::PAddress* _internal_mutable_recver_address();
+  public:
+
+  // .top.tvm_engine.parameters.PReturnResult.PU256 nonce = 10;
+  bool has_nonce() const;
+  private:
+  bool _internal_has_nonce() const;
+  public:
+  void clear_nonce();
+  const ::top::tvm_engine::parameters::PReturnResult_PU256& nonce() const;
+  ::top::tvm_engine::parameters::PReturnResult_PU256* release_nonce();
+  ::top::tvm_engine::parameters::PReturnResult_PU256* mutable_nonce();
+  void set_allocated_nonce(::top::tvm_engine::parameters::PReturnResult_PU256* nonce);
+  private:
+  const ::top::tvm_engine::parameters::PReturnResult_PU256& _internal_nonce() const;
+  ::top::tvm_engine::parameters::PReturnResult_PU256* _internal_mutable_nonce();
+  public:
+
+  // uint64 value = 3;
+  void clear_value();
+  ::PROTOBUF_NAMESPACE_ID::uint64 value() const;
+  void set_value(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_value() const;
+  void _internal_set_value(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // uint64 gas_limit = 5;
+  void clear_gas_limit();
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_limit() const;
+  void set_gas_limit(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_gas_limit() const;
+  void _internal_set_gas_limit(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // uint64 gas_price = 6;
+  void clear_gas_price();
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_price() const;
+  void set_gas_price(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_gas_price() const;
+  void _internal_set_gas_price(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // uint64 max_fee_per_gas = 7;
+  void clear_max_fee_per_gas();
+  ::PROTOBUF_NAMESPACE_ID::uint64 max_fee_per_gas() const;
+  void set_max_fee_per_gas(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_max_fee_per_gas() const;
+  void _internal_set_max_fee_per_gas(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // uint64 max_priority_fee_per_gas = 8;
+  void clear_max_priority_fee_per_gas();
+  ::PROTOBUF_NAMESPACE_ID::uint64 max_priority_fee_per_gas() const;
+  void set_max_priority_fee_per_gas(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_max_priority_fee_per_gas() const;
+  void _internal_set_max_priority_fee_per_gas(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // uint64 chain_id = 11;
+  void clear_chain_id();
+  ::PROTOBUF_NAMESPACE_ID::uint64 chain_id() const;
+  void set_chain_id(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_chain_id() const;
+  void _internal_set_chain_id(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PCallArgs)
+ private:
+  class _Internal;
+
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem > access_list_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr input_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr salt_;
+  ::top::tvm_engine::basic::PAddress* sender_address_;
+  ::top::tvm_engine::basic::PAddress* recver_address_;
+  ::top::tvm_engine::parameters::PReturnResult_PU256* nonce_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 value_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_limit_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_price_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 max_fee_per_gas_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 max_priority_fee_per_gas_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 chain_id_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
+};
+// -------------------------------------------------------------------
+
+class PEstimateGasResult :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PEstimateGasResult) */ {
+ public:
+  PEstimateGasResult();
+  virtual ~PEstimateGasResult();
+
+  PEstimateGasResult(const PEstimateGasResult& from);
+  PEstimateGasResult(PEstimateGasResult&& from) noexcept
+    : PEstimateGasResult() {
+    *this = ::std::move(from);
+  }
+
+  inline PEstimateGasResult& operator=(const PEstimateGasResult& from) {
+    CopyFrom(from);
+    return *this;
+  }
+  inline PEstimateGasResult& operator=(PEstimateGasResult&& from) noexcept {
+    if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
+      if (this != &from) InternalSwap(&from);
+    } else {
+      CopyFrom(from);
+    }
+    return *this;
+  }
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
+  static const PEstimateGasResult& default_instance();
+
+  static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
+  static inline const PEstimateGasResult* internal_default_instance() {
+    return reinterpret_cast<const PEstimateGasResult*>(
+               &_PEstimateGasResult_default_instance_);
+  }
+  static constexpr int kIndexInFileMessages =
+    6;
+
+  friend void swap(PEstimateGasResult& a, PEstimateGasResult& b) {
+    a.Swap(&b);
+  }
+  inline void Swap(PEstimateGasResult* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
+
+  // implements Message ----------------------------------------------
+
+  inline PEstimateGasResult* New() const final {
+    return CreateMaybeMessage<PEstimateGasResult>(nullptr);
+  }
+
+  PEstimateGasResult* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
+    return CreateMaybeMessage<PEstimateGasResult>(arena);
+  }
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void CopyFrom(const PEstimateGasResult& from);
+  void MergeFrom(const PEstimateGasResult& from);
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
+  bool IsInitialized() const final;
+
+  size_t ByteSizeLong() const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
+  int GetCachedSize() const final { return _cached_size_.Get(); }
+
+  private:
+  inline void SharedCtor();
+  inline void SharedDtor();
+  void SetCachedSize(int size) const final;
+  void InternalSwap(PEstimateGasResult* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PEstimateGasResult";
+  }
+  private:
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
+  }
+  inline void* MaybeArenaPtr() const {
+    return nullptr;
+  }
+  public:
+
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
+
+  // nested types ----------------------------------------------------
+
+  // accessors -------------------------------------------------------
+
+  enum : int {
+    kStatusDataFieldNumber = 2,
+    kErrorMessageFieldNumber = 5,
+    kGasUsedFieldNumber = 3,
+    kGasLimitFieldNumber = 4,
+    kStatusFieldNumber = 1,
+  };
+  // bytes status_data = 2;
+  void clear_status_data();
+  const std::string& status_data() const;
+  void set_status_data(const std::string& value);
+  void set_status_data(std::string&& value);
+  void set_status_data(const char* value);
+  void set_status_data(const void* value, size_t size);
+  std::string* mutable_status_data();
+  std::string* release_status_data();
+  void set_allocated_status_data(std::string* status_data);
+  private:
+  const std::string& _internal_status_data() const;
+  void _internal_set_status_data(const std::string& value);
+  std::string* _internal_mutable_status_data();
+  public:
+
+  // string error_message = 5;
+  void clear_error_message();
+  const std::string& error_message() const;
+  void set_error_message(const std::string& value);
+  void set_error_message(std::string&& value);
+  void set_error_message(const char* value);
+  void set_error_message(const char* value, size_t size);
+  std::string* mutable_error_message();
+  std::string* release_error_message();
+  void set_allocated_error_message(std::string* error_message);
+  private:
+  const std::string& _internal_error_message() const;
+  void _internal_set_error_message(const std::string& value);
+  std::string* _internal_mutable_error_message();
+  public:
+
+  // uint64 gas_used = 3;
+  void clear_gas_used();
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_used() const;
+  void set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_gas_used() const;
+  void _internal_set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // uint64 gas_limit = 4;
+  void clear_gas_limit();
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_limit() const;
+  void set_gas_limit(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_gas_limit() const;
+  void _internal_set_gas_limit(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
+  void clear_status();
+  ::top::tvm_engine::parameters::PStatusCode status() const;
+  void set_status(::top::tvm_engine::parameters::PStatusCode value);
+  private:
+  ::top::tvm_engine::parameters::PStatusCode _internal_status() const;
+  void _internal_set_status(::top::tvm_engine::parameters::PStatusCode value);
+  public:
+
+  // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PEstimateGasResult)
+ private:
+  class _Internal;
+
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr status_data_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr error_message_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_used_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_limit_;
+  int status_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
+};
+// -------------------------------------------------------------------
+
+class PAccessListResult :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PAccessListResult) */ {
+ public:
+  PAccessListResult();
+  virtual ~PAccessListResult();
+
+  PAccessListResult(const PAccessListResult& from);
+  PAccessListResult(PAccessListResult&& from) noexcept
+    : PAccessListResult() {
+    *this = ::std::move(from);
+  }
+
+  inline PAccessListResult& operator=(const PAccessListResult& from) {
+    CopyFrom(from);
+    return *this;
+  }
+  inline PAccessListResult& operator=(PAccessListResult&& from) noexcept {
+    if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
+      if (this != &from) InternalSwap(&from);
+    } else {
+      CopyFrom(from);
+    }
+    return *this;
+  }
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
+  static const PAccessListResult& default_instance();
+
+  static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
+  static inline const PAccessListResult* internal_default_instance() {
+    return reinterpret_cast<const PAccessListResult*>(
+               &_PAccessListResult_default_instance_);
+  }
+  static constexpr int kIndexInFileMessages =
+    7;
+
+  friend void swap(PAccessListResult& a, PAccessListResult& b) {
+    a.Swap(&b);
+  }
+  inline void Swap(PAccessListResult* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
+
+  // implements Message ----------------------------------------------
+
+  inline PAccessListResult* New() const final {
+    return CreateMaybeMessage<PAccessListResult>(nullptr);
+  }
+
+  PAccessListResult* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
+    return CreateMaybeMessage<PAccessListResult>(arena);
+  }
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void CopyFrom(const PAccessListResult& from);
+  void MergeFrom(const PAccessListResult& from);
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
+  bool IsInitialized() const final;
+
+  size_t ByteSizeLong() const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
+  int GetCachedSize() const final { return _cached_size_.Get(); }
+
+  private:
+  inline void SharedCtor();
+  inline void SharedDtor();
+  void SetCachedSize(int size) const final;
+  void InternalSwap(PAccessListResult* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PAccessListResult";
+  }
+  private:
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
+  }
+  inline void* MaybeArenaPtr() const {
+    return nullptr;
+  }
+  public:
+
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
+
+  // nested types ----------------------------------------------------
+
+  // accessors -------------------------------------------------------
+
+  enum : int {
+    kAccessListFieldNumber = 4,
+    kStatusDataFieldNumber = 2,
+    kErrorMessageFieldNumber = 5,
+    kGasUsedFieldNumber = 3,
+    kStatusFieldNumber = 1,
+  };
+  // repeated .top.tvm_engine.parameters.PCallArgs.PAccessListItem access_list = 4;
+  int access_list_size() const;
+  private:
+  int _internal_access_list_size() const;
+  public:
+  void clear_access_list();
+  ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* mutable_access_list(int index);
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem >*
+      mutable_access_list();
+  private:
+  const ::top::tvm_engine::parameters::PCallArgs_PAccessListItem& _internal_access_list(int index) const;
+  ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* _internal_add_access_list();
+  public:
+  const ::top::tvm_engine::parameters::PCallArgs_PAccessListItem& access_list(int index) const;
+  ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* add_access_list();
+  const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem >&
+      access_list() const;
+
+  // bytes status_data = 2;
+  void clear_status_data();
+  const std::string& status_data() const;
+  void set_status_data(const std::string& value);
+  void set_status_data(std::string&& value);
+  void set_status_data(const char* value);
+  void set_status_data(const void* value, size_t size);
+  std::string* mutable_status_data();
+  std::string* release_status_data();
+  void set_allocated_status_data(std::string* status_data);
+  private:
+  const std::string& _internal_status_data() const;
+  void _internal_set_status_data(const std::string& value);
+  std::string* _internal_mutable_status_data();
+  public:
+
+  // string error_message = 5;
+  void clear_error_message();
+  const std::string& error_message() const;
+  void set_error_message(const std::string& value);
+  void set_error_message(std::string&& value);
+  void set_error_message(const char* value);
+  void set_error_message(const char* value, size_t size);
+  std::string* mutable_error_message();
+  std::string* release_error_message();
+  void set_allocated_error_message(std::string* error_message);
+  private:
+  const std::string& _internal_error_message() const;
+  void _internal_set_error_message(const std::string& value);
+  std::string* _internal_mutable_error_message();
+  public:
+
+  // uint64 gas_used = 3;
+  void clear_gas_used();
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_used() const;
+  void set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_gas_used() const;
+  void _internal_set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
+  void clear_status();
+  ::top::tvm_engine::parameters::PStatusCode status() const;
+  void set_status(::top::tvm_engine::parameters::PStatusCode value);
+  private:
+  ::top::tvm_engine::parameters::PStatusCode _internal_status() const;
+  void _internal_set_status(::top::tvm_engine::parameters::PStatusCode value);
+  public:
+
+  // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PAccessListResult)
+ private:
+  class _Internal;
+
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem > access_list_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr status_data_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr error_message_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 gas_used_;
+  int status_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
+};
+// -------------------------------------------------------------------
+
+class PDeployAddressResult :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PDeployAddressResult) */ {
+ public:
+  PDeployAddressResult();
+  virtual ~PDeployAddressResult();
+
+  PDeployAddressResult(const PDeployAddressResult& from);
+  PDeployAddressResult(PDeployAddressResult&& from) noexcept
+    : PDeployAddressResult() {
+    *this = ::std::move(from);
+  }
+
+  inline PDeployAddressResult& operator=(const PDeployAddressResult& from) {
+    CopyFrom(from);
+    return *this;
+  }
+  inline PDeployAddressResult& operator=(PDeployAddressResult&& from) noexcept {
+    if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
+      if (this != &from) InternalSwap(&from);
+    } else {
+      CopyFrom(from);
+    }
+    return *this;
+  }
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
+  static const PDeployAddressResult& default_instance();
+
+  static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
+  static inline const PDeployAddressResult* internal_default_instance() {
+    return reinterpret_cast<const PDeployAddressResult*>(
+               &_PDeployAddressResult_default_instance_);
+  }
+  static constexpr int kIndexInFileMessages =
+    8;
+
+  friend void swap(PDeployAddressResult& a, PDeployAddressResult& b) {
+    a.Swap(&b);
+  }
+  inline void Swap(PDeployAddressResult* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
+
+  // implements Message ----------------------------------------------
+
+  inline PDeployAddressResult* New() const final {
+    return CreateMaybeMessage<PDeployAddressResult>(nullptr);
+  }
+
+  PDeployAddressResult* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
+    return CreateMaybeMessage<PDeployAddressResult>(arena);
+  }
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void CopyFrom(const PDeployAddressResult& from);
+  void MergeFrom(const PDeployAddressResult& from);
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
+  bool IsInitialized() const final;
+
+  size_t ByteSizeLong() const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
+  int GetCachedSize() const final { return _cached_size_.Get(); }
+
+  private:
+  inline void SharedCtor();
+  inline void SharedDtor();
+  void SetCachedSize(int size) const final;
+  void InternalSwap(PDeployAddressResult* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PDeployAddressResult";
+  }
+  private:
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
+  }
+  inline void* MaybeArenaPtr() const {
+    return nullptr;
+  }
+  public:
+
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
+
+  // nested types ----------------------------------------------------
+
+  // accessors -------------------------------------------------------
+
+  enum : int {
+    kStatusDataFieldNumber = 2,
+    kSaltFieldNumber = 4,
+    kErrorMessageFieldNumber = 5,
+    kContractAddressFieldNumber = 3,
+    kStatusFieldNumber = 1,
+  };
+  // bytes status_data = 2;
+  void clear_status_data();
+  const std::string& status_data() const;
+  void set_status_data(const std::string& value);
+  void set_status_data(std::string&& value);
+  void set_status_data(const char* value);
+  void set_status_data(const void* value, size_t size);
+  std::string* mutable_status_data();
+  std::string* release_status_data();
+  void set_allocated_status_data(std::string* status_data);
+  private:
+  const std::string& _internal_status_data() const;
+  void _internal_set_status_data(const std::string& value);
+  std::string* _internal_mutable_status_data();
+  public:
+
+  // bytes salt = 4;
+  void clear_salt();
+  const std::string& salt() const;
+  void set_salt(const std::string& value);
+  void set_salt(std::string&& value);
+  void set_salt(const char* value);
+  void set_salt(const void* value, size_t size);
+  std::string* mutable_salt();
+  std::string* release_salt();
+  void set_allocated_salt(std::string* salt);
+  private:
+  const std::string& _internal_salt() const;
+  void _internal_set_salt(const std::string& value);
+  std::string* _internal_mutable_salt();
+  public:
+
+  // string error_message = 5;
+  void clear_error_message();
+  const std::string& error_message() const;
+  void set_error_message(const std::string& value);
+  void set_error_message(std::string&& value);
+  void set_error_message(const char* value);
+  void set_error_message(const char* value, size_t size);
+  std::string* mutable_error_message();
+  std::string* release_error_message();
+  void set_allocated_error_message(std::string* error_message);
+  private:
+  const std::string& _internal_error_message() const;
+  void _internal_set_error_message(const std::string& value);
+  std::string* _internal_mutable_error_message();
+  public:
+
+  // .top.tvm_engine.basic.PAddress contract_address = 3;
+  bool has_contract_address() const;
+  private:
+  bool _internal_has_contract_address() const;
+  public:
+  void clear_contract_address();
+  const ::top::tvm_engine::basic::PAddress& contract_address() const;
+  ::top::tvm_engine::basic::PAddress* release_contract_address();
+  ::top::tvm_engine::basic::PAddress* mutable_contract_address();
+  void set_allocated_contract_address(::top::tvm_engine::basic::PAddress* contract_address);
+  private:
+  const ::top::tvm_engine::basic::PAddress& _internal_contract_address() const;
+  ::top::tvm_engine::basic::PAddress* _internal_mutable_contract_address();
+  public:
+
+  // .top.tvm_engine.parameters.PStatusCode status = 1;
+  void clear_status();
+  ::top::tvm_engine::parameters::PStatusCode status() const;
+  void set_status(::top::tvm_engine::parameters::PStatusCode value);
+  private:
+  ::top::tvm_engine::parameters::PStatusCode _internal_status() const;
+  void _internal_set_status(::top::tvm_engine::parameters::PStatusCode value);
+  public:
+
+  // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PDeployAddressResult)
+ private:
+  class _Internal;
+
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr status_data_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr salt_;
+  ::PROTOBUF_NAMESPACE_ID::internal::ArenaStringPtr error_message_;
+  ::top::tvm_engine::basic::PAddress* contract_address_;
+  int status_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
+};
+// -------------------------------------------------------------------
+
+class PForkSchedule_PActivation :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PForkSchedule.PActivation) */ {
+ public:
+  PForkSchedule_PActivation();
+  virtual ~PForkSchedule_PActivation();
+
+  PForkSchedule_PActivation(const PForkSchedule_PActivation& from);
+  PForkSchedule_PActivation(PForkSchedule_PActivation&& from) noexcept
+    : PForkSchedule_PActivation() {
+    *this = ::std::move(from);
+  }
+
+  inline PForkSchedule_PActivation& operator=(const PForkSchedule_PActivation& from) {
+    CopyFrom(from);
+    return *this;
+  }
+  inline PForkSchedule_PActivation& operator=(PForkSchedule_PActivation&& from) noexcept {
+    if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
+      if (this != &from) InternalSwap(&from);
+    } else {
+      CopyFrom(from);
+    }
+    return *this;
+  }
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
+  static const PForkSchedule_PActivation& default_instance();
+
+  static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
+  static inline const PForkSchedule_PActivation* internal_default_instance() {
+    return reinterpret_cast<const PForkSchedule_PActivation*>(
+               &_PForkSchedule_PActivation_default_instance_);
+  }
+  static constexpr int kIndexInFileMessages =
+    9;
+
+  friend void swap(PForkSchedule_PActivation& a, PForkSchedule_PActivation& b) {
+    a.Swap(&b);
+  }
+  inline void Swap(PForkSchedule_PActivation* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
+
+  // implements Message ----------------------------------------------
+
+  inline PForkSchedule_PActivation* New() const final {
+    return CreateMaybeMessage<PForkSchedule_PActivation>(nullptr);
+  }
+
+  PForkSchedule_PActivation* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
+    return CreateMaybeMessage<PForkSchedule_PActivation>(arena);
+  }
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void CopyFrom(const PForkSchedule_PActivation& from);
+  void MergeFrom(const PForkSchedule_PActivation& from);
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
+  bool IsInitialized() const final;
+
+  size_t ByteSizeLong() const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
+  int GetCachedSize() const final { return _cached_size_.Get(); }
+
+  private:
+  inline void SharedCtor();
+  inline void SharedDtor();
+  void SetCachedSize(int size) const final;
+  void InternalSwap(PForkSchedule_PActivation* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PForkSchedule.PActivation";
+  }
+  private:
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
+  }
+  inline void* MaybeArenaPtr() const {
+    return nullptr;
+  }
+  public:
+
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
+
+  // nested types ----------------------------------------------------
+
+  // accessors -------------------------------------------------------
+
+  enum : int {
+    kHeightFieldNumber = 1,
+    kHardforkFieldNumber = 2,
+  };
+  // uint64 height = 1;
+  void clear_height();
+  ::PROTOBUF_NAMESPACE_ID::uint64 height() const;
+  void set_height(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  private:
+  ::PROTOBUF_NAMESPACE_ID::uint64 _internal_height() const;
+  void _internal_set_height(::PROTOBUF_NAMESPACE_ID::uint64 value);
+  public:
+
+  // .top.tvm_engine.parameters.PHardfork hardfork = 2;
+  void clear_hardfork();
+  ::top::tvm_engine::parameters::PHardfork hardfork() const;
+  void set_hardfork(::top::tvm_engine::parameters::PHardfork value);
+  private:
+  ::top::tvm_engine::parameters::PHardfork _internal_hardfork() const;
+  void _internal_set_hardfork(::top::tvm_engine::parameters::PHardfork value);
+  public:
+
+  // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PForkSchedule.PActivation)
+ private:
+  class _Internal;
+
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::uint64 height_;
+  int hardfork_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
+};
+// -------------------------------------------------------------------
+
+class PForkSchedule :
+    public ::PROTOBUF_NAMESPACE_ID::Message /* @@protoc_insertion_point(class_definition:top.tvm_engine.parameters.PForkSchedule) */ {
+ public:
+  PForkSchedule();
+  virtual ~PForkSchedule();
+
+  PForkSchedule(const PForkSchedule& from);
+  PForkSchedule(PForkSchedule&& from) noexcept
+    : PForkSchedule() {
+    *this = ::std::move(from);
+  }
+
+  inline PForkSchedule& operator=(const PForkSchedule& from) {
+    CopyFrom(from);
+    return *this;
+  }
+  inline PForkSchedule& operator=(PForkSchedule&& from) noexcept {
+    if (GetArenaNoVirtual() == from.GetArenaNoVirtual()) {
+      if (this != &from) InternalSwap(&from);
+    } else {
+      CopyFrom(from);
+    }
+    return *this;
+  }
+
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* descriptor() {
+    return GetDescriptor();
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Descriptor* GetDescriptor() {
+    return GetMetadataStatic().descriptor;
+  }
+  static const ::PROTOBUF_NAMESPACE_ID::Reflection* GetReflection() {
+    return GetMetadataStatic().reflection;
+  }
+  static const PForkSchedule& default_instance();
+
+  static void InitAsDefaultInstance();  // FOR INTERNAL USE ONLY
+  static inline const PForkSchedule* internal_default_instance() {
+    return reinterpret_cast<const PForkSchedule*>(
+               &_PForkSchedule_default_instance_);
+  }
+  static constexpr int kIndexInFileMessages =
+    10;
+
+  friend void swap(PForkSchedule& a, PForkSchedule& b) {
+    a.Swap(&b);
+  }
+  inline void Swap(PForkSchedule* other) {
+    if (other == this) return;
+    InternalSwap(other);
+  }
+
+  // implements Message ----------------------------------------------
+
+  inline PForkSchedule* New() const final {
+    return CreateMaybeMessage<PForkSchedule>(nullptr);
+  }
+
+  PForkSchedule* New(::PROTOBUF_NAMESPACE_ID::Arena* arena) const final {
+    return CreateMaybeMessage<PForkSchedule>(arena);
+  }
+  void CopyFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void MergeFrom(const ::PROTOBUF_NAMESPACE_ID::Message& from) final;
+  void CopyFrom(const PForkSchedule& from);
+  void MergeFrom(const PForkSchedule& from);
+  PROTOBUF_ATTRIBUTE_REINITIALIZES void Clear() final;
+  bool IsInitialized() const final;
+
+  size_t ByteSizeLong() const final;
+  const char* _InternalParse(const char* ptr, ::PROTOBUF_NAMESPACE_ID::internal::ParseContext* ctx) final;
+  ::PROTOBUF_NAMESPACE_ID::uint8* _InternalSerialize(
+      ::PROTOBUF_NAMESPACE_ID::uint8* target, ::PROTOBUF_NAMESPACE_ID::io::EpsCopyOutputStream* stream) const final;
+  int GetCachedSize() const final { return _cached_size_.Get(); }
+
+  private:
+  inline void SharedCtor();
+  inline void SharedDtor();
+  void SetCachedSize(int size) const final;
+  void InternalSwap(PForkSchedule* other);
+  friend class ::PROTOBUF_NAMESPACE_ID::internal::AnyMetadata;
+  static ::PROTOBUF_NAMESPACE_ID::StringPiece FullMessageName() {
+    return "top.tvm_engine.parameters.PForkSchedule";
+  }
+  private:
+  inline ::PROTOBUF_NAMESPACE_ID::Arena* GetArenaNoVirtual() const {
+    return nullptr;
+  }
+  inline void* MaybeArenaPtr() const {
+    return nullptr;
+  }
+  public:
+
+  ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadata() const final;
+  private:
+  static ::PROTOBUF_NAMESPACE_ID::Metadata GetMetadataStatic() {
+    ::PROTOBUF_NAMESPACE_ID::internal::AssignDescriptors(&::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto);
+    return ::descriptor_table_protobuf_5ftypes_2fpparameters_2eproto.file_level_metadata[kIndexInFileMessages];
+  }
+
+  public:
+
+  // nested types ----------------------------------------------------
+
+  typedef PForkSchedule_PActivation PActivation;
+
+  // accessors -------------------------------------------------------
+
+  enum : int {
+    kActivationsFieldNumber = 1,
+  };
+  // repeated .top.tvm_engine.parameters.PForkSchedule.PActivation activations = 1;
+  int activations_size() const;
+  private:
+  int _internal_activations_size() const;
+  public:
+  void clear_activations();
+  ::top::tvm_engine::parameters::PForkSchedule_PActivation* mutable_activations(int index);
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PForkSchedule_PActivation >*
+      mutable_activations();
+  private:
+  const ::top::tvm_engine::parameters::PForkSchedule_PActivation& _internal_activations(int index) const;
+  ::top::tvm_engine::parameters::PForkSchedule_PActivation* _internal_add_activations();
+  public:
+  const ::top::tvm_engine::parameters::PForkSchedule_PActivation& activations(int index) const;
+  ::top::tvm_engine::parameters::PForkSchedule_PActivation* add_activations();
+  const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PForkSchedule_PActivation >&
+      activations() const;
+
+  // @@protoc_insertion_point(class_scope:top.tvm_engine.parameters.PForkSchedule)
+ private:
+  class _Internal;
+
+  ::PROTOBUF_NAMESPACE_ID::internal::InternalMetadataWithArena _internal_metadata_;
+  ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PForkSchedule_PActivation > activations_;
+  mutable ::PROTOBUF_NAMESPACE_ID::internal::CachedSize _cached_size_;
+  friend struct ::TableStruct_protobuf_5ftypes_2fpparameters_2eproto;
+};
+// ===================================================================
+
+
+// ===================================================================
+
+#ifdef __GNUC__
+  #pragma GCC diagnostic push
+  #pragma GCC diagnostic ignored "-Wstrict-aliasing"
+#endif  // __GNUC__
+// PReturnResult_PU256
+
+// bytes data = 1;
+inline void PReturnResult_PU256::clear_data() {
+  data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PReturnResult_PU256::data() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.PU256.data)
+  return _internal_data();
+}
+inline void PReturnResult_PU256::set_data(const std::string& value) {
+  _internal_set_data(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.PU256.data)
+}
+inline std::string* PReturnResult_PU256::mutable_data() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.PU256.data)
+  return _internal_mutable_data();
+}
+inline const std::string& PReturnResult_PU256::_internal_data() const {
+  return data_.GetNoArena();
+}
+inline void PReturnResult_PU256::_internal_set_data(const std::string& value) {
+  
+  data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PReturnResult_PU256::set_data(std::string&& value) {
+  
+  data_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PReturnResult.PU256.data)
+}
+inline void PReturnResult_PU256::set_data(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PReturnResult.PU256.data)
+}
+inline void PReturnResult_PU256::set_data(const void* value, size_t size) {
+  
+  data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PReturnResult.PU256.data)
+}
+inline std::string* PReturnResult_PU256::_internal_mutable_data() {
+  
+  return data_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PReturnResult_PU256::release_data() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.PU256.data)
+  
+  return data_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PReturnResult_PU256::set_allocated_data(std::string* data) {
+  if (data != nullptr) {
+    
+  } else {
+    
+  }
+  data_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), data);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.PU256.data)
+}
+
+// -------------------------------------------------------------------
+
+// PReturnResult_PLog
+
+// .top.tvm_engine.basic.PAddress address = 1;
+inline bool PReturnResult_PLog::_internal_has_address() const {
+  return this != internal_default_instance() && address_ != nullptr;
+}
+inline bool PReturnResult_PLog::has_address() const {
+  return _internal_has_address();
+}
+inline const ::top::tvm_engine::basic::PAddress& PReturnResult_PLog::_internal_address() const {
+  const ::top::tvm_engine::basic::PAddress* p = address_;
+  return p != nullptr ? *p : *reinterpret_cast<const ::top::tvm_engine::basic::PAddress*>(
+      &::top::tvm_engine::basic::_PAddress_default_instance_);
+}
+inline const ::top::tvm_engine::basic::PAddress& PReturnResult_PLog::address() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.PLog.address)
+  return _internal_address();
+}
+inline ::top::tvm_engine::basic::PAddress* PReturnResult_PLog::release_address() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.PLog.address)
+  
+  ::top::tvm_engine::basic::PAddress* temp = address_;
+  address_ = nullptr;
+  return temp;
+}
+inline ::top::tvm_engine::basic::PAddress* PReturnResult_PLog::_internal_mutable_address() {
+  
+  if (address_ == nullptr) {
+    auto* p = CreateMaybeMessage<::top::tvm_engine::basic::PAddress>(GetArenaNoVirtual());
+    address_ = p;
+  }
+  return address_;
+}
+inline ::top::tvm_engine::basic::PAddress* PReturnResult_PLog::mutable_address() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.PLog.address)
+  return _internal_mutable_address();
+}
+inline void PReturnResult_PLog::set_allocated_address(::top::tvm_engine::basic::PAddress* address) {
+  ::PROTOBUF_NAMESPACE_ID::Arena* message_arena = GetArenaNoVirtual();
+  if (message_arena == nullptr) {
+    delete reinterpret_cast< ::PROTOBUF_NAMESPACE_ID::MessageLite*>(address_);
+  }
+  if (address) {
+    ::PROTOBUF_NAMESPACE_ID::Arena* submessage_arena = nullptr;
+    if (message_arena != submessage_arena) {
+      address = ::PROTOBUF_NAMESPACE_ID::internal::GetOwnedMessage(
+          message_arena, address, submessage_arena);
+    }
+    
+  } else {
+    
+  }
+  address_ = address;
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.PLog.address)
+}
+
+// repeated .top.tvm_engine.parameters.PReturnResult.PU256 topics = 2;
+inline int PReturnResult_PLog::_internal_topics_size() const {
+  return topics_.size();
+}
+inline int PReturnResult_PLog::topics_size() const {
+  return _internal_topics_size();
+}
+inline void PReturnResult_PLog::clear_topics() {
+  topics_.Clear();
+}
+inline ::top::tvm_engine::parameters::PReturnResult_PU256* PReturnResult_PLog::mutable_topics(int index) {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.PLog.topics)
+  return topics_.Mutable(index);
+}
+inline ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PU256 >*
+PReturnResult_PLog::mutable_topics() {
+  // @@protoc_insertion_point(field_mutable_list:top.tvm_engine.parameters.PReturnResult.PLog.topics)
+  return &topics_;
+}
+inline const ::top::tvm_engine::parameters::PReturnResult_PU256& PReturnResult_PLog::_internal_topics(int index) const {
+  return topics_.Get(index);
+}
+inline const ::top::tvm_engine::parameters::PReturnResult_PU256& PReturnResult_PLog::topics(int index) const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.PLog.topics)
+  return _internal_topics(index);
+}
+inline ::top::tvm_engine::parameters::PReturnResult_PU256* PReturnResult_PLog::_internal_add_topics() {
+  return topics_.Add();
+}
+inline ::top::tvm_engine::parameters::PReturnResult_PU256* PReturnResult_PLog::add_topics() {
+  // @@protoc_insertion_point(field_add:top.tvm_engine.parameters.PReturnResult.PLog.topics)
+  return _internal_add_topics();
+}
+inline const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PU256 >&
+PReturnResult_PLog::topics() const {
+  // @@protoc_insertion_point(field_list:top.tvm_engine.parameters.PReturnResult.PLog.topics)
+  return topics_;
+}
+
+// bytes data = 3;
+inline void PReturnResult_PLog::clear_data() {
+  data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PReturnResult_PLog::data() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.PLog.data)
+  return _internal_data();
+}
+inline void PReturnResult_PLog::set_data(const std::string& value) {
+  _internal_set_data(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.PLog.data)
+}
+inline std::string* PReturnResult_PLog::mutable_data() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.PLog.data)
+  return _internal_mutable_data();
+}
+inline const std::string& PReturnResult_PLog::_internal_data() const {
+  return data_.GetNoArena();
+}
+inline void PReturnResult_PLog::_internal_set_data(const std::string& value) {
+  
+  data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PReturnResult_PLog::set_data(std::string&& value) {
+  
+  data_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PReturnResult.PLog.data)
+}
+inline void PReturnResult_PLog::set_data(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PReturnResult.PLog.data)
+}
+inline void PReturnResult_PLog::set_data(const void* value, size_t size) {
+  
+  data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PReturnResult.PLog.data)
+}
+inline std::string* PReturnResult_PLog::_internal_mutable_data() {
+  
+  return data_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PReturnResult_PLog::release_data() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.PLog.data)
+  
+  return data_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PReturnResult_PLog::set_allocated_data(std::string* data) {
+  if (data != nullptr) {
+    
+  } else {
+    
+  }
+  data_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), data);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.PLog.data)
+}
+
+// -------------------------------------------------------------------
+
+// PReturnResult
+
+// .top.tvm_engine.parameters.PStatusCode status = 1;
+inline void PReturnResult::clear_status() {
+  status_ = 0;
+}
+inline ::top::tvm_engine::parameters::PStatusCode PReturnResult::_internal_status() const {
+  return static_cast< ::top::tvm_engine::parameters::PStatusCode >(status_);
+}
+inline ::top::tvm_engine::parameters::PStatusCode PReturnResult::status() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.status)
+  return _internal_status();
+}
+inline void PReturnResult::_internal_set_status(::top::tvm_engine::parameters::PStatusCode value) {
+  
+  status_ = value;
+}
+inline void PReturnResult::set_status(::top::tvm_engine::parameters::PStatusCode value) {
+  _internal_set_status(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.status)
+}
+
+// bytes status_data = 2;
+inline void PReturnResult::clear_status_data() {
+  status_data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PReturnResult::status_data() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.status_data)
+  return _internal_status_data();
+}
+inline void PReturnResult::set_status_data(const std::string& value) {
+  _internal_set_status_data(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.status_data)
+}
+inline std::string* PReturnResult::mutable_status_data() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.status_data)
+  return _internal_mutable_status_data();
+}
+inline const std::string& PReturnResult::_internal_status_data() const {
+  return status_data_.GetNoArena();
+}
+inline void PReturnResult::_internal_set_status_data(const std::string& value) {
+  
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PReturnResult::set_status_data(std::string&& value) {
+  
+  status_data_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PReturnResult.status_data)
+}
+inline void PReturnResult::set_status_data(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PReturnResult.status_data)
+}
+inline void PReturnResult::set_status_data(const void* value, size_t size) {
+  
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PReturnResult.status_data)
+}
+inline std::string* PReturnResult::_internal_mutable_status_data() {
+  
+  return status_data_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PReturnResult::release_status_data() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.status_data)
+  
+  return status_data_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PReturnResult::set_allocated_status_data(std::string* status_data) {
+  if (status_data != nullptr) {
+    
+  } else {
+    
+  }
+  status_data_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), status_data);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.status_data)
+}
+
+// uint64 gas_used = 3;
+inline void PReturnResult::clear_gas_used() {
+  gas_used_ = PROTOBUF_ULONGLONG(0);
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PReturnResult::_internal_gas_used() const {
+  return gas_used_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PReturnResult::gas_used() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.gas_used)
+  return _internal_gas_used();
+}
+inline void PReturnResult::_internal_set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  gas_used_ = value;
+}
+inline void PReturnResult::set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_gas_used(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.gas_used)
+}
+
+// repeated .top.tvm_engine.parameters.PReturnResult.PLog logs = 4;
+inline int PReturnResult::_internal_logs_size() const {
+  return logs_.size();
+}
+inline int PReturnResult::logs_size() const {
+  return _internal_logs_size();
+}
+inline void PReturnResult::clear_logs() {
+  logs_.Clear();
+}
+inline ::top::tvm_engine::parameters::PReturnResult_PLog* PReturnResult::mutable_logs(int index) {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.logs)
+  return logs_.Mutable(index);
+}
+inline ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PLog >*
+PReturnResult::mutable_logs() {
+  // @@protoc_insertion_point(field_mutable_list:top.tvm_engine.parameters.PReturnResult.logs)
+  return &logs_;
+}
+inline const ::top::tvm_engine::parameters::PReturnResult_PLog& PReturnResult::_internal_logs(int index) const {
+  return logs_.Get(index);
+}
+inline const ::top::tvm_engine::parameters::PReturnResult_PLog& PReturnResult::logs(int index) const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.logs)
+  return _internal_logs(index);
+}
+inline ::top::tvm_engine::parameters::PReturnResult_PLog* PReturnResult::_internal_add_logs() {
+  return logs_.Add();
+}
+inline ::top::tvm_engine::parameters::PReturnResult_PLog* PReturnResult::add_logs() {
+  // @@protoc_insertion_point(field_add:top.tvm_engine.parameters.PReturnResult.logs)
+  return _internal_add_logs();
+}
+inline const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PLog >&
+PReturnResult::logs() const {
+  // @@protoc_insertion_point(field_list:top.tvm_engine.parameters.PReturnResult.logs)
+  return logs_;
+}
+
+// uint64 effective_gas_price = 5;
+inline void PReturnResult::clear_effective_gas_price() {
+  effective_gas_price_ = PROTOBUF_ULONGLONG(0);
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PReturnResult::_internal_effective_gas_price() const {
+  return effective_gas_price_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PReturnResult::effective_gas_price() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.effective_gas_price)
+  return _internal_effective_gas_price();
+}
+inline void PReturnResult::_internal_set_effective_gas_price(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  effective_gas_price_ = value;
+}
+inline void PReturnResult::set_effective_gas_price(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_effective_gas_price(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.effective_gas_price)
+}
+
+// .top.tvm_engine.basic.PAddress contract_address = 6;
+inline bool PReturnResult::_internal_has_contract_address() const {
+  return this != internal_default_instance() && contract_address_ != nullptr;
+}
+inline bool PReturnResult::has_contract_address() const {
+  return _internal_has_contract_address();
+}
+inline const ::top::tvm_engine::basic::PAddress& PReturnResult::_internal_contract_address() const {
+  const ::top::tvm_engine::basic::PAddress* p = contract_address_;
+  return p != nullptr ? *p : *reinterpret_cast<const ::top::tvm_engine::basic::PAddress*>(
+      &::top::tvm_engine::basic::_PAddress_default_instance_);
+}
+inline const ::top::tvm_engine::basic::PAddress& PReturnResult::contract_address() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.contract_address)
+  return _internal_contract_address();
+}
+inline ::top::tvm_engine::basic::PAddress* PReturnResult::release_contract_address() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.contract_address)
+  
+  ::top::tvm_engine::basic::PAddress* temp = contract_address_;
+  contract_address_ = nullptr;
+  return temp;
+}
+inline ::top::tvm_engine::basic::PAddress* PReturnResult::_internal_mutable_contract_address() {
+  
+  if (contract_address_ == nullptr) {
+    auto* p = CreateMaybeMessage<::top::tvm_engine::basic::PAddress>(GetArenaNoVirtual());
+    contract_address_ = p;
+  }
+  return contract_address_;
+}
+inline ::top::tvm_engine::basic::PAddress* PReturnResult::mutable_contract_address() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.contract_address)
+  return _internal_mutable_contract_address();
+}
+inline void PReturnResult::set_allocated_contract_address(::top::tvm_engine::basic::PAddress* contract_address) {
+  ::PROTOBUF_NAMESPACE_ID::Arena* message_arena = GetArenaNoVirtual();
+  if (message_arena == nullptr) {
+    delete reinterpret_cast< ::PROTOBUF_NAMESPACE_ID::MessageLite*>(contract_address_);
+  }
+  if (contract_address) {
+    ::PROTOBUF_NAMESPACE_ID::Arena* submessage_arena = nullptr;
+    if (message_arena != submessage_arena) {
+      contract_address = ::PROTOBUF_NAMESPACE_ID::internal::GetOwnedMessage(
+          message_arena, contract_address, submessage_arena);
+    }
+    
+  } else {
+    
+  }
+  contract_address_ = contract_address;
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.contract_address)
+}
+
+// bytes code_hash = 7;
+inline void PReturnResult::clear_code_hash() {
+  code_hash_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PReturnResult::code_hash() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.code_hash)
+  return _internal_code_hash();
+}
+inline void PReturnResult::set_code_hash(const std::string& value) {
+  _internal_set_code_hash(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.code_hash)
+}
+inline std::string* PReturnResult::mutable_code_hash() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.code_hash)
+  return _internal_mutable_code_hash();
+}
+inline const std::string& PReturnResult::_internal_code_hash() const {
+  return code_hash_.GetNoArena();
+}
+inline void PReturnResult::_internal_set_code_hash(const std::string& value) {
+  
+  code_hash_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PReturnResult::set_code_hash(std::string&& value) {
+  
+  code_hash_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PReturnResult.code_hash)
+}
+inline void PReturnResult::set_code_hash(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  code_hash_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PReturnResult.code_hash)
+}
+inline void PReturnResult::set_code_hash(const void* value, size_t size) {
+  
+  code_hash_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PReturnResult.code_hash)
+}
+inline std::string* PReturnResult::_internal_mutable_code_hash() {
+  
+  return code_hash_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PReturnResult::release_code_hash() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.code_hash)
+  
+  return code_hash_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PReturnResult::set_allocated_code_hash(std::string* code_hash) {
+  if (code_hash != nullptr) {
+    
+  } else {
+    
+  }
+  code_hash_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), code_hash);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.code_hash)
+}
+
+// uint64 code_size = 8;
+inline void PReturnResult::clear_code_size() {
+  code_size_ = PROTOBUF_ULONGLONG(0);
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PReturnResult::_internal_code_size() const {
+  return code_size_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PReturnResult::code_size() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.code_size)
+  return _internal_code_size();
+}
+inline void PReturnResult::_internal_set_code_size(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  code_size_ = value;
+}
+inline void PReturnResult::set_code_size(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_code_size(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.code_size)
+}
+
+// bytes salt = 9;
+inline void PReturnResult::clear_salt() {
+  salt_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PReturnResult::salt() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.salt)
+  return _internal_salt();
+}
+inline void PReturnResult::set_salt(const std::string& value) {
+  _internal_set_salt(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.salt)
+}
+inline std::string* PReturnResult::mutable_salt() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.salt)
+  return _internal_mutable_salt();
+}
+inline const std::string& PReturnResult::_internal_salt() const {
+  return salt_.GetNoArena();
+}
+inline void PReturnResult::_internal_set_salt(const std::string& value) {
+  
+  salt_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PReturnResult::set_salt(std::string&& value) {
+  
+  salt_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PReturnResult.salt)
+}
+inline void PReturnResult::set_salt(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  salt_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PReturnResult.salt)
+}
+inline void PReturnResult::set_salt(const void* value, size_t size) {
+  
+  salt_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PReturnResult.salt)
+}
+inline std::string* PReturnResult::_internal_mutable_salt() {
+  
+  return salt_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PReturnResult::release_salt() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.salt)
+  
+  return salt_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PReturnResult::set_allocated_salt(std::string* salt) {
+  if (salt != nullptr) {
+    
+  } else {
+    
+  }
+  salt_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), salt);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.salt)
+}
+
+// string error_message = 10;
+inline void PReturnResult::clear_error_message() {
+  error_message_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PReturnResult::error_message() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.error_message)
+  return _internal_error_message();
+}
+inline void PReturnResult::set_error_message(const std::string& value) {
+  _internal_set_error_message(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.error_message)
+}
+inline std::string* PReturnResult::mutable_error_message() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.error_message)
+  return _internal_mutable_error_message();
+}
+inline const std::string& PReturnResult::_internal_error_message() const {
+  return error_message_.GetNoArena();
+}
+inline void PReturnResult::_internal_set_error_message(const std::string& value) {
+  
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PReturnResult::set_error_message(std::string&& value) {
+  
+  error_message_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PReturnResult.error_message)
+}
+inline void PReturnResult::set_error_message(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PReturnResult.error_message)
+}
+inline void PReturnResult::set_error_message(const char* value, size_t size) {
+  
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PReturnResult.error_message)
+}
+inline std::string* PReturnResult::_internal_mutable_error_message() {
+  
+  return error_message_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PReturnResult::release_error_message() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.error_message)
+  
+  return error_message_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PReturnResult::set_allocated_error_message(std::string* error_message) {
+  if (error_message != nullptr) {
+    
+  } else {
+    
+  }
+  error_message_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), error_message);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.error_message)
+}
+
+// -------------------------------------------------------------------
+
+// PStateDiffResult
+
+// .top.tvm_engine.parameters.PReturnResult result = 1;
+inline bool PStateDiffResult::_internal_has_result() const {
+  return this != internal_default_instance() && result_ != nullptr;
+}
+inline bool PStateDiffResult::has_result() const {
+  return _internal_has_result();
+}
+inline void PStateDiffResult::clear_result() {
+  if (GetArenaNoVirtual() == nullptr && result_ != nullptr) {
+    delete result_;
+  }
+  result_ = nullptr;
+}
+inline const ::top::tvm_engine::parameters::PReturnResult& PStateDiffResult::_internal_result() const {
+  const ::top::tvm_engine::parameters::PReturnResult* p = result_;
+  return p != nullptr ? *p : *reinterpret_cast<const ::top::tvm_engine::parameters::PReturnResult*>(
+      &::top::tvm_engine::parameters::_PReturnResult_default_instance_);
+}
+inline const ::top::tvm_engine::parameters::PReturnResult& PStateDiffResult::result() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PStateDiffResult.result)
+  return _internal_result();
+}
+inline ::top::tvm_engine::parameters::PReturnResult* PStateDiffResult::release_result() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PStateDiffResult.result)
+  
+  ::top::tvm_engine::parameters::PReturnResult* temp = result_;
+  result_ = nullptr;
+  return temp;
+}
+inline ::top::tvm_engine::parameters::PReturnResult* PStateDiffResult::_internal_mutable_result() {
+  
+  if (result_ == nullptr) {
+    auto* p = CreateMaybeMessage<::top::tvm_engine::parameters::PReturnResult>(GetArenaNoVirtual());
+    result_ = p;
+  }
+  return result_;
+}
+inline ::top::tvm_engine::parameters::PReturnResult* PStateDiffResult::mutable_result() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PStateDiffResult.result)
+  return _internal_mutable_result();
+}
+inline void PStateDiffResult::set_allocated_result(::top::tvm_engine::parameters::PReturnResult* result) {
+  ::PROTOBUF_NAMESPACE_ID::Arena* message_arena = GetArenaNoVirtual();
+  if (message_arena == nullptr) {
+    delete result_;
+  }
+  if (result) {
+    ::PROTOBUF_NAMESPACE_ID::Arena* submessage_arena = nullptr;
+    if (message_arena != submessage_arena) {
+      result = ::PROTOBUF_NAMESPACE_ID::internal::GetOwnedMessage(
+          message_arena, result, submessage_arena);
+    }
+    
+  } else {
+    
+  }
+  result_ = result;
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PStateDiffResult.result)
+}
+
+// string state_diff = 2;
+inline void PStateDiffResult::clear_state_diff() {
+  state_diff_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PStateDiffResult::state_diff() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PStateDiffResult.state_diff)
+  return _internal_state_diff();
+}
+inline void PStateDiffResult::set_state_diff(const std::string& value) {
+  _internal_set_state_diff(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PStateDiffResult.state_diff)
+}
+inline std::string* PStateDiffResult::mutable_state_diff() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PStateDiffResult.state_diff)
+  return _internal_mutable_state_diff();
+}
+inline const std::string& PStateDiffResult::_internal_state_diff() const {
+  return state_diff_.GetNoArena();
+}
+inline void PStateDiffResult::_internal_set_state_diff(const std::string& value) {
+  
+  state_diff_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PStateDiffResult::set_state_diff(std::string&& value) {
+  
+  state_diff_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PStateDiffResult.state_diff)
+}
+inline void PStateDiffResult::set_state_diff(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  state_diff_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PStateDiffResult.state_diff)
+}
+inline void PStateDiffResult::set_state_diff(const char* value, size_t size) {
+  
+  state_diff_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PStateDiffResult.state_diff)
+}
+inline std::string* PStateDiffResult::_internal_mutable_state_diff() {
+  
+  return state_diff_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PStateDiffResult::release_state_diff() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PStateDiffResult.state_diff)
+  
+  return state_diff_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PStateDiffResult::set_allocated_state_diff(std::string* state_diff) {
+  if (state_diff != nullptr) {
+    
+  } else {
+    
+  }
+  state_diff_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), state_diff);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PStateDiffResult.state_diff)
+}
+
+// -------------------------------------------------------------------
+
+// PCallArgs_PAccessListItem
+
+// .top.tvm_engine.basic.PAddress address = 1;
+inline bool PCallArgs_PAccessListItem::_internal_has_address() const {
+  return this != internal_default_instance() && address_ != nullptr;
+}
+inline bool PCallArgs_PAccessListItem::has_address() const {
+  return _internal_has_address();
+}
+inline const ::top::tvm_engine::basic::PAddress& PCallArgs_PAccessListItem::_internal_address() const {
+  const ::top::tvm_engine::basic::PAddress* p = address_;
+  return p != nullptr ? *p : *reinterpret_cast<const ::top::tvm_engine::basic::PAddress*>(
+      &::top::tvm_engine::basic::_PAddress_default_instance_);
+}
+inline const ::top::tvm_engine::basic::PAddress& PCallArgs_PAccessListItem::address() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.PAccessListItem.address)
+  return _internal_address();
+}
+inline ::top::tvm_engine::basic::PAddress* PCallArgs_PAccessListItem::release_address() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PCallArgs.PAccessListItem.address)
+  
+  ::top::tvm_engine::basic::PAddress* temp = address_;
+  address_ = nullptr;
+  return temp;
+}
+inline ::top::tvm_engine::basic::PAddress* PCallArgs_PAccessListItem::_internal_mutable_address() {
+  
+  if (address_ == nullptr) {
+    auto* p = CreateMaybeMessage<::top::tvm_engine::basic::PAddress>(GetArenaNoVirtual());
+    address_ = p;
+  }
+  return address_;
+}
+inline ::top::tvm_engine::basic::PAddress* PCallArgs_PAccessListItem::mutable_address() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.PAccessListItem.address)
+  return _internal_mutable_address();
+}
+inline void PCallArgs_PAccessListItem::set_allocated_address(::top::tvm_engine::basic::PAddress* address) {
+  ::PROTOBUF_NAMESPACE_ID::Arena* message_arena = GetArenaNoVirtual();
+  if (message_arena == nullptr) {
+    delete reinterpret_cast< ::PROTOBUF_NAMESPACE_ID::MessageLite*>(address_);
+  }
+  if (address) {
+    ::PROTOBUF_NAMESPACE_ID::Arena* submessage_arena = nullptr;
+    if (message_arena != submessage_arena) {
+      address = ::PROTOBUF_NAMESPACE_ID::internal::GetOwnedMessage(
+          message_arena, address, submessage_arena);
+    }
+    
+  } else {
+    
+  }
+  address_ = address;
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PCallArgs.PAccessListItem.address)
+}
+
+// repeated bytes storage_keys = 2;
+inline int PCallArgs_PAccessListItem::_internal_storage_keys_size() const {
+  return storage_keys_.size();
+}
+inline int PCallArgs_PAccessListItem::storage_keys_size() const {
+  return _internal_storage_keys_size();
+}
+inline void PCallArgs_PAccessListItem::clear_storage_keys() {
+  storage_keys_.Clear();
+}
+inline std::string* PCallArgs_PAccessListItem::add_storage_keys() {
+  // @@protoc_insertion_point(field_add_mutable:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+  return _internal_add_storage_keys();
+}
+inline const std::string& PCallArgs_PAccessListItem::_internal_storage_keys(int index) const {
+  return storage_keys_.Get(index);
+}
+inline const std::string& PCallArgs_PAccessListItem::storage_keys(int index) const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+  return _internal_storage_keys(index);
+}
+inline std::string* PCallArgs_PAccessListItem::mutable_storage_keys(int index) {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+  return storage_keys_.Mutable(index);
+}
+inline void PCallArgs_PAccessListItem::set_storage_keys(int index, const std::string& value) {
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+  storage_keys_.Mutable(index)->assign(value);
+}
+inline void PCallArgs_PAccessListItem::set_storage_keys(int index, std::string&& value) {
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+  storage_keys_.Mutable(index)->assign(std::move(value));
+}
+inline void PCallArgs_PAccessListItem::set_storage_keys(int index, const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  storage_keys_.Mutable(index)->assign(value);
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+}
+inline void PCallArgs_PAccessListItem::set_storage_keys(int index, const void* value, size_t size) {
+  storage_keys_.Mutable(index)->assign(
+    reinterpret_cast<const char*>(value), size);
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+}
+inline std::string* PCallArgs_PAccessListItem::_internal_add_storage_keys() {
+  return storage_keys_.Add();
+}
+inline void PCallArgs_PAccessListItem::add_storage_keys(const std::string& value) {
+  storage_keys_.Add()->assign(value);
+  // @@protoc_insertion_point(field_add:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+}
+inline void PCallArgs_PAccessListItem::add_storage_keys(std::string&& value) {
+  storage_keys_.Add(std::move(value));
+  // @@protoc_insertion_point(field_add:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+}
+inline void PCallArgs_PAccessListItem::add_storage_keys(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  storage_keys_.Add()->assign(value);
+  // @@protoc_insertion_point(field_add_char:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+}
+inline void PCallArgs_PAccessListItem::add_storage_keys(const void* value, size_t size) {
+  storage_keys_.Add()->assign(reinterpret_cast<const char*>(value), size);
+  // @@protoc_insertion_point(field_add_pointer:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+}
+inline const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField<std::string>&
+PCallArgs_PAccessListItem::storage_keys() const {
+  // @@protoc_insertion_point(field_list:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+  return storage_keys_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField<std::string>*
+PCallArgs_PAccessListItem::mutable_storage_keys() {
+  // @@protoc_insertion_point(field_mutable_list:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+  return &storage_keys_;
+}
+
+// -------------------------------------------------------------------
+
+// PCallArgs
+
+// .top.tvm_engine.basic.PAddress sender_address = 1;
+inline bool PCallArgs::_internal_has_sender_address() const {
+  return this != internal_default_instance() && sender_address_ != nullptr;
+}
+inline bool PCallArgs::has_sender_address() const {
+  return _internal_has_sender_address();
+}
+inline const ::top::tvm_engine::basic::PAddress& PCallArgs::_internal_sender_address() const {
+  const ::top::tvm_engine::basic::PAddress* p = sender_address_;
+  return p != nullptr ? *p : *reinterpret_cast<const ::top::tvm_engine::basic::PAddress*>(
+      &::top::tvm_engine::basic::_PAddress_default_instance_);
+}
+inline const ::top::tvm_engine::basic::PAddress& PCallArgs::sender_address() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.sender_address)
+  return _internal_sender_address();
+}
+inline ::top::tvm_engine::basic::PAddress* PCallArgs::release_sender_address() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PCallArgs.sender_address)
+  
+  ::top::tvm_engine::basic::PAddress* temp = sender_address_;
+  sender_address_ = nullptr;
+  return temp;
+}
+inline ::top::tvm_engine::basic::PAddress* PCallArgs::_internal_mutable_sender_address() {
+  
+  if (sender_address_ == nullptr) {
+    auto* p = CreateMaybeMessage<::top::tvm_engine::basic::PAddress>(GetArenaNoVirtual());
+    sender_address_ = p;
+  }
+  return sender_address_;
+}
+inline ::top::tvm_engine::basic::PAddress* PCallArgs::mutable_sender_address() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.sender_address)
+  return _internal_mutable_sender_address();
+}
+inline void PCallArgs::set_allocated_sender_address(::top::tvm_engine::basic::PAddress* sender_address) {
+  ::PROTOBUF_NAMESPACE_ID::Arena* message_arena = GetArenaNoVirtual();
+  if (message_arena == nullptr) {
+    delete reinterpret_cast< ::PROTOBUF_NAMESPACE_ID::MessageLite*>(sender_address_);
+  }
+  if (sender_address) {
+    ::PROTOBUF_NAMESPACE_ID::Arena* submessage_arena = nullptr;
+    if (message_arena != submessage_arena) {
+      sender_address = ::PROTOBUF_NAMESPACE_ID::internal::GetOwnedMessage(
+          message_arena, sender_address, submessage_arena);
+    }
+    
+  } else {
+    
+  }
+  sender_address_ = sender_address;
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PCallArgs.sender_address)
+}
+
+// .top.tvm_engine.basic.PAddress recver_address = 2;
+inline bool PCallArgs::_internal_has_recver_address() const {
+  return this != internal_default_instance() && recver_address_ != nullptr;
+}
+inline bool PCallArgs::has_recver_address() const {
+  return _internal_has_recver_address();
+}
+inline const ::top::tvm_engine::basic::PAddress& PCallArgs::_internal_recver_address() const {
+  const ::top::tvm_engine::basic::PAddress* p = recver_address_;
+  return p != nullptr ? *p : *reinterpret_cast<const ::top::tvm_engine::basic::PAddress*>(
+      &::top::tvm_engine::basic::_PAddress_default_instance_);
+}
+inline const ::top::tvm_engine::basic::PAddress& PCallArgs::recver_address() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.recver_address)
+  return _internal_recver_address();
+}
+inline ::top::tvm_engine::basic::PAddress* PCallArgs::release_recver_address() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PCallArgs.recver_address)
+  
+  ::top::tvm_engine::basic::PAddress* temp = recver_address_;
+  recver_address_ = nullptr;
+  return temp;
+}
+inline ::top::tvm_engine::basic::PAddress* PCallArgs::_internal_mutable_recver_address() {
   
-  if (address_ == NULL) {
+  if (recver_address_ == nullptr) {
     auto* p = CreateMaybeMessage<::top::tvm_engine::basic::PAddress>(GetArenaNoVirtual());
-    address_ = p;
+    recver_address_ = p;
   }
-  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.PLog.address)
-  return address_;
+  return recver_address_;
 }
-inline void PReturnResult_PLog::set_allocated_address(::top::tvm_engine::basic::PAddress* address) {
-  ::google::protobuf::Arena* message_arena = GetArenaNoVirtual();
-  if (message_arena == NULL) {
-    delete reinterpret_cast< ::google::protobuf::MessageLite*>(address_);
+inline ::top::tvm_engine::basic::PAddress* PCallArgs::mutable_recver_address() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.recver_address)
+  return _internal_mutable_recver_address();
+}
+inline void PCallArgs::set_allocated_recver_address(::top::tvm_engine::basic::PAddress* recver_address) {
+  ::PROTOBUF_NAMESPACE_ID::Arena* message_arena = GetArenaNoVirtual();
+  if (message_arena == nullptr) {
+    delete reinterpret_cast< ::PROTOBUF_NAMESPACE_ID::MessageLite*>(recver_address_);
   }
-  if (address) {
-    ::google::protobuf::Arena* submessage_arena = NULL;
+  if (recver_address) {
+    ::PROTOBUF_NAMESPACE_ID::Arena* submessage_arena = nullptr;
     if (message_arena != submessage_arena) {
-      address = ::google::protobuf::internal::GetOwnedMessage(
-          message_arena, address, submessage_arena);
+      recver_address = ::PROTOBUF_NAMESPACE_ID::internal::GetOwnedMessage(
+          message_arena, recver_address, submessage_arena);
     }
     
   } else {
     
   }
-  address_ = address;
-  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.PLog.address)
+  recver_address_ = recver_address;
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PCallArgs.recver_address)
 }
 
-// repeated .top.tvm_engine.parameters.PReturnResult.PU256 topics = 2;
-inline int PReturnResult_PLog::topics_size() const {
-  return topics_.size();
+// uint64 value = 3;
+inline void PCallArgs::clear_value() {
+  value_ = PROTOBUF_ULONGLONG(0);
 }
-inline void PReturnResult_PLog::clear_topics() {
-  topics_.Clear();
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::_internal_value() const {
+  return value_;
 }
-inline ::top::tvm_engine::parameters::PReturnResult_PU256* PReturnResult_PLog::mutable_topics(int index) {
-  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.PLog.topics)
-  return topics_.Mutable(index);
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::value() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.value)
+  return _internal_value();
 }
-inline ::google::protobuf::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PU256 >*
-PReturnResult_PLog::mutable_topics() {
-  // @@protoc_insertion_point(field_mutable_list:top.tvm_engine.parameters.PReturnResult.PLog.topics)
-  return &topics_;
+inline void PCallArgs::_internal_set_value(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  value_ = value;
 }
-inline const ::top::tvm_engine::parameters::PReturnResult_PU256& PReturnResult_PLog::topics(int index) const {
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.PLog.topics)
-  return topics_.Get(index);
+inline void PCallArgs::set_value(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_value(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.value)
 }
-inline ::top::tvm_engine::parameters::PReturnResult_PU256* PReturnResult_PLog::add_topics() {
-  // @@protoc_insertion_point(field_add:top.tvm_engine.parameters.PReturnResult.PLog.topics)
-  return topics_.Add();
+
+// bytes input = 4;
+inline void PCallArgs::clear_input() {
+  input_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PCallArgs::input() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.input)
+  return _internal_input();
+}
+inline void PCallArgs::set_input(const std::string& value) {
+  _internal_set_input(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.input)
+}
+inline std::string* PCallArgs::mutable_input() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.input)
+  return _internal_mutable_input();
+}
+inline const std::string& PCallArgs::_internal_input() const {
+  return input_.GetNoArena();
+}
+inline void PCallArgs::_internal_set_input(const std::string& value) {
+  
+  input_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PCallArgs::set_input(std::string&& value) {
+  
+  input_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PCallArgs.input)
+}
+inline void PCallArgs::set_input(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  input_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PCallArgs.input)
+}
+inline void PCallArgs::set_input(const void* value, size_t size) {
+  
+  input_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PCallArgs.input)
+}
+inline std::string* PCallArgs::_internal_mutable_input() {
+  
+  return input_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PCallArgs::release_input() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PCallArgs.input)
+  
+  return input_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PCallArgs::set_allocated_input(std::string* input) {
+  if (input != nullptr) {
+    
+  } else {
+    
+  }
+  input_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), input);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PCallArgs.input)
+}
+
+// uint64 gas_limit = 5;
+inline void PCallArgs::clear_gas_limit() {
+  gas_limit_ = PROTOBUF_ULONGLONG(0);
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::_internal_gas_limit() const {
+  return gas_limit_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::gas_limit() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.gas_limit)
+  return _internal_gas_limit();
+}
+inline void PCallArgs::_internal_set_gas_limit(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  gas_limit_ = value;
+}
+inline void PCallArgs::set_gas_limit(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_gas_limit(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.gas_limit)
+}
+
+// uint64 gas_price = 6;
+inline void PCallArgs::clear_gas_price() {
+  gas_price_ = PROTOBUF_ULONGLONG(0);
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::_internal_gas_price() const {
+  return gas_price_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::gas_price() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.gas_price)
+  return _internal_gas_price();
+}
+inline void PCallArgs::_internal_set_gas_price(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  gas_price_ = value;
+}
+inline void PCallArgs::set_gas_price(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_gas_price(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.gas_price)
+}
+
+// uint64 max_fee_per_gas = 7;
+inline void PCallArgs::clear_max_fee_per_gas() {
+  max_fee_per_gas_ = PROTOBUF_ULONGLONG(0);
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::_internal_max_fee_per_gas() const {
+  return max_fee_per_gas_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::max_fee_per_gas() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.max_fee_per_gas)
+  return _internal_max_fee_per_gas();
+}
+inline void PCallArgs::_internal_set_max_fee_per_gas(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  max_fee_per_gas_ = value;
+}
+inline void PCallArgs::set_max_fee_per_gas(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_max_fee_per_gas(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.max_fee_per_gas)
+}
+
+// uint64 max_priority_fee_per_gas = 8;
+inline void PCallArgs::clear_max_priority_fee_per_gas() {
+  max_priority_fee_per_gas_ = PROTOBUF_ULONGLONG(0);
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::_internal_max_priority_fee_per_gas() const {
+  return max_priority_fee_per_gas_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::max_priority_fee_per_gas() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.max_priority_fee_per_gas)
+  return _internal_max_priority_fee_per_gas();
+}
+inline void PCallArgs::_internal_set_max_priority_fee_per_gas(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  max_priority_fee_per_gas_ = value;
+}
+inline void PCallArgs::set_max_priority_fee_per_gas(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_max_priority_fee_per_gas(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.max_priority_fee_per_gas)
+}
+
+// repeated .top.tvm_engine.parameters.PCallArgs.PAccessListItem access_list = 9;
+inline int PCallArgs::_internal_access_list_size() const {
+  return access_list_.size();
+}
+inline int PCallArgs::access_list_size() const {
+  return _internal_access_list_size();
+}
+inline void PCallArgs::clear_access_list() {
+  access_list_.Clear();
+}
+inline ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* PCallArgs::mutable_access_list(int index) {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.access_list)
+  return access_list_.Mutable(index);
+}
+inline ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem >*
+PCallArgs::mutable_access_list() {
+  // @@protoc_insertion_point(field_mutable_list:top.tvm_engine.parameters.PCallArgs.access_list)
+  return &access_list_;
+}
+inline const ::top::tvm_engine::parameters::PCallArgs_PAccessListItem& PCallArgs::_internal_access_list(int index) const {
+  return access_list_.Get(index);
+}
+inline const ::top::tvm_engine::parameters::PCallArgs_PAccessListItem& PCallArgs::access_list(int index) const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.access_list)
+  return _internal_access_list(index);
+}
+inline ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* PCallArgs::_internal_add_access_list() {
+  return access_list_.Add();
+}
+inline ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* PCallArgs::add_access_list() {
+  // @@protoc_insertion_point(field_add:top.tvm_engine.parameters.PCallArgs.access_list)
+  return _internal_add_access_list();
+}
+inline const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem >&
+PCallArgs::access_list() const {
+  // @@protoc_insertion_point(field_list:top.tvm_engine.parameters.PCallArgs.access_list)
+  return access_list_;
+}
+
+// .top.tvm_engine.parameters.PReturnResult.PU256 nonce = 10;
+inline bool PCallArgs::_internal_has_nonce() const {
+  return this != internal_default_instance() && nonce_ != nullptr;
+}
+inline bool PCallArgs::has_nonce() const {
+  return _internal_has_nonce();
+}
+inline void PCallArgs::clear_nonce() {
+  if (GetArenaNoVirtual() == nullptr && nonce_ != nullptr) {
+    delete nonce_;
+  }
+  nonce_ = nullptr;
+}
+inline const ::top::tvm_engine::parameters::PReturnResult_PU256& PCallArgs::_internal_nonce() const {
+  const ::top::tvm_engine::parameters::PReturnResult_PU256* p = nonce_;
+  return p != nullptr ? *p : *reinterpret_cast<const ::top::tvm_engine::parameters::PReturnResult_PU256*>(
+      &::top::tvm_engine::parameters::_PReturnResult_PU256_default_instance_);
+}
+inline const ::top::tvm_engine::parameters::PReturnResult_PU256& PCallArgs::nonce() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.nonce)
+  return _internal_nonce();
+}
+inline ::top::tvm_engine::parameters::PReturnResult_PU256* PCallArgs::release_nonce() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PCallArgs.nonce)
+  
+  ::top::tvm_engine::parameters::PReturnResult_PU256* temp = nonce_;
+  nonce_ = nullptr;
+  return temp;
+}
+inline ::top::tvm_engine::parameters::PReturnResult_PU256* PCallArgs::_internal_mutable_nonce() {
+  
+  if (nonce_ == nullptr) {
+    auto* p = CreateMaybeMessage<::top::tvm_engine::parameters::PReturnResult_PU256>(GetArenaNoVirtual());
+    nonce_ = p;
+  }
+  return nonce_;
+}
+inline ::top::tvm_engine::parameters::PReturnResult_PU256* PCallArgs::mutable_nonce() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.nonce)
+  return _internal_mutable_nonce();
+}
+inline void PCallArgs::set_allocated_nonce(::top::tvm_engine::parameters::PReturnResult_PU256* nonce) {
+  ::PROTOBUF_NAMESPACE_ID::Arena* message_arena = GetArenaNoVirtual();
+  if (message_arena == nullptr) {
+    delete nonce_;
+  }
+  if (nonce) {
+    ::PROTOBUF_NAMESPACE_ID::Arena* submessage_arena = nullptr;
+    if (message_arena != submessage_arena) {
+      nonce = ::PROTOBUF_NAMESPACE_ID::internal::GetOwnedMessage(
+          message_arena, nonce, submessage_arena);
+    }
+    
+  } else {
+    
+  }
+  nonce_ = nonce;
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PCallArgs.nonce)
+}
+
+// uint64 chain_id = 11;
+inline void PCallArgs::clear_chain_id() {
+  chain_id_ = PROTOBUF_ULONGLONG(0);
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::_internal_chain_id() const {
+  return chain_id_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PCallArgs::chain_id() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.chain_id)
+  return _internal_chain_id();
+}
+inline void PCallArgs::_internal_set_chain_id(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  chain_id_ = value;
+}
+inline void PCallArgs::set_chain_id(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_chain_id(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.chain_id)
+}
+
+// bytes salt = 12;
+inline void PCallArgs::clear_salt() {
+  salt_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PCallArgs::salt() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.salt)
+  return _internal_salt();
+}
+inline void PCallArgs::set_salt(const std::string& value) {
+  _internal_set_salt(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.salt)
+}
+inline std::string* PCallArgs::mutable_salt() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.salt)
+  return _internal_mutable_salt();
+}
+inline const std::string& PCallArgs::_internal_salt() const {
+  return salt_.GetNoArena();
+}
+inline void PCallArgs::_internal_set_salt(const std::string& value) {
+  
+  salt_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PCallArgs::set_salt(std::string&& value) {
+  
+  salt_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PCallArgs.salt)
+}
+inline void PCallArgs::set_salt(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  salt_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PCallArgs.salt)
+}
+inline void PCallArgs::set_salt(const void* value, size_t size) {
+  
+  salt_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PCallArgs.salt)
+}
+inline std::string* PCallArgs::_internal_mutable_salt() {
+  
+  return salt_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PCallArgs::release_salt() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PCallArgs.salt)
+  
+  return salt_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PCallArgs::set_allocated_salt(std::string* salt) {
+  if (salt != nullptr) {
+    
+  } else {
+    
+  }
+  salt_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), salt);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PCallArgs.salt)
+}
+
+// -------------------------------------------------------------------
+
+// PEstimateGasResult
+
+// .top.tvm_engine.parameters.PStatusCode status = 1;
+inline void PEstimateGasResult::clear_status() {
+  status_ = 0;
+}
+inline ::top::tvm_engine::parameters::PStatusCode PEstimateGasResult::_internal_status() const {
+  return static_cast< ::top::tvm_engine::parameters::PStatusCode >(status_);
+}
+inline ::top::tvm_engine::parameters::PStatusCode PEstimateGasResult::status() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PEstimateGasResult.status)
+  return _internal_status();
+}
+inline void PEstimateGasResult::_internal_set_status(::top::tvm_engine::parameters::PStatusCode value) {
+  
+  status_ = value;
+}
+inline void PEstimateGasResult::set_status(::top::tvm_engine::parameters::PStatusCode value) {
+  _internal_set_status(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PEstimateGasResult.status)
+}
+
+// bytes status_data = 2;
+inline void PEstimateGasResult::clear_status_data() {
+  status_data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PEstimateGasResult::status_data() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PEstimateGasResult.status_data)
+  return _internal_status_data();
+}
+inline void PEstimateGasResult::set_status_data(const std::string& value) {
+  _internal_set_status_data(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PEstimateGasResult.status_data)
+}
+inline std::string* PEstimateGasResult::mutable_status_data() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PEstimateGasResult.status_data)
+  return _internal_mutable_status_data();
+}
+inline const std::string& PEstimateGasResult::_internal_status_data() const {
+  return status_data_.GetNoArena();
+}
+inline void PEstimateGasResult::_internal_set_status_data(const std::string& value) {
+  
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PEstimateGasResult::set_status_data(std::string&& value) {
+  
+  status_data_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PEstimateGasResult.status_data)
+}
+inline void PEstimateGasResult::set_status_data(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PEstimateGasResult.status_data)
+}
+inline void PEstimateGasResult::set_status_data(const void* value, size_t size) {
+  
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PEstimateGasResult.status_data)
+}
+inline std::string* PEstimateGasResult::_internal_mutable_status_data() {
+  
+  return status_data_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PEstimateGasResult::release_status_data() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PEstimateGasResult.status_data)
+  
+  return status_data_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PEstimateGasResult::set_allocated_status_data(std::string* status_data) {
+  if (status_data != nullptr) {
+    
+  } else {
+    
+  }
+  status_data_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), status_data);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PEstimateGasResult.status_data)
+}
+
+// uint64 gas_used = 3;
+inline void PEstimateGasResult::clear_gas_used() {
+  gas_used_ = PROTOBUF_ULONGLONG(0);
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PEstimateGasResult::_internal_gas_used() const {
+  return gas_used_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PEstimateGasResult::gas_used() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PEstimateGasResult.gas_used)
+  return _internal_gas_used();
+}
+inline void PEstimateGasResult::_internal_set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  gas_used_ = value;
+}
+inline void PEstimateGasResult::set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_gas_used(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PEstimateGasResult.gas_used)
+}
+
+// uint64 gas_limit = 4;
+inline void PEstimateGasResult::clear_gas_limit() {
+  gas_limit_ = PROTOBUF_ULONGLONG(0);
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PEstimateGasResult::_internal_gas_limit() const {
+  return gas_limit_;
+}
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PEstimateGasResult::gas_limit() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PEstimateGasResult.gas_limit)
+  return _internal_gas_limit();
+}
+inline void PEstimateGasResult::_internal_set_gas_limit(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  
+  gas_limit_ = value;
 }
-inline const ::google::protobuf::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PU256 >&
-PReturnResult_PLog::topics() const {
-  // @@protoc_insertion_point(field_list:top.tvm_engine.parameters.PReturnResult.PLog.topics)
-  return topics_;
+inline void PEstimateGasResult::set_gas_limit(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_gas_limit(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PEstimateGasResult.gas_limit)
 }
 
-// bytes data = 3;
-inline void PReturnResult_PLog::clear_data() {
-  data_.ClearToEmptyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+// string error_message = 5;
+inline void PEstimateGasResult::clear_error_message() {
+  error_message_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline const ::std::string& PReturnResult_PLog::data() const {
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.PLog.data)
-  return data_.GetNoArena();
+inline const std::string& PEstimateGasResult::error_message() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PEstimateGasResult.error_message)
+  return _internal_error_message();
+}
+inline void PEstimateGasResult::set_error_message(const std::string& value) {
+  _internal_set_error_message(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PEstimateGasResult.error_message)
 }
-inline void PReturnResult_PLog::set_data(const ::std::string& value) {
+inline std::string* PEstimateGasResult::mutable_error_message() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PEstimateGasResult.error_message)
+  return _internal_mutable_error_message();
+}
+inline const std::string& PEstimateGasResult::_internal_error_message() const {
+  return error_message_.GetNoArena();
+}
+inline void PEstimateGasResult::_internal_set_error_message(const std::string& value) {
   
-  data_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), value);
-  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.PLog.data)
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
 }
-#if LANG_CXX11
-inline void PReturnResult_PLog::set_data(::std::string&& value) {
+inline void PEstimateGasResult::set_error_message(std::string&& value) {
   
-  data_.SetNoArena(
-    &::google::protobuf::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
-  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PReturnResult.PLog.data)
+  error_message_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PEstimateGasResult.error_message)
 }
-#endif
-inline void PReturnResult_PLog::set_data(const char* value) {
-  GOOGLE_DCHECK(value != NULL);
+inline void PEstimateGasResult::set_error_message(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
   
-  data_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
-  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PReturnResult.PLog.data)
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PEstimateGasResult.error_message)
 }
-inline void PReturnResult_PLog::set_data(const void* value, size_t size) {
+inline void PEstimateGasResult::set_error_message(const char* value, size_t size) {
   
-  data_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(),
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
       ::std::string(reinterpret_cast<const char*>(value), size));
-  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PReturnResult.PLog.data)
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PEstimateGasResult.error_message)
 }
-inline ::std::string* PReturnResult_PLog::mutable_data() {
+inline std::string* PEstimateGasResult::_internal_mutable_error_message() {
   
-  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.PLog.data)
-  return data_.MutableNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  return error_message_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline ::std::string* PReturnResult_PLog::release_data() {
-  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.PLog.data)
+inline std::string* PEstimateGasResult::release_error_message() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PEstimateGasResult.error_message)
   
-  return data_.ReleaseNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  return error_message_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline void PReturnResult_PLog::set_allocated_data(::std::string* data) {
-  if (data != NULL) {
+inline void PEstimateGasResult::set_allocated_error_message(std::string* error_message) {
+  if (error_message != nullptr) {
     
   } else {
     
   }
-  data_.SetAllocatedNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), data);
-  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.PLog.data)
+  error_message_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), error_message);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PEstimateGasResult.error_message)
 }
 
 // -------------------------------------------------------------------
 
-// PReturnResult
+// PAccessListResult
 
-// uint32 status = 1;
-inline void PReturnResult::clear_status() {
-  status_ = 0u;
+// .top.tvm_engine.parameters.PStatusCode status = 1;
+inline void PAccessListResult::clear_status() {
+  status_ = 0;
 }
-inline ::google::protobuf::uint32 PReturnResult::status() const {
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.status)
-  return status_;
+inline ::top::tvm_engine::parameters::PStatusCode PAccessListResult::_internal_status() const {
+  return static_cast< ::top::tvm_engine::parameters::PStatusCode >(status_);
 }
-inline void PReturnResult::set_status(::google::protobuf::uint32 value) {
+inline ::top::tvm_engine::parameters::PStatusCode PAccessListResult::status() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PAccessListResult.status)
+  return _internal_status();
+}
+inline void PAccessListResult::_internal_set_status(::top::tvm_engine::parameters::PStatusCode value) {
   
   status_ = value;
-  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.status)
+}
+inline void PAccessListResult::set_status(::top::tvm_engine::parameters::PStatusCode value) {
+  _internal_set_status(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PAccessListResult.status)
 }
 
 // bytes status_data = 2;
-inline void PReturnResult::clear_status_data() {
-  status_data_.ClearToEmptyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+inline void PAccessListResult::clear_status_data() {
+  status_data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline const ::std::string& PReturnResult::status_data() const {
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.status_data)
+inline const std::string& PAccessListResult::status_data() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PAccessListResult.status_data)
+  return _internal_status_data();
+}
+inline void PAccessListResult::set_status_data(const std::string& value) {
+  _internal_set_status_data(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PAccessListResult.status_data)
+}
+inline std::string* PAccessListResult::mutable_status_data() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PAccessListResult.status_data)
+  return _internal_mutable_status_data();
+}
+inline const std::string& PAccessListResult::_internal_status_data() const {
   return status_data_.GetNoArena();
 }
-inline void PReturnResult::set_status_data(const ::std::string& value) {
+inline void PAccessListResult::_internal_set_status_data(const std::string& value) {
   
-  status_data_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), value);
-  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.status_data)
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
 }
-#if LANG_CXX11
-inline void PReturnResult::set_status_data(::std::string&& value) {
+inline void PAccessListResult::set_status_data(std::string&& value) {
   
   status_data_.SetNoArena(
-    &::google::protobuf::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
-  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PReturnResult.status_data)
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PAccessListResult.status_data)
 }
-#endif
-inline void PReturnResult::set_status_data(const char* value) {
-  GOOGLE_DCHECK(value != NULL);
+inline void PAccessListResult::set_status_data(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
   
-  status_data_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
-  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PReturnResult.status_data)
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PAccessListResult.status_data)
 }
-inline void PReturnResult::set_status_data(const void* value, size_t size) {
+inline void PAccessListResult::set_status_data(const void* value, size_t size) {
   
-  status_data_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(),
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
       ::std::string(reinterpret_cast<const char*>(value), size));
-  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PReturnResult.status_data)
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PAccessListResult.status_data)
 }
-inline ::std::string* PReturnResult::mutable_status_data() {
+inline std::string* PAccessListResult::_internal_mutable_status_data() {
   
-  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.status_data)
-  return status_data_.MutableNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  return status_data_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline ::std::string* PReturnResult::release_status_data() {
-  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PReturnResult.status_data)
+inline std::string* PAccessListResult::release_status_data() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PAccessListResult.status_data)
   
-  return status_data_.ReleaseNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  return status_data_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline void PReturnResult::set_allocated_status_data(::std::string* status_data) {
-  if (status_data != NULL) {
+inline void PAccessListResult::set_allocated_status_data(std::string* status_data) {
+  if (status_data != nullptr) {
     
   } else {
     
   }
-  status_data_.SetAllocatedNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), status_data);
-  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PReturnResult.status_data)
+  status_data_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), status_data);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PAccessListResult.status_data)
 }
 
 // uint64 gas_used = 3;
-inline void PReturnResult::clear_gas_used() {
-  gas_used_ = GOOGLE_ULONGLONG(0);
+inline void PAccessListResult::clear_gas_used() {
+  gas_used_ = PROTOBUF_ULONGLONG(0);
 }
-inline ::google::protobuf::uint64 PReturnResult::gas_used() const {
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.gas_used)
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PAccessListResult::_internal_gas_used() const {
   return gas_used_;
 }
-inline void PReturnResult::set_gas_used(::google::protobuf::uint64 value) {
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PAccessListResult::gas_used() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PAccessListResult.gas_used)
+  return _internal_gas_used();
+}
+inline void PAccessListResult::_internal_set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value) {
   
   gas_used_ = value;
-  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PReturnResult.gas_used)
+}
+inline void PAccessListResult::set_gas_used(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_gas_used(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PAccessListResult.gas_used)
 }
 
-// repeated .top.tvm_engine.parameters.PReturnResult.PLog logs = 4;
-inline int PReturnResult::logs_size() const {
-  return logs_.size();
+// repeated .top.tvm_engine.parameters.PCallArgs.PAccessListItem access_list = 4;
+inline int PAccessListResult::_internal_access_list_size() const {
+  return access_list_.size();
 }
-inline void PReturnResult::clear_logs() {
-  logs_.Clear();
+inline int PAccessListResult::access_list_size() const {
+  return _internal_access_list_size();
 }
-inline ::top::tvm_engine::parameters::PReturnResult_PLog* PReturnResult::mutable_logs(int index) {
-  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PReturnResult.logs)
-  return logs_.Mutable(index);
+inline void PAccessListResult::clear_access_list() {
+  access_list_.Clear();
 }
-inline ::google::protobuf::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PLog >*
-PReturnResult::mutable_logs() {
-  // @@protoc_insertion_point(field_mutable_list:top.tvm_engine.parameters.PReturnResult.logs)
-  return &logs_;
+inline ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* PAccessListResult::mutable_access_list(int index) {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PAccessListResult.access_list)
+  return access_list_.Mutable(index);
 }
-inline const ::top::tvm_engine::parameters::PReturnResult_PLog& PReturnResult::logs(int index) const {
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PReturnResult.logs)
-  return logs_.Get(index);
+inline ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem >*
+PAccessListResult::mutable_access_list() {
+  // @@protoc_insertion_point(field_mutable_list:top.tvm_engine.parameters.PAccessListResult.access_list)
+  return &access_list_;
 }
-inline ::top::tvm_engine::parameters::PReturnResult_PLog* PReturnResult::add_logs() {
-  // @@protoc_insertion_point(field_add:top.tvm_engine.parameters.PReturnResult.logs)
-  return logs_.Add();
+inline const ::top::tvm_engine::parameters::PCallArgs_PAccessListItem& PAccessListResult::_internal_access_list(int index) const {
+  return access_list_.Get(index);
 }
-inline const ::google::protobuf::RepeatedPtrField< ::top::tvm_engine::parameters::PReturnResult_PLog >&
-PReturnResult::logs() const {
-  // @@protoc_insertion_point(field_list:top.tvm_engine.parameters.PReturnResult.logs)
-  return logs_;
+inline const ::top::tvm_engine::parameters::PCallArgs_PAccessListItem& PAccessListResult::access_list(int index) const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PAccessListResult.access_list)
+  return _internal_access_list(index);
+}
+inline ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* PAccessListResult::_internal_add_access_list() {
+  return access_list_.Add();
+}
+inline ::top::tvm_engine::parameters::PCallArgs_PAccessListItem* PAccessListResult::add_access_list() {
+  // @@protoc_insertion_point(field_add:top.tvm_engine.parameters.PAccessListResult.access_list)
+  return _internal_add_access_list();
+}
+inline const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PCallArgs_PAccessListItem >&
+PAccessListResult::access_list() const {
+  // @@protoc_insertion_point(field_list:top.tvm_engine.parameters.PAccessListResult.access_list)
+  return access_list_;
+}
+
+// string error_message = 5;
+inline void PAccessListResult::clear_error_message() {
+  error_message_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PAccessListResult::error_message() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PAccessListResult.error_message)
+  return _internal_error_message();
+}
+inline void PAccessListResult::set_error_message(const std::string& value) {
+  _internal_set_error_message(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PAccessListResult.error_message)
+}
+inline std::string* PAccessListResult::mutable_error_message() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PAccessListResult.error_message)
+  return _internal_mutable_error_message();
+}
+inline const std::string& PAccessListResult::_internal_error_message() const {
+  return error_message_.GetNoArena();
+}
+inline void PAccessListResult::_internal_set_error_message(const std::string& value) {
+  
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PAccessListResult::set_error_message(std::string&& value) {
+  
+  error_message_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PAccessListResult.error_message)
+}
+inline void PAccessListResult::set_error_message(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PAccessListResult.error_message)
+}
+inline void PAccessListResult::set_error_message(const char* value, size_t size) {
+  
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PAccessListResult.error_message)
+}
+inline std::string* PAccessListResult::_internal_mutable_error_message() {
+  
+  return error_message_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PAccessListResult::release_error_message() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PAccessListResult.error_message)
+  
+  return error_message_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PAccessListResult::set_allocated_error_message(std::string* error_message) {
+  if (error_message != nullptr) {
+    
+  } else {
+    
+  }
+  error_message_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), error_message);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PAccessListResult.error_message)
 }
 
 // -------------------------------------------------------------------
 
-// PCallArgs
+// PDeployAddressResult
 
-// .top.tvm_engine.basic.PAddress sender_address = 1;
-inline bool PCallArgs::has_sender_address() const {
-  return this != internal_default_instance() && sender_address_ != NULL;
+// .top.tvm_engine.parameters.PStatusCode status = 1;
+inline void PDeployAddressResult::clear_status() {
+  status_ = 0;
 }
-inline const ::top::tvm_engine::basic::PAddress& PCallArgs::_internal_sender_address() const {
-  return *sender_address_;
+inline ::top::tvm_engine::parameters::PStatusCode PDeployAddressResult::_internal_status() const {
+  return static_cast< ::top::tvm_engine::parameters::PStatusCode >(status_);
 }
-inline const ::top::tvm_engine::basic::PAddress& PCallArgs::sender_address() const {
-  const ::top::tvm_engine::basic::PAddress* p = sender_address_;
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.sender_address)
-  return p != NULL ? *p : *reinterpret_cast<const ::top::tvm_engine::basic::PAddress*>(
-      &::top::tvm_engine::basic::_PAddress_default_instance_);
+inline ::top::tvm_engine::parameters::PStatusCode PDeployAddressResult::status() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PDeployAddressResult.status)
+  return _internal_status();
 }
-inline ::top::tvm_engine::basic::PAddress* PCallArgs::release_sender_address() {
-  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PCallArgs.sender_address)
+inline void PDeployAddressResult::_internal_set_status(::top::tvm_engine::parameters::PStatusCode value) {
   
-  ::top::tvm_engine::basic::PAddress* temp = sender_address_;
-  sender_address_ = NULL;
-  return temp;
+  status_ = value;
 }
-inline ::top::tvm_engine::basic::PAddress* PCallArgs::mutable_sender_address() {
+inline void PDeployAddressResult::set_status(::top::tvm_engine::parameters::PStatusCode value) {
+  _internal_set_status(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PDeployAddressResult.status)
+}
+
+// bytes status_data = 2;
+inline void PDeployAddressResult::clear_status_data() {
+  status_data_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline const std::string& PDeployAddressResult::status_data() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PDeployAddressResult.status_data)
+  return _internal_status_data();
+}
+inline void PDeployAddressResult::set_status_data(const std::string& value) {
+  _internal_set_status_data(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PDeployAddressResult.status_data)
+}
+inline std::string* PDeployAddressResult::mutable_status_data() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PDeployAddressResult.status_data)
+  return _internal_mutable_status_data();
+}
+inline const std::string& PDeployAddressResult::_internal_status_data() const {
+  return status_data_.GetNoArena();
+}
+inline void PDeployAddressResult::_internal_set_status_data(const std::string& value) {
   
-  if (sender_address_ == NULL) {
-    auto* p = CreateMaybeMessage<::top::tvm_engine::basic::PAddress>(GetArenaNoVirtual());
-    sender_address_ = p;
-  }
-  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.sender_address)
-  return sender_address_;
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
 }
-inline void PCallArgs::set_allocated_sender_address(::top::tvm_engine::basic::PAddress* sender_address) {
-  ::google::protobuf::Arena* message_arena = GetArenaNoVirtual();
-  if (message_arena == NULL) {
-    delete reinterpret_cast< ::google::protobuf::MessageLite*>(sender_address_);
-  }
-  if (sender_address) {
-    ::google::protobuf::Arena* submessage_arena = NULL;
-    if (message_arena != submessage_arena) {
-      sender_address = ::google::protobuf::internal::GetOwnedMessage(
-          message_arena, sender_address, submessage_arena);
-    }
+inline void PDeployAddressResult::set_status_data(std::string&& value) {
+  
+  status_data_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PDeployAddressResult.status_data)
+}
+inline void PDeployAddressResult::set_status_data(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PDeployAddressResult.status_data)
+}
+inline void PDeployAddressResult::set_status_data(const void* value, size_t size) {
+  
+  status_data_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PDeployAddressResult.status_data)
+}
+inline std::string* PDeployAddressResult::_internal_mutable_status_data() {
+  
+  return status_data_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PDeployAddressResult::release_status_data() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PDeployAddressResult.status_data)
+  
+  return status_data_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PDeployAddressResult::set_allocated_status_data(std::string* status_data) {
+  if (status_data != nullptr) {
     
   } else {
     
   }
-  sender_address_ = sender_address;
-  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PCallArgs.sender_address)
+  status_data_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), status_data);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PDeployAddressResult.status_data)
 }
 
-// .top.tvm_engine.basic.PAddress recver_address = 2;
-inline bool PCallArgs::has_recver_address() const {
-  return this != internal_default_instance() && recver_address_ != NULL;
+// .top.tvm_engine.basic.PAddress contract_address = 3;
+inline bool PDeployAddressResult::_internal_has_contract_address() const {
+  return this != internal_default_instance() && contract_address_ != nullptr;
 }
-inline const ::top::tvm_engine::basic::PAddress& PCallArgs::_internal_recver_address() const {
-  return *recver_address_;
+inline bool PDeployAddressResult::has_contract_address() const {
+  return _internal_has_contract_address();
 }
-inline const ::top::tvm_engine::basic::PAddress& PCallArgs::recver_address() const {
-  const ::top::tvm_engine::basic::PAddress* p = recver_address_;
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.recver_address)
-  return p != NULL ? *p : *reinterpret_cast<const ::top::tvm_engine::basic::PAddress*>(
+inline const ::top::tvm_engine::basic::PAddress& PDeployAddressResult::_internal_contract_address() const {
+  const ::top::tvm_engine::basic::PAddress* p = contract_address_;
+  return p != nullptr ? *p : *reinterpret_cast<const ::top::tvm_engine::basic::PAddress*>(
       &::top::tvm_engine::basic::_PAddress_default_instance_);
 }
-inline ::top::tvm_engine::basic::PAddress* PCallArgs::release_recver_address() {
-  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PCallArgs.recver_address)
+inline const ::top::tvm_engine::basic::PAddress& PDeployAddressResult::contract_address() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PDeployAddressResult.contract_address)
+  return _internal_contract_address();
+}
+inline ::top::tvm_engine::basic::PAddress* PDeployAddressResult::release_contract_address() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PDeployAddressResult.contract_address)
   
-  ::top::tvm_engine::basic::PAddress* temp = recver_address_;
-  recver_address_ = NULL;
+  ::top::tvm_engine::basic::PAddress* temp = contract_address_;
+  contract_address_ = nullptr;
   return temp;
 }
-inline ::top::tvm_engine::basic::PAddress* PCallArgs::mutable_recver_address() {
+inline ::top::tvm_engine::basic::PAddress* PDeployAddressResult::_internal_mutable_contract_address() {
   
-  if (recver_address_ == NULL) {
+  if (contract_address_ == nullptr) {
     auto* p = CreateMaybeMessage<::top::tvm_engine::basic::PAddress>(GetArenaNoVirtual());
-    recver_address_ = p;
+    contract_address_ = p;
   }
-  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.recver_address)
-  return recver_address_;
+  return contract_address_;
 }
-inline void PCallArgs::set_allocated_recver_address(::top::tvm_engine::basic::PAddress* recver_address) {
-  ::google::protobuf::Arena* message_arena = GetArenaNoVirtual();
-  if (message_arena == NULL) {
-    delete reinterpret_cast< ::google::protobuf::MessageLite*>(recver_address_);
+inline ::top::tvm_engine::basic::PAddress* PDeployAddressResult::mutable_contract_address() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PDeployAddressResult.contract_address)
+  return _internal_mutable_contract_address();
+}
+inline void PDeployAddressResult::set_allocated_contract_address(::top::tvm_engine::basic::PAddress* contract_address) {
+  ::PROTOBUF_NAMESPACE_ID::Arena* message_arena = GetArenaNoVirtual();
+  if (message_arena == nullptr) {
+    delete reinterpret_cast< ::PROTOBUF_NAMESPACE_ID::MessageLite*>(contract_address_);
   }
-  if (recver_address) {
-    ::google::protobuf::Arena* submessage_arena = NULL;
+  if (contract_address) {
+    ::PROTOBUF_NAMESPACE_ID::Arena* submessage_arena = nullptr;
     if (message_arena != submessage_arena) {
-      recver_address = ::google::protobuf::internal::GetOwnedMessage(
-          message_arena, recver_address, submessage_arena);
+      contract_address = ::PROTOBUF_NAMESPACE_ID::internal::GetOwnedMessage(
+          message_arena, contract_address, submessage_arena);
     }
     
   } else {
     
   }
-  recver_address_ = recver_address;
-  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PCallArgs.recver_address)
+  contract_address_ = contract_address;
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PDeployAddressResult.contract_address)
 }
 
-// uint64 value = 3;
-inline void PCallArgs::clear_value() {
-  value_ = GOOGLE_ULONGLONG(0);
+// bytes salt = 4;
+inline void PDeployAddressResult::clear_salt() {
+  salt_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline ::google::protobuf::uint64 PCallArgs::value() const {
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.value)
-  return value_;
+inline const std::string& PDeployAddressResult::salt() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PDeployAddressResult.salt)
+  return _internal_salt();
+}
+inline void PDeployAddressResult::set_salt(const std::string& value) {
+  _internal_set_salt(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PDeployAddressResult.salt)
+}
+inline std::string* PDeployAddressResult::mutable_salt() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PDeployAddressResult.salt)
+  return _internal_mutable_salt();
 }
-inline void PCallArgs::set_value(::google::protobuf::uint64 value) {
+inline const std::string& PDeployAddressResult::_internal_salt() const {
+  return salt_.GetNoArena();
+}
+inline void PDeployAddressResult::_internal_set_salt(const std::string& value) {
   
-  value_ = value;
-  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.value)
+  salt_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
+}
+inline void PDeployAddressResult::set_salt(std::string&& value) {
+  
+  salt_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PDeployAddressResult.salt)
+}
+inline void PDeployAddressResult::set_salt(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
+  
+  salt_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PDeployAddressResult.salt)
+}
+inline void PDeployAddressResult::set_salt(const void* value, size_t size) {
+  
+  salt_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
+      ::std::string(reinterpret_cast<const char*>(value), size));
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PDeployAddressResult.salt)
+}
+inline std::string* PDeployAddressResult::_internal_mutable_salt() {
+  
+  return salt_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline std::string* PDeployAddressResult::release_salt() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PDeployAddressResult.salt)
+  
+  return salt_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
+}
+inline void PDeployAddressResult::set_allocated_salt(std::string* salt) {
+  if (salt != nullptr) {
+    
+  } else {
+    
+  }
+  salt_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), salt);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PDeployAddressResult.salt)
 }
 
-// bytes input = 4;
-inline void PCallArgs::clear_input() {
-  input_.ClearToEmptyNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+// string error_message = 5;
+inline void PDeployAddressResult::clear_error_message() {
+  error_message_.ClearToEmptyNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline const ::std::string& PCallArgs::input() const {
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.input)
-  return input_.GetNoArena();
+inline const std::string& PDeployAddressResult::error_message() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PDeployAddressResult.error_message)
+  return _internal_error_message();
+}
+inline void PDeployAddressResult::set_error_message(const std::string& value) {
+  _internal_set_error_message(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PDeployAddressResult.error_message)
 }
-inline void PCallArgs::set_input(const ::std::string& value) {
+inline std::string* PDeployAddressResult::mutable_error_message() {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PDeployAddressResult.error_message)
+  return _internal_mutable_error_message();
+}
+inline const std::string& PDeployAddressResult::_internal_error_message() const {
+  return error_message_.GetNoArena();
+}
+inline void PDeployAddressResult::_internal_set_error_message(const std::string& value) {
   
-  input_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), value);
-  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.input)
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), value);
 }
-#if LANG_CXX11
-inline void PCallArgs::set_input(::std::string&& value) {
+inline void PDeployAddressResult::set_error_message(std::string&& value) {
   
-  input_.SetNoArena(
-    &::google::protobuf::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
-  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PCallArgs.input)
+  error_message_.SetNoArena(
+    &::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::move(value));
+  // @@protoc_insertion_point(field_set_rvalue:top.tvm_engine.parameters.PDeployAddressResult.error_message)
 }
-#endif
-inline void PCallArgs::set_input(const char* value) {
-  GOOGLE_DCHECK(value != NULL);
+inline void PDeployAddressResult::set_error_message(const char* value) {
+  GOOGLE_DCHECK(value != nullptr);
   
-  input_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
-  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PCallArgs.input)
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), ::std::string(value));
+  // @@protoc_insertion_point(field_set_char:top.tvm_engine.parameters.PDeployAddressResult.error_message)
 }
-inline void PCallArgs::set_input(const void* value, size_t size) {
+inline void PDeployAddressResult::set_error_message(const char* value, size_t size) {
   
-  input_.SetNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(),
+  error_message_.SetNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(),
       ::std::string(reinterpret_cast<const char*>(value), size));
-  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PCallArgs.input)
+  // @@protoc_insertion_point(field_set_pointer:top.tvm_engine.parameters.PDeployAddressResult.error_message)
 }
-inline ::std::string* PCallArgs::mutable_input() {
+inline std::string* PDeployAddressResult::_internal_mutable_error_message() {
   
-  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PCallArgs.input)
-  return input_.MutableNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  return error_message_.MutableNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline ::std::string* PCallArgs::release_input() {
-  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PCallArgs.input)
+inline std::string* PDeployAddressResult::release_error_message() {
+  // @@protoc_insertion_point(field_release:top.tvm_engine.parameters.PDeployAddressResult.error_message)
   
-  return input_.ReleaseNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited());
+  return error_message_.ReleaseNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited());
 }
-inline void PCallArgs::set_allocated_input(::std::string* input) {
-  if (input != NULL) {
+inline void PDeployAddressResult::set_allocated_error_message(std::string* error_message) {
+  if (error_message != nullptr) {
     
   } else {
     
   }
-  input_.SetAllocatedNoArena(&::google::protobuf::internal::GetEmptyStringAlreadyInited(), input);
-  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PCallArgs.input)
+  error_message_.SetAllocatedNoArena(&::PROTOBUF_NAMESPACE_ID::internal::GetEmptyStringAlreadyInited(), error_message);
+  // @@protoc_insertion_point(field_set_allocated:top.tvm_engine.parameters.PDeployAddressResult.error_message)
 }
 
-// uint64 gas_limit = 5;
-inline void PCallArgs::clear_gas_limit() {
-  gas_limit_ = GOOGLE_ULONGLONG(0);
+// -------------------------------------------------------------------
+
+// PForkSchedule_PActivation
+
+// uint64 height = 1;
+inline void PForkSchedule_PActivation::clear_height() {
+  height_ = PROTOBUF_ULONGLONG(0);
 }
-inline ::google::protobuf::uint64 PCallArgs::gas_limit() const {
-  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PCallArgs.gas_limit)
-  return gas_limit_;
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PForkSchedule_PActivation::_internal_height() const {
+  return height_;
 }
-inline void PCallArgs::set_gas_limit(::google::protobuf::uint64 value) {
+inline ::PROTOBUF_NAMESPACE_ID::uint64 PForkSchedule_PActivation::height() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PForkSchedule.PActivation.height)
+  return _internal_height();
+}
+inline void PForkSchedule_PActivation::_internal_set_height(::PROTOBUF_NAMESPACE_ID::uint64 value) {
   
-  gas_limit_ = value;
-  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PCallArgs.gas_limit)
+  height_ = value;
+}
+inline void PForkSchedule_PActivation::set_height(::PROTOBUF_NAMESPACE_ID::uint64 value) {
+  _internal_set_height(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PForkSchedule.PActivation.height)
+}
+
+// .top.tvm_engine.parameters.PHardfork hardfork = 2;
+inline void PForkSchedule_PActivation::clear_hardfork() {
+  hardfork_ = 0;
+}
+inline ::top::tvm_engine::parameters::PHardfork PForkSchedule_PActivation::_internal_hardfork() const {
+  return static_cast< ::top::tvm_engine::parameters::PHardfork >(hardfork_);
+}
+inline ::top::tvm_engine::parameters::PHardfork PForkSchedule_PActivation::hardfork() const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PForkSchedule.PActivation.hardfork)
+  return _internal_hardfork();
+}
+inline void PForkSchedule_PActivation::_internal_set_hardfork(::top::tvm_engine::parameters::PHardfork value) {
+  
+  hardfork_ = value;
+}
+inline void PForkSchedule_PActivation::set_hardfork(::top::tvm_engine::parameters::PHardfork value) {
+  _internal_set_hardfork(value);
+  // @@protoc_insertion_point(field_set:top.tvm_engine.parameters.PForkSchedule.PActivation.hardfork)
+}
+
+// -------------------------------------------------------------------
+
+// PForkSchedule
+
+// repeated .top.tvm_engine.parameters.PForkSchedule.PActivation activations = 1;
+inline int PForkSchedule::_internal_activations_size() const {
+  return activations_.size();
+}
+inline int PForkSchedule::activations_size() const {
+  return _internal_activations_size();
+}
+inline void PForkSchedule::clear_activations() {
+  activations_.Clear();
+}
+inline ::top::tvm_engine::parameters::PForkSchedule_PActivation* PForkSchedule::mutable_activations(int index) {
+  // @@protoc_insertion_point(field_mutable:top.tvm_engine.parameters.PForkSchedule.activations)
+  return activations_.Mutable(index);
+}
+inline ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PForkSchedule_PActivation >*
+PForkSchedule::mutable_activations() {
+  // @@protoc_insertion_point(field_mutable_list:top.tvm_engine.parameters.PForkSchedule.activations)
+  return &activations_;
+}
+inline const ::top::tvm_engine::parameters::PForkSchedule_PActivation& PForkSchedule::_internal_activations(int index) const {
+  return activations_.Get(index);
+}
+inline const ::top::tvm_engine::parameters::PForkSchedule_PActivation& PForkSchedule::activations(int index) const {
+  // @@protoc_insertion_point(field_get:top.tvm_engine.parameters.PForkSchedule.activations)
+  return _internal_activations(index);
+}
+inline ::top::tvm_engine::parameters::PForkSchedule_PActivation* PForkSchedule::_internal_add_activations() {
+  return activations_.Add();
+}
+inline ::top::tvm_engine::parameters::PForkSchedule_PActivation* PForkSchedule::add_activations() {
+  // @@protoc_insertion_point(field_add:top.tvm_engine.parameters.PForkSchedule.activations)
+  return _internal_add_activations();
+}
+inline const ::PROTOBUF_NAMESPACE_ID::RepeatedPtrField< ::top::tvm_engine::parameters::PForkSchedule_PActivation >&
+PForkSchedule::activations() const {
+  // @@protoc_insertion_point(field_list:top.tvm_engine.parameters.PForkSchedule.activations)
+  return activations_;
 }
 
 #ifdef __GNUC__
@@ -1120,6 +4329,20 @@ inline void PCallArgs::set_gas_limit(::google::protobuf::uint64 value) {
 
 // -------------------------------------------------------------------
 
+// -------------------------------------------------------------------
+
+// -------------------------------------------------------------------
+
+// -------------------------------------------------------------------
+
+// -------------------------------------------------------------------
+
+// -------------------------------------------------------------------
+
+// -------------------------------------------------------------------
+
+// -------------------------------------------------------------------
+
 
 // @@protoc_insertion_point(namespace_scope)
 
@@ -1127,6 +4350,22 @@ inline void PCallArgs::set_gas_limit(::google::protobuf::uint64 value) {
 }  // namespace tvm_engine
 }  // namespace top
 
+PROTOBUF_NAMESPACE_OPEN
+
+template <> struct is_proto_enum< ::top::tvm_engine::parameters::PStatusCode> : ::std::true_type {};
+template <>
+inline const EnumDescriptor* GetEnumDescriptor< ::top::tvm_engine::parameters::PStatusCode>() {
+  return ::top::tvm_engine::parameters::PStatusCode_descriptor();
+}
+template <> struct is_proto_enum< ::top::tvm_engine::parameters::PHardfork> : ::std::true_type {};
+template <>
+inline const EnumDescriptor* GetEnumDescriptor< ::top::tvm_engine::parameters::PHardfork>() {
+  return ::top::tvm_engine::parameters::PHardfork_descriptor();
+}
+
+PROTOBUF_NAMESPACE_CLOSE
+
 // @@protoc_insertion_point(global_scope)
 
-#endif  // PROTOBUF_INCLUDED_protobuf_5ftypes_2fpparameters_2eproto
+#include <google/protobuf/port_undef.inc>
+#endif  // GOOGLE_PROTOBUF_INCLUDED_GOOGLE_PROTOBUF_INCLUDED_protobuf_5ftypes_2fpparameters_2eproto
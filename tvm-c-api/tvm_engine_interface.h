@@ -2,4 +2,17 @@
 
 #include <cstdint>
 
-extern "C" bool call();
\ No newline at end of file
+// input is `PForkSchedule`, once at startup before any other call
+extern "C" bool set_fork_schedule();
+
+extern "C" bool call();
+extern "C" bool view();
+extern "C" bool estimate_gas();
+extern "C" bool create_access_list();
+extern "C" bool predict_deploy_address();
+extern "C" bool trace_state_diff();
+extern "C" bool call_with_diff();
+
+// built with the `tracing` feature only
+extern "C" bool trace_struct_logs();
+extern "C" bool trace_calls();
\ No newline at end of file
@@ -64,9 +64,21 @@ uint64_t tvm_import_instance::tvm_block_height() {
 void tvm_import_instance::tvm_block_coinbase(uint64_t register_id) {
     return current_logic()->block_coinbase(register_id);
 }
+void tvm_import_instance::tvm_block_hash(uint64_t height, uint64_t register_id) {
+    return current_logic()->block_hash(height, register_id);
+}
 uint64_t tvm_import_instance::tvm_block_timestamp() {
     return current_logic()->block_timestamp();
 }
+uint64_t tvm_import_instance::tvm_block_gas_limit() {
+    return current_logic()->block_gas_limit();
+}
+uint64_t tvm_import_instance::tvm_block_base_fee_per_gas() {
+    return current_logic()->block_base_fee_per_gas();
+}
+void tvm_import_instance::tvm_block_randomness(uint64_t register_id) {
+    return current_logic()->block_randomness(register_id);
+}
 uint64_t tvm_import_instance::tvm_chain_id() {
     return current_logic()->chain_id();
 }
@@ -121,9 +133,21 @@ uint64_t tvm_block_height() {
 void tvm_block_coinbase(uint64_t register_id) {
     return tvm_import_instance::instance()->tvm_block_coinbase(register_id);
 }
+void tvm_block_hash(uint64_t height, uint64_t register_id) {
+    return tvm_import_instance::instance()->tvm_block_hash(height, register_id);
+}
 uint64_t tvm_block_timestamp() {
     return tvm_import_instance::instance()->tvm_block_timestamp();
 }
+uint64_t tvm_block_gas_limit() {
+    return tvm_import_instance::instance()->tvm_block_gas_limit();
+}
+uint64_t tvm_block_base_fee_per_gas() {
+    return tvm_import_instance::instance()->tvm_block_base_fee_per_gas();
+}
+void tvm_block_randomness(uint64_t register_id) {
+    return tvm_import_instance::instance()->tvm_block_randomness(register_id);
+}
 uint64_t tvm_chain_id() {
     return tvm_import_instance::instance()->tvm_chain_id();
 }
@@ -35,7 +35,11 @@ public:
     void tvm_origin_address(uint64_t register_id);
     uint64_t tvm_block_height();
     void tvm_block_coinbase(uint64_t register_id);
+    void tvm_block_hash(uint64_t height, uint64_t register_id);
     uint64_t tvm_block_timestamp();
+    uint64_t tvm_block_gas_limit();
+    uint64_t tvm_block_base_fee_per_gas();
+    void tvm_block_randomness(uint64_t register_id);
     uint64_t tvm_chain_id();
     void tvm_log_utf8(uint64_t len, uint64_t ptr);
 
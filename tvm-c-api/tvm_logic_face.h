@@ -20,7 +20,11 @@ public:
     virtual void origin_address(uint64_t register_id) = 0;
     virtual uint64_t block_height() = 0;
     virtual void block_coinbase(uint64_t register_id) = 0;
+    virtual void block_hash(uint64_t height, uint64_t register_id) = 0;
     virtual uint64_t block_timestamp() = 0;
+    virtual uint64_t block_gas_limit() = 0;
+    virtual uint64_t block_base_fee_per_gas() = 0;
+    virtual void block_randomness(uint64_t register_id) = 0;
     virtual uint64_t chain_id() = 0;
     virtual void log_utf8(uint64_t len, uint64_t ptr) = 0;
 };
@@ -11,7 +11,7 @@ fn main() {
         .flag("-std=c++11")
         .flag("-Wno-unused-parameter") // disable warning from google/protobuf
         .include("../tvm-c-api/")
-        .include("../../xdepends/include/") // to include src/xtopcom/xdepends/include get protobuf
+        .include("../../xdepends/include/") // to include src/xtopcom/xdepends/include get protobuf, 3.11 or later
         .file("../tvm-c-api/tvm_import_instance.cpp")
         .file("../tvm-c-api/protobuf_types/pbasic.pb.cc")
         .file("../tvm-c-api/protobuf_types/pparameters.pb.cc")
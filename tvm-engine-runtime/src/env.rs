@@ -1,7 +1,7 @@
-use tvm_engine_types::{Address, U256};
+use tvm_engine_types::{Address, H256, U256};
 
 /// Timestamp represented by the number of nanoseconds since the Unix Epoch.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Timestamp(u64);
 
 impl Timestamp {
@@ -31,7 +31,18 @@ pub trait Env {
 
     fn block_coinbase(&self) -> Address;
 
+    /// hash of block `height`, only asked for the 256 blocks before `block_height`.
+    fn block_hash(&self, height: u64) -> H256;
+
     fn block_timestamp(&self) -> Timestamp;
 
+    fn block_gas_limit(&self) -> u64;
+
+    /// base fee per gas of the current block, in wei.
+    fn block_base_fee_per_gas(&self) -> U256;
+
+    /// randomness of the current block from TOP consensus, returned by `DIFFICULTY` as `PREVRANDAO`.
+    fn block_randomness(&self) -> H256;
+
     fn chain_id(&self) -> u64;
 }
@@ -1,3 +1,5 @@
+use tvm_engine_types::U256;
+
 /// The purpose of this trait is to represent a reference to a value that
 /// could be obtained by IO, but without eagerly loading it into memory.
 pub trait StorageIntermediate: Sized {
@@ -23,7 +25,7 @@ pub trait IO {
     /// all bytes that encode as return result.
     fn set_output(&self, value: &[u8]);
 
-    /// check if a key exist in storage
+    // check if a key exist in storage
     // fn storage_has_key(&self, key: &[u8]) -> bool;
 
     /// write the given value into storage key, return old reference(if any)
@@ -50,6 +52,17 @@ pub trait IO {
         value.copy_to_slice(&mut result);
         Ok(u64::from_be_bytes(result))
     }
+
+    // convenience function to read a U256 (big-endian encoding)
+    fn read_u256(&self, key: &[u8]) -> Result<U256, error::ReadError> {
+        let value = self.read_storage(key).ok_or(error::ReadError::MissingValue)?;
+        if value.len() != 32 {
+            return Err(error::ReadError::InvalidU256);
+        }
+        let mut result = [0u8; 32];
+        value.copy_to_slice(&mut result);
+        Ok(U256::from_big_endian(&result))
+    }
 }
 
 pub mod methods {
@@ -57,13 +70,28 @@ pub mod methods {
     use super::{StorageIntermediate, IO};
     use tvm_engine_types::{address_to_key, storage_to_key, uTop, Address, KeyPrefix, H256, U256};
 
-    // balance
-    pub fn get_balance<I: IO>(io: &I, address: &Address) -> uTop {
-        let raw = io.read_u64(&address_to_key(KeyPrefix::Balance, address)).unwrap_or(0);
-        uTop::new(raw)
+    // balance in wei, stored as 32 bytes.
+    // accounts written before are 8 bytes of uTop, read as is and rewritten in wei by the next `set_balance`.
+    pub fn get_balance<I: IO>(io: &I, address: &Address) -> U256 {
+        let key = address_to_key(KeyPrefix::Balance, address);
+        io.read_u256(&key)
+            .or_else(|_| io.read_u64(&key).map(|raw| uTop::new(raw).into_wei_raw()))
+            .unwrap_or_default()
     }
-    pub fn set_balance<I: IO>(io: &mut I, address: &Address, amount: &uTop) {
-        io.write_storage(&address_to_key(KeyPrefix::Balance, address), &amount.to_be_bytes());
+    pub fn set_balance<I: IO>(io: &mut I, address: &Address, amount: &U256) {
+        let mut bytes = [0u8; 32];
+        amount.to_big_endian(&mut bytes);
+        io.write_storage(&address_to_key(KeyPrefix::Balance, address), &bytes);
+    }
+    /// rewrite an 8 bytes uTop balance in wei, returns whether there was one.
+    pub fn migrate_balance<I: IO>(io: &mut I, address: &Address) -> bool {
+        match io.read_u64(&address_to_key(KeyPrefix::Balance, address)) {
+            Ok(raw) => {
+                set_balance(io, address, &uTop::new(raw).into_wei_raw());
+                true
+            }
+            Err(_) => false,
+        }
     }
     // pub fn add_balance<I: IO>(io: &mut I, address: &Address, amount: &uTop) {
     //     let current_balance = get_balance(io, address);
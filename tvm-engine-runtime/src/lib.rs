@@ -1,9 +1,11 @@
 pub mod dup_cache;
 pub mod env;
 pub mod io;
+pub mod memory;
 pub mod runtime;
 pub mod utils;
 
 pub use dup_cache::{DupCache, PairDupCache};
 pub use io::methods;
+pub use memory::{MemoryEnv, MemoryIO};
 pub use utils::{keccak, sha256};
@@ -0,0 +1,220 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+use tvm_engine_types::{Address, KeyPrefix, H256, U256};
+
+use crate::env::{Env, Timestamp};
+use crate::io::{StorageIntermediate, IO};
+
+/// length of `address_to_key`, the account level keys.
+const ACCOUNT_KEY_LEN: usize = 22;
+
+impl StorageIntermediate for Vec<u8> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        Vec::is_empty(self)
+    }
+
+    fn copy_to_slice(&self, buffer: &mut [u8]) {
+        buffer.copy_from_slice(self)
+    }
+}
+
+#[derive(Default)]
+struct MemoryState {
+    input: Vec<u8>,
+    output: Vec<u8>,
+    storage: HashMap<Vec<u8>, Vec<u8>>,
+}
+
+/// In-memory `IO` backed by a hash map, for running the engine without the C++ host.
+///
+/// Like `Runtime`, this is a handle: clones share the same storage, so the caller can keep
+/// one to inspect state after handing the other to `Engine`.
+#[derive(Clone, Default)]
+pub struct MemoryIO {
+    state: Rc<RefCell<MemoryState>>,
+}
+
+impl MemoryIO {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// bytes returned by the next `get_input`.
+    pub fn set_input(&self, input: &[u8]) {
+        self.state.borrow_mut().input = input.to_vec();
+    }
+
+    /// bytes written by the last `set_output`.
+    pub fn output(&self) -> Vec<u8> {
+        self.state.borrow().output.clone()
+    }
+
+    /// number of keys currently in storage.
+    pub fn storage_len(&self) -> usize {
+        self.state.borrow().storage.len()
+    }
+}
+
+impl IO for MemoryIO {
+    type StorageValue = Vec<u8>;
+
+    fn get_input(&self) -> Self::StorageValue {
+        self.state.borrow().input.clone()
+    }
+
+    fn set_output(&self, value: &[u8]) {
+        self.state.borrow_mut().output = value.to_vec();
+    }
+
+    fn write_storage(&mut self, key: &[u8], value: &[u8]) -> Option<Self::StorageValue> {
+        self.state.borrow_mut().storage.insert(key.to_vec(), value.to_vec())
+    }
+
+    fn read_storage(&self, key: &[u8]) -> Option<Self::StorageValue> {
+        self.state.borrow().storage.get(key).cloned()
+    }
+
+    fn remove_storage(&mut self, key: &[u8]) -> Option<Self::StorageValue> {
+        let mut state = self.state.borrow_mut();
+        // `remove_all_storage` removes the account's storage key, which drops every slot under it.
+        if key.len() == ACCOUNT_KEY_LEN && key[1] == KeyPrefix::Storage as u8 {
+            state.storage.retain(|k, _| !k.starts_with(key));
+        }
+        state.storage.remove(key)
+    }
+}
+
+/// In-memory `Env` with a configurable block context.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryEnv {
+    pub gas_price: U256,
+    pub origin: Address,
+    pub block_height: u64,
+    pub block_coinbase: Address,
+    /// hashes of previous blocks, zero for the missing ones.
+    pub block_hashes: BTreeMap<u64, H256>,
+    pub block_timestamp: Timestamp,
+    pub block_gas_limit: u64,
+    pub block_base_fee_per_gas: U256,
+    pub block_randomness: H256,
+    pub chain_id: u64,
+}
+
+impl Env for MemoryEnv {
+    fn gas_price(&self) -> U256 {
+        self.gas_price
+    }
+
+    fn origin(&self) -> Address {
+        self.origin
+    }
+
+    fn block_height(&self) -> u64 {
+        self.block_height
+    }
+
+    fn block_coinbase(&self) -> Address {
+        self.block_coinbase
+    }
+
+    fn block_hash(&self, height: u64) -> H256 {
+        self.block_hashes.get(&height).copied().unwrap_or_default()
+    }
+
+    fn block_timestamp(&self) -> Timestamp {
+        self.block_timestamp
+    }
+
+    fn block_gas_limit(&self) -> u64 {
+        self.block_gas_limit
+    }
+
+    fn block_base_fee_per_gas(&self) -> U256 {
+        self.block_base_fee_per_gas
+    }
+
+    fn block_randomness(&self) -> H256 {
+        self.block_randomness
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::*;
+    use tvm_engine_types::{address_to_key, uTop, H256};
+
+    #[test]
+    fn test_memory_io_account() {
+        let mut io = MemoryIO::new();
+        let address = Address::build_from_str("7156526fbd7a3c72969b54f64e42c10fbb768c8a").unwrap();
+        assert!(is_account_empty(&io, &address));
+
+        set_balance(&mut io, &address, &uTop::new(100).into_wei_raw());
+        increment_nonce(&mut io, &address);
+        set_code(&mut io, &address, &[0x60, 0x00]);
+        assert_eq!(get_balance(&io, &address), uTop::new(100).into_wei_raw());
+        assert_eq!(get_nonce(&io, &address), U256::one());
+        assert_eq!(get_code_size(&io, &address), 2);
+
+        // clones share the same storage
+        let view = io.clone();
+        remove_account(&mut io, &address);
+        assert!(is_account_empty(&view, &address));
+        assert_eq!(view.storage_len(), 0);
+    }
+
+    #[test]
+    fn test_memory_io_balance_migration() {
+        let mut io = MemoryIO::new();
+        let address = Address::build_from_str("7156526fbd7a3c72969b54f64e42c10fbb768c8a").unwrap();
+        let key = address_to_key(KeyPrefix::Balance, &address);
+        io.write_storage(&key, &100u64.to_be_bytes());
+        assert_eq!(get_balance(&io, &address), uTop::new(100).into_wei_raw());
+
+        assert!(migrate_balance(&mut io, &address));
+        assert_eq!(io.read_storage(&key).unwrap().len(), 32);
+        assert_eq!(get_balance(&io, &address), uTop::new(100).into_wei_raw());
+        assert!(!migrate_balance(&mut io, &address));
+
+        // wei below 1 uTop are kept
+        let balance = uTop::new(100).into_wei_raw() + 1;
+        set_balance(&mut io, &address, &balance);
+        assert_eq!(get_balance(&io, &address), balance);
+    }
+
+    #[test]
+    fn test_memory_io_remove_all_storage() {
+        let mut io = MemoryIO::new();
+        let address = Address::build_from_str("7156526fbd7a3c72969b54f64e42c10fbb768c8a").unwrap();
+        let other = Address::build_from_str("56d9407e0ae1246a2aafcfa57f3fc1bd7023df81").unwrap();
+        let value = H256::from_low_u64_be(1);
+        set_storage(&mut io, &address, &H256::from_low_u64_be(1), &value);
+        set_storage(&mut io, &address, &H256::from_low_u64_be(2), &value);
+        set_storage(&mut io, &other, &H256::from_low_u64_be(1), &value);
+
+        remove_all_storage(&mut io, &address);
+        assert_eq!(get_storage(&io, &address, &H256::from_low_u64_be(1)), H256::zero());
+        assert_eq!(get_storage(&io, &address, &H256::from_low_u64_be(2)), H256::zero());
+        assert_eq!(get_storage(&io, &other, &H256::from_low_u64_be(1)), value);
+    }
+
+    #[test]
+    fn test_memory_io_input_output() {
+        let io = MemoryIO::new();
+        io.set_input(b"input");
+        assert_eq!(io.get_input().to_vec(), b"input".to_vec());
+        io.set_output(b"output");
+        assert_eq!(io.output(), b"output".to_vec());
+    }
+}
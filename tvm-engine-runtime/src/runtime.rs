@@ -1,4 +1,4 @@
-use tvm_engine_types::{Address, U256};
+use tvm_engine_types::{Address, H256, U256};
 
 use crate::env::Env;
 use crate::io::{StorageIntermediate, IO};
@@ -120,10 +120,42 @@ impl Env for Runtime {
         Address::build_from_slice(&bytes).unwrap()
     }
 
+    fn block_hash(&self, height: u64) -> H256 {
+        unsafe {
+            exports::tvm_block_hash(height, Self::ENV_REGISTER.0);
+        }
+        let bytes = Self::ENV_REGISTER.to_vec();
+        if bytes.len() == 32 {
+            H256::from_slice(&bytes)
+        } else {
+            H256::zero()
+        }
+    }
+
     fn block_timestamp(&self) -> crate::env::Timestamp {
         crate::env::Timestamp::new(unsafe { exports::tvm_block_timestamp() })
     }
 
+    fn block_gas_limit(&self) -> u64 {
+        unsafe { exports::tvm_block_gas_limit() }
+    }
+
+    fn block_base_fee_per_gas(&self) -> U256 {
+        U256::from(unsafe { exports::tvm_block_base_fee_per_gas() })
+    }
+
+    fn block_randomness(&self) -> H256 {
+        unsafe {
+            exports::tvm_block_randomness(Self::ENV_REGISTER.0);
+        }
+        let bytes = Self::ENV_REGISTER.to_vec();
+        if bytes.len() == 32 {
+            H256::from_slice(&bytes)
+        } else {
+            H256::zero()
+        }
+    }
+
     fn chain_id(&self) -> u64 {
         unsafe { exports::tvm_chain_id() }
     }
@@ -149,7 +181,11 @@ mod exports {
         pub fn tvm_origin_address(register_id: u64);
         pub fn tvm_block_height() -> u64;
         pub fn tvm_block_coinbase(register_id: u64);
+        pub fn tvm_block_hash(height: u64, register_id: u64);
         pub fn tvm_block_timestamp() -> u64;
+        pub fn tvm_block_gas_limit() -> u64;
+        pub fn tvm_block_base_fee_per_gas() -> u64;
+        pub fn tvm_block_randomness(register_id: u64);
         pub fn tvm_chain_id() -> u64;
 
         // logs
@@ -10,8 +10,11 @@ pub fn keccak(input: &[u8]) -> H256 {
     H256::from_slice(sha3::Keccak256::digest(input).as_slice())
 }
 
+/// Without `build_as_xtop_lib` there is no host to log to, so the engine can
+/// be linked and tested on its own.
 pub fn panic_utf8(bytes: &[u8]) -> ! {
     println!("panic: {:?}", bytes);
+    #[cfg(feature = "build_as_xtop_lib")]
     unsafe {
         crate::runtime::tvm_log_utf8(bytes.len() as u64, bytes.as_ptr() as u64);
     }
@@ -20,9 +23,12 @@ pub fn panic_utf8(bytes: &[u8]) -> ! {
 
 pub fn log_utf8(bytes: &[u8]) {
     // println!("log: {:?}", bytes);
+    #[cfg(feature = "build_as_xtop_lib")]
     unsafe {
         crate::runtime::tvm_log_utf8(bytes.len() as u64, bytes.as_ptr() as u64);
     }
+    #[cfg(not(feature = "build_as_xtop_lib"))]
+    let _ = bytes;
 }
 
 #[macro_export]
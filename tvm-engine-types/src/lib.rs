@@ -1,3 +1,5 @@
+// generated code still carries `#![allow(box_pointers)]`
+#[allow(renamed_and_removed_lints)]
 mod proto;
 mod storage;
 mod types;
@@ -6,9 +8,12 @@ pub use primitive_types::{H160, H256, U256};
 pub use proto::{
     pbasic::PAddress,
     pparameters::{
+        pcall_args::PAccessListItem,
+        pfork_schedule::PActivation,
         preturn_result::{PLog, PU256},
-        PCallArgs, PReturnResult,
+        PAccessListResult, PCallArgs, PDeployAddressResult, PEstimateGasResult, PForkSchedule, PHardfork,
+        PReturnResult, PStateDiffResult, PStatusCode,
     },
 };
 pub use storage::{address_to_key, storage_to_key, KeyPrefix};
-pub use types::{uTop, Address, Gas};
+pub use types::{uTop, AccountKind, Address, AddressError, Gas, ParseUTopError, ShardId};
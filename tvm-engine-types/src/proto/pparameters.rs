@@ -30,13 +30,26 @@ const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
 pub struct PReturnResult {
     // message fields
     // @@protoc_insertion_point(field:top.tvm_engine.parameters.PReturnResult.status)
-    pub status: u32,
+    pub status: ::protobuf::EnumOrUnknown<PStatusCode>,
     // @@protoc_insertion_point(field:top.tvm_engine.parameters.PReturnResult.status_data)
     pub status_data: ::std::vec::Vec<u8>,
     // @@protoc_insertion_point(field:top.tvm_engine.parameters.PReturnResult.gas_used)
     pub gas_used: u64,
     // @@protoc_insertion_point(field:top.tvm_engine.parameters.PReturnResult.logs)
     pub logs: ::std::vec::Vec<preturn_result::PLog>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PReturnResult.effective_gas_price)
+    pub effective_gas_price: u64,
+    ///  set by successful deployments only, the deployed runtime code is described by code_hash and code_size
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PReturnResult.contract_address)
+    pub contract_address: ::protobuf::MessageField<super::pbasic::PAddress>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PReturnResult.code_hash)
+    pub code_hash: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PReturnResult.code_size)
+    pub code_size: u64,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PReturnResult.salt)
+    pub salt: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PReturnResult.error_message)
+    pub error_message: ::std::string::String,
     // special fields
     // @@protoc_insertion_point(special_field:top.tvm_engine.parameters.PReturnResult.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -54,7 +67,7 @@ impl PReturnResult {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(10);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "status",
@@ -76,6 +89,36 @@ impl PReturnResult {
             |m: &PReturnResult| { &m.logs },
             |m: &mut PReturnResult| { &mut m.logs },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "effective_gas_price",
+            |m: &PReturnResult| { &m.effective_gas_price },
+            |m: &mut PReturnResult| { &mut m.effective_gas_price },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::pbasic::PAddress>(
+            "contract_address",
+            |m: &PReturnResult| { &m.contract_address },
+            |m: &mut PReturnResult| { &mut m.contract_address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code_hash",
+            |m: &PReturnResult| { &m.code_hash },
+            |m: &mut PReturnResult| { &mut m.code_hash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code_size",
+            |m: &PReturnResult| { &m.code_size },
+            |m: &mut PReturnResult| { &mut m.code_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "salt",
+            |m: &PReturnResult| { &m.salt },
+            |m: &mut PReturnResult| { &mut m.salt },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error_message",
+            |m: &PReturnResult| { &m.error_message },
+            |m: &mut PReturnResult| { &mut m.error_message },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PReturnResult>(
             "PReturnResult",
             fields,
@@ -95,7 +138,7 @@ impl ::protobuf::Message for PReturnResult {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.status = is.read_uint32()?;
+                    self.status = is.read_enum_or_unknown()?;
                 },
                 18 => {
                     self.status_data = is.read_bytes()?;
@@ -106,6 +149,24 @@ impl ::protobuf::Message for PReturnResult {
                 34 => {
                     self.logs.push(is.read_message()?);
                 },
+                40 => {
+                    self.effective_gas_price = is.read_uint64()?;
+                },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.contract_address)?;
+                },
+                58 => {
+                    self.code_hash = is.read_bytes()?;
+                },
+                64 => {
+                    self.code_size = is.read_uint64()?;
+                },
+                74 => {
+                    self.salt = is.read_bytes()?;
+                },
+                82 => {
+                    self.error_message = is.read_string()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -118,8 +179,8 @@ impl ::protobuf::Message for PReturnResult {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.status != 0 {
-            my_size += ::protobuf::rt::uint32_size(1, self.status);
+        if self.status != ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED) {
+            my_size += ::protobuf::rt::int32_size(1, self.status.value());
         }
         if !self.status_data.is_empty() {
             my_size += ::protobuf::rt::bytes_size(2, &self.status_data);
@@ -131,14 +192,33 @@ impl ::protobuf::Message for PReturnResult {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         };
+        if self.effective_gas_price != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.effective_gas_price);
+        }
+        if let Some(v) = self.contract_address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.code_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.code_hash);
+        }
+        if self.code_size != 0 {
+            my_size += ::protobuf::rt::uint64_size(8, self.code_size);
+        }
+        if !self.salt.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(9, &self.salt);
+        }
+        if !self.error_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.error_message);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.status != 0 {
-            os.write_uint32(1, self.status)?;
+        if self.status != ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.status))?;
         }
         if !self.status_data.is_empty() {
             os.write_bytes(2, &self.status_data)?;
@@ -149,6 +229,24 @@ impl ::protobuf::Message for PReturnResult {
         for v in &self.logs {
             ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
         };
+        if self.effective_gas_price != 0 {
+            os.write_uint64(5, self.effective_gas_price)?;
+        }
+        if let Some(v) = self.contract_address.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        }
+        if !self.code_hash.is_empty() {
+            os.write_bytes(7, &self.code_hash)?;
+        }
+        if self.code_size != 0 {
+            os.write_uint64(8, self.code_size)?;
+        }
+        if !self.salt.is_empty() {
+            os.write_bytes(9, &self.salt)?;
+        }
+        if !self.error_message.is_empty() {
+            os.write_string(10, &self.error_message)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -166,19 +264,31 @@ impl ::protobuf::Message for PReturnResult {
     }
 
     fn clear(&mut self) {
-        self.status = 0;
+        self.status = ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED);
         self.status_data.clear();
         self.gas_used = 0;
         self.logs.clear();
+        self.effective_gas_price = 0;
+        self.contract_address.clear();
+        self.code_hash.clear();
+        self.code_size = 0;
+        self.salt.clear();
+        self.error_message.clear();
         self.special_fields.clear();
     }
 
     fn default_instance() -> &'static PReturnResult {
         static instance: PReturnResult = PReturnResult {
-            status: 0,
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
             status_data: ::std::vec::Vec::new(),
             gas_used: 0,
             logs: ::std::vec::Vec::new(),
+            effective_gas_price: 0,
+            contract_address: ::protobuf::MessageField::none(),
+            code_hash: ::std::vec::Vec::new(),
+            code_size: 0,
+            salt: ::std::vec::Vec::new(),
+            error_message: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -487,6 +597,148 @@ pub mod preturn_result {
     }
 }
 
+///  output of `call_with_diff`
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:top.tvm_engine.parameters.PStateDiffResult)
+pub struct PStateDiffResult {
+    // message fields
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PStateDiffResult.result)
+    pub result: ::protobuf::MessageField<PReturnResult>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PStateDiffResult.state_diff)
+    pub state_diff: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:top.tvm_engine.parameters.PStateDiffResult.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PStateDiffResult {
+    fn default() -> &'a PStateDiffResult {
+        <PStateDiffResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PStateDiffResult {
+    pub fn new() -> PStateDiffResult {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, PReturnResult>(
+            "result",
+            |m: &PStateDiffResult| { &m.result },
+            |m: &mut PStateDiffResult| { &mut m.result },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "state_diff",
+            |m: &PStateDiffResult| { &m.state_diff },
+            |m: &mut PStateDiffResult| { &mut m.state_diff },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PStateDiffResult>(
+            "PStateDiffResult",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PStateDiffResult {
+    const NAME: &'static str = "PStateDiffResult";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.result)?;
+                },
+                18 => {
+                    self.state_diff = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.result.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.state_diff.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.state_diff);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.result.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.state_diff.is_empty() {
+            os.write_string(2, &self.state_diff)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PStateDiffResult {
+        PStateDiffResult::new()
+    }
+
+    fn clear(&mut self) {
+        self.result.clear();
+        self.state_diff.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PStateDiffResult {
+        static instance: PStateDiffResult = PStateDiffResult {
+            result: ::protobuf::MessageField::none(),
+            state_diff: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PStateDiffResult {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PStateDiffResult").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PStateDiffResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PStateDiffResult {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:top.tvm_engine.parameters.PCallArgs)
 pub struct PCallArgs {
@@ -501,6 +753,22 @@ pub struct PCallArgs {
     pub input: ::std::vec::Vec<u8>,
     // @@protoc_insertion_point(field:top.tvm_engine.parameters.PCallArgs.gas_limit)
     pub gas_limit: u64,
+    ///  fees in wei per gas: EIP-1559 when max_fee_per_gas is set, legacy gas_price otherwise,
+    ///  Env::gas_price when none is set
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PCallArgs.gas_price)
+    pub gas_price: u64,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PCallArgs.max_fee_per_gas)
+    pub max_fee_per_gas: u64,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PCallArgs.max_priority_fee_per_gas)
+    pub max_priority_fee_per_gas: u64,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PCallArgs.access_list)
+    pub access_list: ::std::vec::Vec<pcall_args::PAccessListItem>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PCallArgs.nonce)
+    pub nonce: ::protobuf::MessageField<preturn_result::PU256>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PCallArgs.chain_id)
+    pub chain_id: u64,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PCallArgs.salt)
+    pub salt: ::std::vec::Vec<u8>,
     // special fields
     // @@protoc_insertion_point(special_field:top.tvm_engine.parameters.PCallArgs.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -518,7 +786,7 @@ impl PCallArgs {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut fields = ::std::vec::Vec::with_capacity(12);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::pbasic::PAddress>(
             "sender_address",
@@ -545,6 +813,41 @@ impl PCallArgs {
             |m: &PCallArgs| { &m.gas_limit },
             |m: &mut PCallArgs| { &mut m.gas_limit },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "gas_price",
+            |m: &PCallArgs| { &m.gas_price },
+            |m: &mut PCallArgs| { &mut m.gas_price },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_fee_per_gas",
+            |m: &PCallArgs| { &m.max_fee_per_gas },
+            |m: &mut PCallArgs| { &mut m.max_fee_per_gas },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_priority_fee_per_gas",
+            |m: &PCallArgs| { &m.max_priority_fee_per_gas },
+            |m: &mut PCallArgs| { &mut m.max_priority_fee_per_gas },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "access_list",
+            |m: &PCallArgs| { &m.access_list },
+            |m: &mut PCallArgs| { &mut m.access_list },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, preturn_result::PU256>(
+            "nonce",
+            |m: &PCallArgs| { &m.nonce },
+            |m: &mut PCallArgs| { &mut m.nonce },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "chain_id",
+            |m: &PCallArgs| { &m.chain_id },
+            |m: &mut PCallArgs| { &mut m.chain_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "salt",
+            |m: &PCallArgs| { &m.salt },
+            |m: &mut PCallArgs| { &mut m.salt },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PCallArgs>(
             "PCallArgs",
             fields,
@@ -578,6 +881,27 @@ impl ::protobuf::Message for PCallArgs {
                 40 => {
                     self.gas_limit = is.read_uint64()?;
                 },
+                48 => {
+                    self.gas_price = is.read_uint64()?;
+                },
+                56 => {
+                    self.max_fee_per_gas = is.read_uint64()?;
+                },
+                64 => {
+                    self.max_priority_fee_per_gas = is.read_uint64()?;
+                },
+                74 => {
+                    self.access_list.push(is.read_message()?);
+                },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.nonce)?;
+                },
+                88 => {
+                    self.chain_id = is.read_uint64()?;
+                },
+                98 => {
+                    self.salt = is.read_bytes()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -607,6 +931,29 @@ impl ::protobuf::Message for PCallArgs {
         if self.gas_limit != 0 {
             my_size += ::protobuf::rt::uint64_size(5, self.gas_limit);
         }
+        if self.gas_price != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.gas_price);
+        }
+        if self.max_fee_per_gas != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.max_fee_per_gas);
+        }
+        if self.max_priority_fee_per_gas != 0 {
+            my_size += ::protobuf::rt::uint64_size(8, self.max_priority_fee_per_gas);
+        }
+        for value in &self.access_list {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.nonce.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.chain_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(11, self.chain_id);
+        }
+        if !self.salt.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(12, &self.salt);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -628,6 +975,27 @@ impl ::protobuf::Message for PCallArgs {
         if self.gas_limit != 0 {
             os.write_uint64(5, self.gas_limit)?;
         }
+        if self.gas_price != 0 {
+            os.write_uint64(6, self.gas_price)?;
+        }
+        if self.max_fee_per_gas != 0 {
+            os.write_uint64(7, self.max_fee_per_gas)?;
+        }
+        if self.max_priority_fee_per_gas != 0 {
+            os.write_uint64(8, self.max_priority_fee_per_gas)?;
+        }
+        for v in &self.access_list {
+            ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
+        };
+        if let Some(v) = self.nonce.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        if self.chain_id != 0 {
+            os.write_uint64(11, self.chain_id)?;
+        }
+        if !self.salt.is_empty() {
+            os.write_bytes(12, &self.salt)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -650,6 +1018,13 @@ impl ::protobuf::Message for PCallArgs {
         self.value = 0;
         self.input.clear();
         self.gas_limit = 0;
+        self.gas_price = 0;
+        self.max_fee_per_gas = 0;
+        self.max_priority_fee_per_gas = 0;
+        self.access_list.clear();
+        self.nonce.clear();
+        self.chain_id = 0;
+        self.salt.clear();
         self.special_fields.clear();
     }
 
@@ -660,6 +1035,13 @@ impl ::protobuf::Message for PCallArgs {
             value: 0,
             input: ::std::vec::Vec::new(),
             gas_limit: 0,
+            gas_price: 0,
+            max_fee_per_gas: 0,
+            max_priority_fee_per_gas: 0,
+            access_list: ::std::vec::Vec::new(),
+            nonce: ::protobuf::MessageField::none(),
+            chain_id: 0,
+            salt: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -683,77 +1065,1600 @@ impl ::protobuf::reflect::ProtobufValue for PCallArgs {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+/// Nested message and enums of message `PCallArgs`
+pub mod pcall_args {
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:top.tvm_engine.parameters.PCallArgs.PAccessListItem)
+    pub struct PAccessListItem {
+        // message fields
+        // @@protoc_insertion_point(field:top.tvm_engine.parameters.PCallArgs.PAccessListItem.address)
+        pub address: ::protobuf::MessageField<super::super::pbasic::PAddress>,
+        // @@protoc_insertion_point(field:top.tvm_engine.parameters.PCallArgs.PAccessListItem.storage_keys)
+        pub storage_keys: ::std::vec::Vec<::std::vec::Vec<u8>>,
+        // special fields
+        // @@protoc_insertion_point(special_field:top.tvm_engine.parameters.PCallArgs.PAccessListItem.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a PAccessListItem {
+        fn default() -> &'a PAccessListItem {
+            <PAccessListItem as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl PAccessListItem {
+        pub fn new() -> PAccessListItem {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::super::pbasic::PAddress>(
+                "address",
+                |m: &PAccessListItem| { &m.address },
+                |m: &mut PAccessListItem| { &mut m.address },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+                "storage_keys",
+                |m: &PAccessListItem| { &m.storage_keys },
+                |m: &mut PAccessListItem| { &mut m.storage_keys },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PAccessListItem>(
+                "PCallArgs.PAccessListItem",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for PAccessListItem {
+        const NAME: &'static str = "PAccessListItem";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    10 => {
+                        ::protobuf::rt::read_singular_message_into_field(is, &mut self.address)?;
+                    },
+                    18 => {
+                        self.storage_keys.push(is.read_bytes()?);
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if let Some(v) = self.address.as_ref() {
+                let len = v.compute_size();
+                my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            }
+            for value in &self.storage_keys {
+                my_size += ::protobuf::rt::bytes_size(2, &value);
+            };
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if let Some(v) = self.address.as_ref() {
+                ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+            }
+            for v in &self.storage_keys {
+                os.write_bytes(2, &v)?;
+            };
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> PAccessListItem {
+            PAccessListItem::new()
+        }
+
+        fn clear(&mut self) {
+            self.address.clear();
+            self.storage_keys.clear();
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static PAccessListItem {
+            static instance: PAccessListItem = PAccessListItem {
+                address: ::protobuf::MessageField::none(),
+                storage_keys: ::std::vec::Vec::new(),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for PAccessListItem {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("PCallArgs.PAccessListItem").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for PAccessListItem {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for PAccessListItem {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:top.tvm_engine.parameters.PEstimateGasResult)
+pub struct PEstimateGasResult {
+    // message fields
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PEstimateGasResult.status)
+    pub status: ::protobuf::EnumOrUnknown<PStatusCode>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PEstimateGasResult.status_data)
+    pub status_data: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PEstimateGasResult.gas_used)
+    pub gas_used: u64,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PEstimateGasResult.gas_limit)
+    pub gas_limit: u64,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PEstimateGasResult.error_message)
+    pub error_message: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:top.tvm_engine.parameters.PEstimateGasResult.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PEstimateGasResult {
+    fn default() -> &'a PEstimateGasResult {
+        <PEstimateGasResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PEstimateGasResult {
+    pub fn new() -> PEstimateGasResult {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &PEstimateGasResult| { &m.status },
+            |m: &mut PEstimateGasResult| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status_data",
+            |m: &PEstimateGasResult| { &m.status_data },
+            |m: &mut PEstimateGasResult| { &mut m.status_data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "gas_used",
+            |m: &PEstimateGasResult| { &m.gas_used },
+            |m: &mut PEstimateGasResult| { &mut m.gas_used },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "gas_limit",
+            |m: &PEstimateGasResult| { &m.gas_limit },
+            |m: &mut PEstimateGasResult| { &mut m.gas_limit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error_message",
+            |m: &PEstimateGasResult| { &m.error_message },
+            |m: &mut PEstimateGasResult| { &mut m.error_message },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PEstimateGasResult>(
+            "PEstimateGasResult",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PEstimateGasResult {
+    const NAME: &'static str = "PEstimateGasResult";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.status_data = is.read_bytes()?;
+                },
+                24 => {
+                    self.gas_used = is.read_uint64()?;
+                },
+                32 => {
+                    self.gas_limit = is.read_uint64()?;
+                },
+                42 => {
+                    self.error_message = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.status != ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED) {
+            my_size += ::protobuf::rt::int32_size(1, self.status.value());
+        }
+        if !self.status_data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.status_data);
+        }
+        if self.gas_used != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.gas_used);
+        }
+        if self.gas_limit != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.gas_limit);
+        }
+        if !self.error_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.error_message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.status != ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        if !self.status_data.is_empty() {
+            os.write_bytes(2, &self.status_data)?;
+        }
+        if self.gas_used != 0 {
+            os.write_uint64(3, self.gas_used)?;
+        }
+        if self.gas_limit != 0 {
+            os.write_uint64(4, self.gas_limit)?;
+        }
+        if !self.error_message.is_empty() {
+            os.write_string(5, &self.error_message)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PEstimateGasResult {
+        PEstimateGasResult::new()
+    }
+
+    fn clear(&mut self) {
+        self.status = ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED);
+        self.status_data.clear();
+        self.gas_used = 0;
+        self.gas_limit = 0;
+        self.error_message.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PEstimateGasResult {
+        static instance: PEstimateGasResult = PEstimateGasResult {
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            status_data: ::std::vec::Vec::new(),
+            gas_used: 0,
+            gas_limit: 0,
+            error_message: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PEstimateGasResult {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PEstimateGasResult").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PEstimateGasResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PEstimateGasResult {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:top.tvm_engine.parameters.PAccessListResult)
+pub struct PAccessListResult {
+    // message fields
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PAccessListResult.status)
+    pub status: ::protobuf::EnumOrUnknown<PStatusCode>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PAccessListResult.status_data)
+    pub status_data: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PAccessListResult.gas_used)
+    pub gas_used: u64,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PAccessListResult.access_list)
+    pub access_list: ::std::vec::Vec<pcall_args::PAccessListItem>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PAccessListResult.error_message)
+    pub error_message: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:top.tvm_engine.parameters.PAccessListResult.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PAccessListResult {
+    fn default() -> &'a PAccessListResult {
+        <PAccessListResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PAccessListResult {
+    pub fn new() -> PAccessListResult {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &PAccessListResult| { &m.status },
+            |m: &mut PAccessListResult| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status_data",
+            |m: &PAccessListResult| { &m.status_data },
+            |m: &mut PAccessListResult| { &mut m.status_data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "gas_used",
+            |m: &PAccessListResult| { &m.gas_used },
+            |m: &mut PAccessListResult| { &mut m.gas_used },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "access_list",
+            |m: &PAccessListResult| { &m.access_list },
+            |m: &mut PAccessListResult| { &mut m.access_list },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error_message",
+            |m: &PAccessListResult| { &m.error_message },
+            |m: &mut PAccessListResult| { &mut m.error_message },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PAccessListResult>(
+            "PAccessListResult",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PAccessListResult {
+    const NAME: &'static str = "PAccessListResult";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.status_data = is.read_bytes()?;
+                },
+                24 => {
+                    self.gas_used = is.read_uint64()?;
+                },
+                34 => {
+                    self.access_list.push(is.read_message()?);
+                },
+                42 => {
+                    self.error_message = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.status != ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED) {
+            my_size += ::protobuf::rt::int32_size(1, self.status.value());
+        }
+        if !self.status_data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.status_data);
+        }
+        if self.gas_used != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.gas_used);
+        }
+        for value in &self.access_list {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.error_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.error_message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.status != ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        if !self.status_data.is_empty() {
+            os.write_bytes(2, &self.status_data)?;
+        }
+        if self.gas_used != 0 {
+            os.write_uint64(3, self.gas_used)?;
+        }
+        for v in &self.access_list {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        if !self.error_message.is_empty() {
+            os.write_string(5, &self.error_message)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PAccessListResult {
+        PAccessListResult::new()
+    }
+
+    fn clear(&mut self) {
+        self.status = ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED);
+        self.status_data.clear();
+        self.gas_used = 0;
+        self.access_list.clear();
+        self.error_message.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PAccessListResult {
+        static instance: PAccessListResult = PAccessListResult {
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            status_data: ::std::vec::Vec::new(),
+            gas_used: 0,
+            access_list: ::std::vec::Vec::new(),
+            error_message: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PAccessListResult {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PAccessListResult").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PAccessListResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PAccessListResult {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:top.tvm_engine.parameters.PDeployAddressResult)
+pub struct PDeployAddressResult {
+    // message fields
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PDeployAddressResult.status)
+    pub status: ::protobuf::EnumOrUnknown<PStatusCode>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PDeployAddressResult.status_data)
+    pub status_data: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PDeployAddressResult.contract_address)
+    pub contract_address: ::protobuf::MessageField<super::pbasic::PAddress>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PDeployAddressResult.salt)
+    pub salt: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PDeployAddressResult.error_message)
+    pub error_message: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:top.tvm_engine.parameters.PDeployAddressResult.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PDeployAddressResult {
+    fn default() -> &'a PDeployAddressResult {
+        <PDeployAddressResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PDeployAddressResult {
+    pub fn new() -> PDeployAddressResult {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &PDeployAddressResult| { &m.status },
+            |m: &mut PDeployAddressResult| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status_data",
+            |m: &PDeployAddressResult| { &m.status_data },
+            |m: &mut PDeployAddressResult| { &mut m.status_data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::pbasic::PAddress>(
+            "contract_address",
+            |m: &PDeployAddressResult| { &m.contract_address },
+            |m: &mut PDeployAddressResult| { &mut m.contract_address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "salt",
+            |m: &PDeployAddressResult| { &m.salt },
+            |m: &mut PDeployAddressResult| { &mut m.salt },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error_message",
+            |m: &PDeployAddressResult| { &m.error_message },
+            |m: &mut PDeployAddressResult| { &mut m.error_message },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PDeployAddressResult>(
+            "PDeployAddressResult",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PDeployAddressResult {
+    const NAME: &'static str = "PDeployAddressResult";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.status_data = is.read_bytes()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.contract_address)?;
+                },
+                34 => {
+                    self.salt = is.read_bytes()?;
+                },
+                42 => {
+                    self.error_message = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.status != ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED) {
+            my_size += ::protobuf::rt::int32_size(1, self.status.value());
+        }
+        if !self.status_data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.status_data);
+        }
+        if let Some(v) = self.contract_address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.salt.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.salt);
+        }
+        if !self.error_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.error_message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.status != ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        if !self.status_data.is_empty() {
+            os.write_bytes(2, &self.status_data)?;
+        }
+        if let Some(v) = self.contract_address.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if !self.salt.is_empty() {
+            os.write_bytes(4, &self.salt)?;
+        }
+        if !self.error_message.is_empty() {
+            os.write_string(5, &self.error_message)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PDeployAddressResult {
+        PDeployAddressResult::new()
+    }
+
+    fn clear(&mut self) {
+        self.status = ::protobuf::EnumOrUnknown::new(PStatusCode::SUCCEED);
+        self.status_data.clear();
+        self.contract_address.clear();
+        self.salt.clear();
+        self.error_message.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PDeployAddressResult {
+        static instance: PDeployAddressResult = PDeployAddressResult {
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            status_data: ::std::vec::Vec::new(),
+            contract_address: ::protobuf::MessageField::none(),
+            salt: ::std::vec::Vec::new(),
+            error_message: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PDeployAddressResult {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PDeployAddressResult").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PDeployAddressResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PDeployAddressResult {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  input of `set_fork_schedule`, London from height 0 unless an activation replaces it
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:top.tvm_engine.parameters.PForkSchedule)
+pub struct PForkSchedule {
+    // message fields
+    // @@protoc_insertion_point(field:top.tvm_engine.parameters.PForkSchedule.activations)
+    pub activations: ::std::vec::Vec<pfork_schedule::PActivation>,
+    // special fields
+    // @@protoc_insertion_point(special_field:top.tvm_engine.parameters.PForkSchedule.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PForkSchedule {
+    fn default() -> &'a PForkSchedule {
+        <PForkSchedule as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PForkSchedule {
+    pub fn new() -> PForkSchedule {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "activations",
+            |m: &PForkSchedule| { &m.activations },
+            |m: &mut PForkSchedule| { &mut m.activations },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PForkSchedule>(
+            "PForkSchedule",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PForkSchedule {
+    const NAME: &'static str = "PForkSchedule";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.activations.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.activations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.activations {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PForkSchedule {
+        PForkSchedule::new()
+    }
+
+    fn clear(&mut self) {
+        self.activations.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PForkSchedule {
+        static instance: PForkSchedule = PForkSchedule {
+            activations: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PForkSchedule {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PForkSchedule").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PForkSchedule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PForkSchedule {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `PForkSchedule`
+pub mod pfork_schedule {
+    #[derive(PartialEq,Clone,Default,Debug)]
+    // @@protoc_insertion_point(message:top.tvm_engine.parameters.PForkSchedule.PActivation)
+    pub struct PActivation {
+        // message fields
+        // @@protoc_insertion_point(field:top.tvm_engine.parameters.PForkSchedule.PActivation.height)
+        pub height: u64,
+        // @@protoc_insertion_point(field:top.tvm_engine.parameters.PForkSchedule.PActivation.hardfork)
+        pub hardfork: ::protobuf::EnumOrUnknown<super::PHardfork>,
+        // special fields
+        // @@protoc_insertion_point(special_field:top.tvm_engine.parameters.PForkSchedule.PActivation.special_fields)
+        pub special_fields: ::protobuf::SpecialFields,
+    }
+
+    impl<'a> ::std::default::Default for &'a PActivation {
+        fn default() -> &'a PActivation {
+            <PActivation as ::protobuf::Message>::default_instance()
+        }
+    }
+
+    impl PActivation {
+        pub fn new() -> PActivation {
+            ::std::default::Default::default()
+        }
+
+        pub(in super) fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+            let mut fields = ::std::vec::Vec::with_capacity(2);
+            let mut oneofs = ::std::vec::Vec::with_capacity(0);
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "height",
+                |m: &PActivation| { &m.height },
+                |m: &mut PActivation| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+                "hardfork",
+                |m: &PActivation| { &m.hardfork },
+                |m: &mut PActivation| { &mut m.hardfork },
+            ));
+            ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PActivation>(
+                "PForkSchedule.PActivation",
+                fields,
+                oneofs,
+            )
+        }
+    }
+
+    impl ::protobuf::Message for PActivation {
+        const NAME: &'static str = "PActivation";
+
+        fn is_initialized(&self) -> bool {
+            true
+        }
+
+        fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+            while let Some(tag) = is.read_raw_tag_or_eof()? {
+                match tag {
+                    8 => {
+                        self.height = is.read_uint64()?;
+                    },
+                    16 => {
+                        self.hardfork = is.read_enum_or_unknown()?;
+                    },
+                    tag => {
+                        ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                    },
+                };
+            }
+            ::std::result::Result::Ok(())
+        }
+
+        // Compute sizes of nested messages
+        #[allow(unused_variables)]
+        fn compute_size(&self) -> u64 {
+            let mut my_size = 0;
+            if self.height != 0 {
+                my_size += ::protobuf::rt::uint64_size(1, self.height);
+            }
+            if self.hardfork != ::protobuf::EnumOrUnknown::new(super::PHardfork::LONDON) {
+                my_size += ::protobuf::rt::int32_size(2, self.hardfork.value());
+            }
+            my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+            self.special_fields.cached_size().set(my_size as u32);
+            my_size
+        }
+
+        fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+            if self.height != 0 {
+                os.write_uint64(1, self.height)?;
+            }
+            if self.hardfork != ::protobuf::EnumOrUnknown::new(super::PHardfork::LONDON) {
+                os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.hardfork))?;
+            }
+            os.write_unknown_fields(self.special_fields.unknown_fields())?;
+            ::std::result::Result::Ok(())
+        }
+
+        fn special_fields(&self) -> &::protobuf::SpecialFields {
+            &self.special_fields
+        }
+
+        fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+            &mut self.special_fields
+        }
+
+        fn new() -> PActivation {
+            PActivation::new()
+        }
+
+        fn clear(&mut self) {
+            self.height = 0;
+            self.hardfork = ::protobuf::EnumOrUnknown::new(super::PHardfork::LONDON);
+            self.special_fields.clear();
+        }
+
+        fn default_instance() -> &'static PActivation {
+            static instance: PActivation = PActivation {
+                height: 0,
+                hardfork: ::protobuf::EnumOrUnknown::from_i32(0),
+                special_fields: ::protobuf::SpecialFields::new(),
+            };
+            &instance
+        }
+    }
+
+    impl ::protobuf::MessageFull for PActivation {
+        fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().message_by_package_relative_name("PForkSchedule.PActivation").unwrap()).clone()
+        }
+    }
+
+    impl ::std::fmt::Display for PActivation {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            ::protobuf::text_format::fmt(self, f)
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for PActivation {
+        type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+    }
+}
+
+///  Stable code of every outcome, carried in the `status` of the results. Never renumbered.
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:top.tvm_engine.parameters.PStatusCode)
+pub enum PStatusCode {
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.SUCCEED)
+    SUCCEED = 0,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.REVERT)
+    REVERT = 1,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.OUT_OF_GAS)
+    OUT_OF_GAS = 2,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.OUT_OF_FUND)
+    OUT_OF_FUND = 3,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.OUT_OF_OFFSET)
+    OUT_OF_OFFSET = 4,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.NONCE_TOO_LOW)
+    NONCE_TOO_LOW = 100,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.NONCE_TOO_HIGH)
+    NONCE_TOO_HIGH = 101,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.CHAIN_ID_MISMATCH)
+    CHAIN_ID_MISMATCH = 102,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.INTRINSIC_GAS_TOO_LOW)
+    INTRINSIC_GAS_TOO_LOW = 103,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.INSUFFICIENT_FUNDS)
+    INSUFFICIENT_FUNDS = 104,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.INIT_CODE_TOO_LARGE)
+    INIT_CODE_TOO_LARGE = 105,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.FEE_CAP_TOO_LOW)
+    FEE_CAP_TOO_LOW = 106,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.TIP_ABOVE_FEE_CAP)
+    TIP_ABOVE_FEE_CAP = 107,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.SALT_TABLE_MISMATCH)
+    SALT_TABLE_MISMATCH = 108,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.MALFORMED_ARGS)
+    MALFORMED_ARGS = 109,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.STACK_UNDERFLOW)
+    STACK_UNDERFLOW = 200,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.STACK_OVERFLOW)
+    STACK_OVERFLOW = 201,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.INVALID_JUMP)
+    INVALID_JUMP = 202,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.INVALID_RANGE)
+    INVALID_RANGE = 203,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.DESIGNATED_INVALID)
+    DESIGNATED_INVALID = 204,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.CALL_TOO_DEEP)
+    CALL_TOO_DEEP = 205,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.CREATE_COLLISION)
+    CREATE_COLLISION = 206,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.CREATE_CONTRACT_LIMIT)
+    CREATE_CONTRACT_LIMIT = 207,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.INVALID_CODE)
+    INVALID_CODE = 208,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.PC_UNDERFLOW)
+    PC_UNDERFLOW = 209,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.CREATE_EMPTY)
+    CREATE_EMPTY = 210,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.EVM_ERROR_OTHER)
+    EVM_ERROR_OTHER = 299,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.NOT_SUPPORTED)
+    NOT_SUPPORTED = 300,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.UNHANDLED_INTERRUPT)
+    UNHANDLED_INTERRUPT = 301,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.CALL_ERROR_AS_FATAL)
+    CALL_ERROR_AS_FATAL = 302,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.EVM_FATAL_OTHER)
+    EVM_FATAL_OTHER = 399,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PStatusCode.CROSS_TABLE_CREATE)
+    CROSS_TABLE_CREATE = 400,
+}
+
+impl ::protobuf::Enum for PStatusCode {
+    const NAME: &'static str = "PStatusCode";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<PStatusCode> {
+        match value {
+            0 => ::std::option::Option::Some(PStatusCode::SUCCEED),
+            1 => ::std::option::Option::Some(PStatusCode::REVERT),
+            2 => ::std::option::Option::Some(PStatusCode::OUT_OF_GAS),
+            3 => ::std::option::Option::Some(PStatusCode::OUT_OF_FUND),
+            4 => ::std::option::Option::Some(PStatusCode::OUT_OF_OFFSET),
+            100 => ::std::option::Option::Some(PStatusCode::NONCE_TOO_LOW),
+            101 => ::std::option::Option::Some(PStatusCode::NONCE_TOO_HIGH),
+            102 => ::std::option::Option::Some(PStatusCode::CHAIN_ID_MISMATCH),
+            103 => ::std::option::Option::Some(PStatusCode::INTRINSIC_GAS_TOO_LOW),
+            104 => ::std::option::Option::Some(PStatusCode::INSUFFICIENT_FUNDS),
+            105 => ::std::option::Option::Some(PStatusCode::INIT_CODE_TOO_LARGE),
+            106 => ::std::option::Option::Some(PStatusCode::FEE_CAP_TOO_LOW),
+            107 => ::std::option::Option::Some(PStatusCode::TIP_ABOVE_FEE_CAP),
+            108 => ::std::option::Option::Some(PStatusCode::SALT_TABLE_MISMATCH),
+            109 => ::std::option::Option::Some(PStatusCode::MALFORMED_ARGS),
+            200 => ::std::option::Option::Some(PStatusCode::STACK_UNDERFLOW),
+            201 => ::std::option::Option::Some(PStatusCode::STACK_OVERFLOW),
+            202 => ::std::option::Option::Some(PStatusCode::INVALID_JUMP),
+            203 => ::std::option::Option::Some(PStatusCode::INVALID_RANGE),
+            204 => ::std::option::Option::Some(PStatusCode::DESIGNATED_INVALID),
+            205 => ::std::option::Option::Some(PStatusCode::CALL_TOO_DEEP),
+            206 => ::std::option::Option::Some(PStatusCode::CREATE_COLLISION),
+            207 => ::std::option::Option::Some(PStatusCode::CREATE_CONTRACT_LIMIT),
+            208 => ::std::option::Option::Some(PStatusCode::INVALID_CODE),
+            209 => ::std::option::Option::Some(PStatusCode::PC_UNDERFLOW),
+            210 => ::std::option::Option::Some(PStatusCode::CREATE_EMPTY),
+            299 => ::std::option::Option::Some(PStatusCode::EVM_ERROR_OTHER),
+            300 => ::std::option::Option::Some(PStatusCode::NOT_SUPPORTED),
+            301 => ::std::option::Option::Some(PStatusCode::UNHANDLED_INTERRUPT),
+            302 => ::std::option::Option::Some(PStatusCode::CALL_ERROR_AS_FATAL),
+            399 => ::std::option::Option::Some(PStatusCode::EVM_FATAL_OTHER),
+            400 => ::std::option::Option::Some(PStatusCode::CROSS_TABLE_CREATE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [PStatusCode] = &[
+        PStatusCode::SUCCEED,
+        PStatusCode::REVERT,
+        PStatusCode::OUT_OF_GAS,
+        PStatusCode::OUT_OF_FUND,
+        PStatusCode::OUT_OF_OFFSET,
+        PStatusCode::NONCE_TOO_LOW,
+        PStatusCode::NONCE_TOO_HIGH,
+        PStatusCode::CHAIN_ID_MISMATCH,
+        PStatusCode::INTRINSIC_GAS_TOO_LOW,
+        PStatusCode::INSUFFICIENT_FUNDS,
+        PStatusCode::INIT_CODE_TOO_LARGE,
+        PStatusCode::FEE_CAP_TOO_LOW,
+        PStatusCode::TIP_ABOVE_FEE_CAP,
+        PStatusCode::SALT_TABLE_MISMATCH,
+        PStatusCode::MALFORMED_ARGS,
+        PStatusCode::STACK_UNDERFLOW,
+        PStatusCode::STACK_OVERFLOW,
+        PStatusCode::INVALID_JUMP,
+        PStatusCode::INVALID_RANGE,
+        PStatusCode::DESIGNATED_INVALID,
+        PStatusCode::CALL_TOO_DEEP,
+        PStatusCode::CREATE_COLLISION,
+        PStatusCode::CREATE_CONTRACT_LIMIT,
+        PStatusCode::INVALID_CODE,
+        PStatusCode::PC_UNDERFLOW,
+        PStatusCode::CREATE_EMPTY,
+        PStatusCode::EVM_ERROR_OTHER,
+        PStatusCode::NOT_SUPPORTED,
+        PStatusCode::UNHANDLED_INTERRUPT,
+        PStatusCode::CALL_ERROR_AS_FATAL,
+        PStatusCode::EVM_FATAL_OTHER,
+        PStatusCode::CROSS_TABLE_CREATE,
+    ];
+}
+
+impl ::protobuf::EnumFull for PStatusCode {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("PStatusCode").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = match self {
+            PStatusCode::SUCCEED => 0,
+            PStatusCode::REVERT => 1,
+            PStatusCode::OUT_OF_GAS => 2,
+            PStatusCode::OUT_OF_FUND => 3,
+            PStatusCode::OUT_OF_OFFSET => 4,
+            PStatusCode::NONCE_TOO_LOW => 5,
+            PStatusCode::NONCE_TOO_HIGH => 6,
+            PStatusCode::CHAIN_ID_MISMATCH => 7,
+            PStatusCode::INTRINSIC_GAS_TOO_LOW => 8,
+            PStatusCode::INSUFFICIENT_FUNDS => 9,
+            PStatusCode::INIT_CODE_TOO_LARGE => 10,
+            PStatusCode::FEE_CAP_TOO_LOW => 11,
+            PStatusCode::TIP_ABOVE_FEE_CAP => 12,
+            PStatusCode::SALT_TABLE_MISMATCH => 13,
+            PStatusCode::MALFORMED_ARGS => 14,
+            PStatusCode::STACK_UNDERFLOW => 15,
+            PStatusCode::STACK_OVERFLOW => 16,
+            PStatusCode::INVALID_JUMP => 17,
+            PStatusCode::INVALID_RANGE => 18,
+            PStatusCode::DESIGNATED_INVALID => 19,
+            PStatusCode::CALL_TOO_DEEP => 20,
+            PStatusCode::CREATE_COLLISION => 21,
+            PStatusCode::CREATE_CONTRACT_LIMIT => 22,
+            PStatusCode::INVALID_CODE => 23,
+            PStatusCode::PC_UNDERFLOW => 24,
+            PStatusCode::CREATE_EMPTY => 25,
+            PStatusCode::EVM_ERROR_OTHER => 26,
+            PStatusCode::NOT_SUPPORTED => 27,
+            PStatusCode::UNHANDLED_INTERRUPT => 28,
+            PStatusCode::CALL_ERROR_AS_FATAL => 29,
+            PStatusCode::EVM_FATAL_OTHER => 30,
+            PStatusCode::CROSS_TABLE_CREATE => 31,
+        };
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for PStatusCode {
+    fn default() -> Self {
+        PStatusCode::SUCCEED
+    }
+}
+
+impl PStatusCode {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<PStatusCode>("PStatusCode")
+    }
+}
+
+///  EVM rule sets of `PForkSchedule`
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:top.tvm_engine.parameters.PHardfork)
+pub enum PHardfork {
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PHardfork.LONDON)
+    LONDON = 0,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PHardfork.FRONTIER)
+    FRONTIER = 1,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PHardfork.ISTANBUL)
+    ISTANBUL = 2,
+    // @@protoc_insertion_point(enum_value:top.tvm_engine.parameters.PHardfork.BERLIN)
+    BERLIN = 3,
+}
+
+impl ::protobuf::Enum for PHardfork {
+    const NAME: &'static str = "PHardfork";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<PHardfork> {
+        match value {
+            0 => ::std::option::Option::Some(PHardfork::LONDON),
+            1 => ::std::option::Option::Some(PHardfork::FRONTIER),
+            2 => ::std::option::Option::Some(PHardfork::ISTANBUL),
+            3 => ::std::option::Option::Some(PHardfork::BERLIN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [PHardfork] = &[
+        PHardfork::LONDON,
+        PHardfork::FRONTIER,
+        PHardfork::ISTANBUL,
+        PHardfork::BERLIN,
+    ];
+}
+
+impl ::protobuf::EnumFull for PHardfork {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("PHardfork").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for PHardfork {
+    fn default() -> Self {
+        PHardfork::LONDON
+    }
+}
+
+impl PHardfork {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<PHardfork>("PHardfork")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x20protobuf_types/pparameters.proto\x12\x19top.tvm_engine.parameters\
-    \x1a\x1bprotobuf_types/pbasic.proto\"\xe2\x02\n\rPReturnResult\x12\x16\n\
-    \x06status\x18\x01\x20\x01(\rR\x06status\x12\x1f\n\x0bstatus_data\x18\
-    \x02\x20\x01(\x0cR\nstatusData\x12\x19\n\x08gas_used\x18\x03\x20\x01(\
-    \x04R\x07gasUsed\x12A\n\x04logs\x18\x04\x20\x03(\x0b2-.top.tvm_engine.pa\
-    rameters.PReturnResult.PLogR\x04logs\x1a\x1b\n\x05PU256\x12\x12\n\x04dat\
-    a\x18\x01\x20\x01(\x0cR\x04data\x1a\x9c\x01\n\x04PLog\x128\n\x07address\
-    \x18\x01\x20\x01(\x0b2\x1e.top.tvm_engine.basic.PAddressR\x07address\x12\
-    F\n\x06topics\x18\x02\x20\x03(\x0b2..top.tvm_engine.parameters.PReturnRe\
-    sult.PU256R\x06topics\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04data\"\
-    \xe2\x01\n\tPCallArgs\x12E\n\x0esender_address\x18\x01\x20\x01(\x0b2\x1e\
-    .top.tvm_engine.basic.PAddressR\rsenderAddress\x12E\n\x0erecver_address\
-    \x18\x02\x20\x01(\x0b2\x1e.top.tvm_engine.basic.PAddressR\rrecverAddress\
-    \x12\x14\n\x05value\x18\x03\x20\x01(\x04R\x05value\x12\x14\n\x05input\
-    \x18\x04\x20\x01(\x0cR\x05input\x12\x1b\n\tgas_limit\x18\x05\x20\x01(\
-    \x04R\x08gasLimitJ\xe1\x08\n\x06\x12\x04\0\0\x1d\x01\n\x08\n\x01\x0c\x12\
-    \x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x02\x07$\n\x08\n\x01\x02\x12\x03\
-    \x04\x08!\n\n\n\x02\x04\0\x12\x04\x06\0\x15\x01\n\n\n\x03\x04\0\x01\x12\
-    \x03\x06\x08\x15\n\x0c\n\x04\x04\0\x03\0\x12\x04\x07\x04\t\x05\n\x0c\n\
-    \x05\x04\0\x03\0\x01\x12\x03\x07\x0c\x11\n+\n\x06\x04\0\x03\0\x02\0\x12\
-    \x03\x08\x08\x17\"\x1c\x20use\x20bytes\x20=\x20U256\x20=\x20[u8;32]\n\n\
-    \x0f\n\x07\x04\0\x03\0\x02\0\x04\x12\x04\x08\x08\x07\x13\n\x0e\n\x07\x04\
-    \0\x03\0\x02\0\x05\x12\x03\x08\x08\r\n\x0e\n\x07\x04\0\x03\0\x02\0\x01\
-    \x12\x03\x08\x0e\x12\n\x0e\n\x07\x04\0\x03\0\x02\0\x03\x12\x03\x08\x15\
-    \x16\n\x0c\n\x04\x04\0\x03\x01\x12\x04\x0b\x04\x0f\x05\n\x0c\n\x05\x04\0\
-    \x03\x01\x01\x12\x03\x0b\x0c\x10\n\r\n\x06\x04\0\x03\x01\x02\0\x12\x03\
-    \x0c\x08#\n\x0f\n\x07\x04\0\x03\x01\x02\0\x04\x12\x04\x0c\x08\x0b\x12\n\
-    \x0e\n\x07\x04\0\x03\x01\x02\0\x06\x12\x03\x0c\x08\x16\n\x0e\n\x07\x04\0\
-    \x03\x01\x02\0\x01\x12\x03\x0c\x17\x1e\n\x0e\n\x07\x04\0\x03\x01\x02\0\
-    \x03\x12\x03\x0c!\"\n\r\n\x06\x04\0\x03\x01\x02\x01\x12\x03\r\x08\"\n\
-    \x0e\n\x07\x04\0\x03\x01\x02\x01\x04\x12\x03\r\x08\x10\n\x0e\n\x07\x04\0\
-    \x03\x01\x02\x01\x06\x12\x03\r\x11\x16\n\x0e\n\x07\x04\0\x03\x01\x02\x01\
-    \x01\x12\x03\r\x17\x1d\n\x0e\n\x07\x04\0\x03\x01\x02\x01\x03\x12\x03\r\
-    \x20!\n\r\n\x06\x04\0\x03\x01\x02\x02\x12\x03\x0e\x08\x17\n\x0f\n\x07\
-    \x04\0\x03\x01\x02\x02\x04\x12\x04\x0e\x08\r\"\n\x0e\n\x07\x04\0\x03\x01\
-    \x02\x02\x05\x12\x03\x0e\x08\r\n\x0e\n\x07\x04\0\x03\x01\x02\x02\x01\x12\
-    \x03\x0e\x0e\x12\n\x0e\n\x07\x04\0\x03\x01\x02\x02\x03\x12\x03\x0e\x15\
-    \x16\n\x0b\n\x04\x04\0\x02\0\x12\x03\x11\x04\x16\n\r\n\x05\x04\0\x02\0\
-    \x04\x12\x04\x11\x04\x0f\x05\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x11\x04\
-    \n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x11\x0b\x11\n\x0c\n\x05\x04\0\x02\
-    \0\x03\x12\x03\x11\x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x12\x04\x1a\
-    \n\r\n\x05\x04\0\x02\x01\x04\x12\x04\x12\x04\x11\x16\n\x0c\n\x05\x04\0\
-    \x02\x01\x05\x12\x03\x12\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x12\
-    \n\x15\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x12\x18\x19\n\x0b\n\x04\x04\
-    \0\x02\x02\x12\x03\x13\x04\x18\n\r\n\x05\x04\0\x02\x02\x04\x12\x04\x13\
-    \x04\x12\x1a\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x13\x04\n\n\x0c\n\x05\
-    \x04\0\x02\x02\x01\x12\x03\x13\x0b\x13\n\x0c\n\x05\x04\0\x02\x02\x03\x12\
-    \x03\x13\x16\x17\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x14\x04\x1b\n\x0c\n\
-    \x05\x04\0\x02\x03\x04\x12\x03\x14\x04\x0c\n\x0c\n\x05\x04\0\x02\x03\x06\
-    \x12\x03\x14\r\x11\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x14\x12\x16\n\
-    \x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x14\x19\x1a\n\n\n\x02\x04\x01\x12\
-    \x04\x17\0\x1d\x01\n\n\n\x03\x04\x01\x01\x12\x03\x17\x08\x11\n\x0b\n\x04\
-    \x04\x01\x02\0\x12\x03\x18\x04&\n\r\n\x05\x04\x01\x02\0\x04\x12\x04\x18\
-    \x04\x17\x13\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x18\x04\x12\n\x0c\n\
-    \x05\x04\x01\x02\0\x01\x12\x03\x18\x13!\n\x0c\n\x05\x04\x01\x02\0\x03\
-    \x12\x03\x18$%\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x19\x04&\n\r\n\x05\
-    \x04\x01\x02\x01\x04\x12\x04\x19\x04\x18&\n\x0c\n\x05\x04\x01\x02\x01\
-    \x06\x12\x03\x19\x04\x12\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x19\x13\
-    !\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x19$%\n\x0b\n\x04\x04\x01\x02\
-    \x02\x12\x03\x1a\x04\x15\n\r\n\x05\x04\x01\x02\x02\x04\x12\x04\x1a\x04\
-    \x19&\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x1a\x04\n\n\x0c\n\x05\x04\
-    \x01\x02\x02\x01\x12\x03\x1a\x0b\x10\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\
-    \x03\x1a\x13\x14\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x1b\x04\x14\n\r\n\
-    \x05\x04\x01\x02\x03\x04\x12\x04\x1b\x04\x1a\x15\n\x0c\n\x05\x04\x01\x02\
-    \x03\x05\x12\x03\x1b\x04\t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x1b\n\
-    \x0f\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x1b\x12\x13\n\x0b\n\x04\x04\
-    \x01\x02\x04\x12\x03\x1c\x04\x19\n\r\n\x05\x04\x01\x02\x04\x04\x12\x04\
-    \x1c\x04\x1b\x14\n\x0c\n\x05\x04\x01\x02\x04\x05\x12\x03\x1c\x04\n\n\x0c\
-    \n\x05\x04\x01\x02\x04\x01\x12\x03\x1c\x0b\x14\n\x0c\n\x05\x04\x01\x02\
-    \x04\x03\x12\x03\x1c\x17\x18b\x06proto3\
+    \x1a\x1bprotobuf_types/pbasic.proto\"\xf8\x04\n\rPReturnResult\x12>\n\
+    \x06status\x18\x01\x20\x01(\x0e2&.top.tvm_engine.parameters.PStatusCodeR\
+    \x06status\x12\x1f\n\x0bstatus_data\x18\x02\x20\x01(\x0cR\nstatusData\
+    \x12\x19\n\x08gas_used\x18\x03\x20\x01(\x04R\x07gasUsed\x12A\n\x04logs\
+    \x18\x04\x20\x03(\x0b2-.top.tvm_engine.parameters.PReturnResult.PLogR\
+    \x04logs\x12.\n\x13effective_gas_price\x18\x05\x20\x01(\x04R\x11effectiv\
+    eGasPrice\x12I\n\x10contract_address\x18\x06\x20\x01(\x0b2\x1e.top.tvm_e\
+    ngine.basic.PAddressR\x0fcontractAddress\x12\x1b\n\tcode_hash\x18\x07\
+    \x20\x01(\x0cR\x08codeHash\x12\x1b\n\tcode_size\x18\x08\x20\x01(\x04R\
+    \x08codeSize\x12\x12\n\x04salt\x18\t\x20\x01(\x0cR\x04salt\x12#\n\rerror\
+    _message\x18\n\x20\x01(\tR\x0cerrorMessage\x1a\x1b\n\x05PU256\x12\x12\n\
+    \x04data\x18\x01\x20\x01(\x0cR\x04data\x1a\x9c\x01\n\x04PLog\x128\n\x07a\
+    ddress\x18\x01\x20\x01(\x0b2\x1e.top.tvm_engine.basic.PAddressR\x07addre\
+    ss\x12F\n\x06topics\x18\x02\x20\x03(\x0b2..top.tvm_engine.parameters.PRe\
+    turnResult.PU256R\x06topics\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04d\
+    ata\"s\n\x10PStateDiffResult\x12@\n\x06result\x18\x01\x20\x01(\x0b2(.top\
+    .tvm_engine.parameters.PReturnResultR\x06result\x12\x1d\n\nstate_diff\
+    \x18\x02\x20\x01(\tR\tstateDiff\"\x9a\x05\n\tPCallArgs\x12E\n\x0esender_\
+    address\x18\x01\x20\x01(\x0b2\x1e.top.tvm_engine.basic.PAddressR\rsender\
+    Address\x12E\n\x0erecver_address\x18\x02\x20\x01(\x0b2\x1e.top.tvm_engin\
+    e.basic.PAddressR\rrecverAddress\x12\x14\n\x05value\x18\x03\x20\x01(\x04\
+    R\x05value\x12\x14\n\x05input\x18\x04\x20\x01(\x0cR\x05input\x12\x1b\n\t\
+    gas_limit\x18\x05\x20\x01(\x04R\x08gasLimit\x12\x1b\n\tgas_price\x18\x06\
+    \x20\x01(\x04R\x08gasPrice\x12%\n\x0fmax_fee_per_gas\x18\x07\x20\x01(\
+    \x04R\x0cmaxFeePerGas\x126\n\x18max_priority_fee_per_gas\x18\x08\x20\x01\
+    (\x04R\x14maxPriorityFeePerGas\x12U\n\x0baccess_list\x18\t\x20\x03(\x0b2\
+    4.top.tvm_engine.parameters.PCallArgs.PAccessListItemR\naccessList\x12D\
+    \n\x05nonce\x18\n\x20\x01(\x0b2..top.tvm_engine.parameters.PReturnResult\
+    .PU256R\x05nonce\x12\x19\n\x08chain_id\x18\x0b\x20\x01(\x04R\x07chainId\
+    \x12\x12\n\x04salt\x18\x0c\x20\x01(\x0cR\x04salt\x1an\n\x0fPAccessListIt\
+    em\x128\n\x07address\x18\x01\x20\x01(\x0b2\x1e.top.tvm_engine.basic.PAdd\
+    ressR\x07address\x12!\n\x0cstorage_keys\x18\x02\x20\x03(\x0cR\x0bstorage\
+    Keys\"\xd2\x01\n\x12PEstimateGasResult\x12>\n\x06status\x18\x01\x20\x01(\
+    \x0e2&.top.tvm_engine.parameters.PStatusCodeR\x06status\x12\x1f\n\x0bsta\
+    tus_data\x18\x02\x20\x01(\x0cR\nstatusData\x12\x19\n\x08gas_used\x18\x03\
+    \x20\x01(\x04R\x07gasUsed\x12\x1b\n\tgas_limit\x18\x04\x20\x01(\x04R\x08\
+    gasLimit\x12#\n\rerror_message\x18\x05\x20\x01(\tR\x0cerrorMessage\"\x8b\
+    \x02\n\x11PAccessListResult\x12>\n\x06status\x18\x01\x20\x01(\x0e2&.top.\
+    tvm_engine.parameters.PStatusCodeR\x06status\x12\x1f\n\x0bstatus_data\
+    \x18\x02\x20\x01(\x0cR\nstatusData\x12\x19\n\x08gas_used\x18\x03\x20\x01\
+    (\x04R\x07gasUsed\x12U\n\x0baccess_list\x18\x04\x20\x03(\x0b24.top.tvm_e\
+    ngine.parameters.PCallArgs.PAccessListItemR\naccessList\x12#\n\rerror_me\
+    ssage\x18\x05\x20\x01(\tR\x0cerrorMessage\"\xfb\x01\n\x14PDeployAddressR\
+    esult\x12>\n\x06status\x18\x01\x20\x01(\x0e2&.top.tvm_engine.parameters.\
+    PStatusCodeR\x06status\x12\x1f\n\x0bstatus_data\x18\x02\x20\x01(\x0cR\ns\
+    tatusData\x12I\n\x10contract_address\x18\x03\x20\x01(\x0b2\x1e.top.tvm_e\
+    ngine.basic.PAddressR\x0fcontractAddress\x12\x12\n\x04salt\x18\x04\x20\
+    \x01(\x0cR\x04salt\x12#\n\rerror_message\x18\x05\x20\x01(\tR\x0cerrorMes\
+    sage\"\xd0\x01\n\rPForkSchedule\x12V\n\x0bactivations\x18\x01\x20\x03(\
+    \x0b24.top.tvm_engine.parameters.PForkSchedule.PActivationR\x0bactivatio\
+    ns\x1ag\n\x0bPActivation\x12\x16\n\x06height\x18\x01\x20\x01(\x04R\x06he\
+    ight\x12@\n\x08hardfork\x18\x02\x20\x01(\x0e2$.top.tvm_engine.parameters\
+    .PHardforkR\x08hardfork*\xb5\x05\n\x0bPStatusCode\x12\x0b\n\x07SUCCEED\
+    \x10\0\x12\n\n\x06REVERT\x10\x01\x12\x0e\n\nOUT_OF_GAS\x10\x02\x12\x0f\n\
+    \x0bOUT_OF_FUND\x10\x03\x12\x11\n\rOUT_OF_OFFSET\x10\x04\x12\x11\n\rNONC\
+    E_TOO_LOW\x10d\x12\x12\n\x0eNONCE_TOO_HIGH\x10e\x12\x15\n\x11CHAIN_ID_MI\
+    SMATCH\x10f\x12\x19\n\x15INTRINSIC_GAS_TOO_LOW\x10g\x12\x16\n\x12INSUFFI\
+    CIENT_FUNDS\x10h\x12\x17\n\x13INIT_CODE_TOO_LARGE\x10i\x12\x13\n\x0fFEE_\
+    CAP_TOO_LOW\x10j\x12\x15\n\x11TIP_ABOVE_FEE_CAP\x10k\x12\x17\n\x13SALT_T\
+    ABLE_MISMATCH\x10l\x12\x12\n\x0eMALFORMED_ARGS\x10m\x12\x14\n\x0fSTACK_U\
+    NDERFLOW\x10\xc8\x01\x12\x13\n\x0eSTACK_OVERFLOW\x10\xc9\x01\x12\x11\n\
+    \x0cINVALID_JUMP\x10\xca\x01\x12\x12\n\rINVALID_RANGE\x10\xcb\x01\x12\
+    \x17\n\x12DESIGNATED_INVALID\x10\xcc\x01\x12\x12\n\rCALL_TOO_DEEP\x10\
+    \xcd\x01\x12\x15\n\x10CREATE_COLLISION\x10\xce\x01\x12\x1a\n\x15CREATE_C\
+    ONTRACT_LIMIT\x10\xcf\x01\x12\x11\n\x0cINVALID_CODE\x10\xd0\x01\x12\x11\
+    \n\x0cPC_UNDERFLOW\x10\xd1\x01\x12\x11\n\x0cCREATE_EMPTY\x10\xd2\x01\x12\
+    \x14\n\x0fEVM_ERROR_OTHER\x10\xab\x02\x12\x12\n\rNOT_SUPPORTED\x10\xac\
+    \x02\x12\x18\n\x13UNHANDLED_INTERRUPT\x10\xad\x02\x12\x18\n\x13CALL_ERRO\
+    R_AS_FATAL\x10\xae\x02\x12\x14\n\x0fEVM_FATAL_OTHER\x10\x8f\x03\x12\x17\
+    \n\x12CROSS_TABLE_CREATE\x10\x90\x03*?\n\tPHardfork\x12\n\n\x06LONDON\
+    \x10\0\x12\x0c\n\x08FRONTIER\x10\x01\x12\x0c\n\x08ISTANBUL\x10\x02\x12\n\
+    \n\x06BERLIN\x10\x03J\x9f0\n\x07\x12\x05\0\0\x8e\x01\x01\n\x08\n\x01\x0c\
+    \x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x02\0%\n\x08\n\x01\x02\x12\x03\
+    \x04\0\"\ne\n\x02\x05\0\x12\x04\x07\00\x01\x1aY\x20Stable\x20code\x20of\
+    \x20every\x20outcome,\x20carried\x20in\x20the\x20`status`\x20of\x20the\
+    \x20results.\x20Never\x20renumbered.\n\n\n\n\x03\x05\0\x01\x12\x03\x07\
+    \x05\x10\n\x0b\n\x04\x05\0\x02\0\x12\x03\x08\x04\x10\n\x0c\n\x05\x05\0\
+    \x02\0\x01\x12\x03\x08\x04\x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x08\
+    \x0e\x0f\n0\n\x04\x05\0\x02\x01\x12\x03\t\x04\x0f\"#\x20status_data\x20h\
+    olds\x20the\x20revert\x20data\n\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\t\
+    \x04\n\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\t\r\x0e\n\x0b\n\x04\x05\0\
+    \x02\x02\x12\x03\n\x04\x13\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\n\x04\
+    \x0e\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\n\x11\x12\n\x0b\n\x04\x05\0\
+    \x02\x03\x12\x03\x0b\x04\x14\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x0b\
+    \x04\x0f\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x0b\x12\x13\n\x0b\n\x04\
+    \x05\0\x02\x04\x12\x03\x0c\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\
+    \x0c\x04\x11\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x0c\x14\x15\nG\n\x04\
+    \x05\0\x02\x05\x12\x03\x0f\x04\x18\x1a:\x20rejected\x20before\x20executi\
+    on,\x20nothing\x20is\x20charged\x20or\x20written\n\n\x0c\n\x05\x05\0\x02\
+    \x05\x01\x12\x03\x0f\x04\x11\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x0f\
+    \x14\x17\n\x0b\n\x04\x05\0\x02\x06\x12\x03\x10\x04\x19\n\x0c\n\x05\x05\0\
+    \x02\x06\x01\x12\x03\x10\x04\x12\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\
+    \x10\x15\x18\n\x0b\n\x04\x05\0\x02\x07\x12\x03\x11\x04\x1c\n\x0c\n\x05\
+    \x05\0\x02\x07\x01\x12\x03\x11\x04\x15\n\x0c\n\x05\x05\0\x02\x07\x02\x12\
+    \x03\x11\x18\x1b\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x12\x04\x20\n\x0c\n\
+    \x05\x05\0\x02\x08\x01\x12\x03\x12\x04\x19\n\x0c\n\x05\x05\0\x02\x08\x02\
+    \x12\x03\x12\x1c\x1f\n\x0b\n\x04\x05\0\x02\t\x12\x03\x13\x04\x1d\n\x0c\n\
+    \x05\x05\0\x02\t\x01\x12\x03\x13\x04\x16\n\x0c\n\x05\x05\0\x02\t\x02\x12\
+    \x03\x13\x19\x1c\n\x0b\n\x04\x05\0\x02\n\x12\x03\x14\x04\x1e\n\x0c\n\x05\
+    \x05\0\x02\n\x01\x12\x03\x14\x04\x17\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\
+    \x14\x1a\x1d\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x15\x04\x1a\n\x0c\n\x05\
+    \x05\0\x02\x0b\x01\x12\x03\x15\x04\x13\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\
+    \x03\x15\x16\x19\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x16\x04\x1c\n\x0c\n\
+    \x05\x05\0\x02\x0c\x01\x12\x03\x16\x04\x15\n\x0c\n\x05\x05\0\x02\x0c\x02\
+    \x12\x03\x16\x18\x1b\n\x0b\n\x04\x05\0\x02\r\x12\x03\x17\x04\x1e\n\x0c\n\
+    \x05\x05\0\x02\r\x01\x12\x03\x17\x04\x17\n\x0c\n\x05\x05\0\x02\r\x02\x12\
+    \x03\x17\x1a\x1d\n8\n\x04\x05\0\x02\x0e\x12\x03\x18\x04\x19\"+\x20a\x20f\
+    ield\x20of\x20PCallArgs\x20has\x20the\x20wrong\x20length\n\n\x0c\n\x05\
+    \x05\0\x02\x0e\x01\x12\x03\x18\x04\x12\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\
+    \x03\x18\x15\x18\nJ\n\x04\x05\0\x02\x0f\x12\x03\x1b\x04\x1a\x1a=\x20evm\
+    \x20errors,\x20gas_used\x20is\x20charged\x20and\x20nothing\x20else\x20is\
+    \x20written\n\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x1b\x04\x13\n\x0c\n\
+    \x05\x05\0\x02\x0f\x02\x12\x03\x1b\x16\x19\n\x0b\n\x04\x05\0\x02\x10\x12\
+    \x03\x1c\x04\x19\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03\x1c\x04\x12\n\x0c\
+    \n\x05\x05\0\x02\x10\x02\x12\x03\x1c\x15\x18\n\x0b\n\x04\x05\0\x02\x11\
+    \x12\x03\x1d\x04\x17\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03\x1d\x04\x10\n\
+    \x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x1d\x13\x16\n\x0b\n\x04\x05\0\x02\
+    \x12\x12\x03\x1e\x04\x18\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x1e\x04\
+    \x11\n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\x1e\x14\x17\n\x0b\n\x04\x05\0\
+    \x02\x13\x12\x03\x1f\x04\x1d\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x1f\
+    \x04\x16\n\x0c\n\x05\x05\0\x02\x13\x02\x12\x03\x1f\x19\x1c\n\x0b\n\x04\
+    \x05\0\x02\x14\x12\x03\x20\x04\x18\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\
+    \x20\x04\x11\n\x0c\n\x05\x05\0\x02\x14\x02\x12\x03\x20\x14\x17\n\x0b\n\
+    \x04\x05\0\x02\x15\x12\x03!\x04\x1b\n\x0c\n\x05\x05\0\x02\x15\x01\x12\
+    \x03!\x04\x14\n\x0c\n\x05\x05\0\x02\x15\x02\x12\x03!\x17\x1a\n\x0b\n\x04\
+    \x05\0\x02\x16\x12\x03\"\x04\x20\n\x0c\n\x05\x05\0\x02\x16\x01\x12\x03\"\
+    \x04\x19\n\x0c\n\x05\x05\0\x02\x16\x02\x12\x03\"\x1c\x1f\n\x0b\n\x04\x05\
+    \0\x02\x17\x12\x03#\x04\x17\n\x0c\n\x05\x05\0\x02\x17\x01\x12\x03#\x04\
+    \x10\n\x0c\n\x05\x05\0\x02\x17\x02\x12\x03#\x13\x16\n\x0b\n\x04\x05\0\
+    \x02\x18\x12\x03$\x04\x17\n\x0c\n\x05\x05\0\x02\x18\x01\x12\x03$\x04\x10\
+    \n\x0c\n\x05\x05\0\x02\x18\x02\x12\x03$\x13\x16\n\x0b\n\x04\x05\0\x02\
+    \x19\x12\x03%\x04\x17\n\x0c\n\x05\x05\0\x02\x19\x01\x12\x03%\x04\x10\n\
+    \x0c\n\x05\x05\0\x02\x19\x02\x12\x03%\x13\x16\n+\n\x04\x05\0\x02\x1a\x12\
+    \x03&\x04\x1a\"\x1e\x20error_message\x20holds\x20the\x20text\n\n\x0c\n\
+    \x05\x05\0\x02\x1a\x01\x12\x03&\x04\x13\n\x0c\n\x05\x05\0\x02\x1a\x02\
+    \x12\x03&\x16\x19\n\x1f\n\x04\x05\0\x02\x1b\x12\x03)\x04\x18\x1a\x12\x20\
+    evm\x20fatal\x20errors\n\n\x0c\n\x05\x05\0\x02\x1b\x01\x12\x03)\x04\x11\
+    \n\x0c\n\x05\x05\0\x02\x1b\x02\x12\x03)\x14\x17\n\x0b\n\x04\x05\0\x02\
+    \x1c\x12\x03*\x04\x1e\n\x0c\n\x05\x05\0\x02\x1c\x01\x12\x03*\x04\x17\n\
+    \x0c\n\x05\x05\0\x02\x1c\x02\x12\x03*\x1a\x1d\n\x0b\n\x04\x05\0\x02\x1d\
+    \x12\x03+\x04\x1e\n\x0c\n\x05\x05\0\x02\x1d\x01\x12\x03+\x04\x17\n\x0c\n\
+    \x05\x05\0\x02\x1d\x02\x12\x03+\x1a\x1d\n+\n\x04\x05\0\x02\x1e\x12\x03,\
+    \x04\x1a\"\x1e\x20error_message\x20holds\x20the\x20text\n\n\x0c\n\x05\
+    \x05\0\x02\x1e\x01\x12\x03,\x04\x13\n\x0c\n\x05\x05\0\x02\x1e\x02\x12\
+    \x03,\x16\x19\n\x1e\n\x04\x05\0\x02\x1f\x12\x03/\x04\x1d\x1a\x11\x20engi\
+    ne\x20policies\n\n\x0c\n\x05\x05\0\x02\x1f\x01\x12\x03/\x04\x16\n\x0c\n\
+    \x05\x05\0\x02\x1f\x02\x12\x03/\x19\x1c\n\n\n\x02\x04\0\x12\x042\0H\x01\
+    \n\n\n\x03\x04\0\x01\x12\x032\x08\x15\n\x0c\n\x04\x04\0\x03\0\x12\x043\
+    \x045\x05\n\x0c\n\x05\x04\0\x03\0\x01\x12\x033\x0c\x11\n+\n\x06\x04\0\
+    \x03\0\x02\0\x12\x034\x08\x17\"\x1c\x20use\x20bytes\x20=\x20U256\x20=\
+    \x20[u8;32]\n\n\x0e\n\x07\x04\0\x03\0\x02\0\x05\x12\x034\x08\r\n\x0e\n\
+    \x07\x04\0\x03\0\x02\0\x01\x12\x034\x0e\x12\n\x0e\n\x07\x04\0\x03\0\x02\
+    \0\x03\x12\x034\x15\x16\n\x0c\n\x04\x04\0\x03\x01\x12\x047\x04;\x05\n\
+    \x0c\n\x05\x04\0\x03\x01\x01\x12\x037\x0c\x10\n\r\n\x06\x04\0\x03\x01\
+    \x02\0\x12\x038\x08#\n\x0e\n\x07\x04\0\x03\x01\x02\0\x06\x12\x038\x08\
+    \x16\n\x0e\n\x07\x04\0\x03\x01\x02\0\x01\x12\x038\x17\x1e\n\x0e\n\x07\
+    \x04\0\x03\x01\x02\0\x03\x12\x038!\"\n\r\n\x06\x04\0\x03\x01\x02\x01\x12\
+    \x039\x08\"\n\x0e\n\x07\x04\0\x03\x01\x02\x01\x04\x12\x039\x08\x10\n\x0e\
+    \n\x07\x04\0\x03\x01\x02\x01\x06\x12\x039\x11\x16\n\x0e\n\x07\x04\0\x03\
+    \x01\x02\x01\x01\x12\x039\x17\x1d\n\x0e\n\x07\x04\0\x03\x01\x02\x01\x03\
+    \x12\x039\x20!\n\r\n\x06\x04\0\x03\x01\x02\x02\x12\x03:\x08\x17\n\x0e\n\
+    \x07\x04\0\x03\x01\x02\x02\x05\x12\x03:\x08\r\n\x0e\n\x07\x04\0\x03\x01\
+    \x02\x02\x01\x12\x03:\x0e\x12\n\x0e\n\x07\x04\0\x03\x01\x02\x02\x03\x12\
+    \x03:\x15\x16\n\x0b\n\x04\x04\0\x02\0\x12\x03=\x04\x1b\n\x0c\n\x05\x04\0\
+    \x02\0\x06\x12\x03=\x04\x0f\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03=\x10\x16\
+    \n\x0c\n\x05\x04\0\x02\0\x03\x12\x03=\x19\x1a\nK\n\x04\x04\0\x02\x01\x12\
+    \x03>\x04\x1a\">\x20call\x20output\x20or\x20revert\x20data,\x20empty\x20\
+    for\x20successful\x20deployments\n\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\
+    >\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03>\n\x15\n\x0c\n\x05\x04\0\
+    \x02\x01\x03\x12\x03>\x18\x19\n\x0b\n\x04\x04\0\x02\x02\x12\x03?\x04\x18\
+    \n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03?\x04\n\n\x0c\n\x05\x04\0\x02\x02\
+    \x01\x12\x03?\x0b\x13\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03?\x16\x17\n\
+    \x0b\n\x04\x04\0\x02\x03\x12\x03@\x04\x1b\n\x0c\n\x05\x04\0\x02\x03\x04\
+    \x12\x03@\x04\x0c\n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03@\r\x11\n\x0c\n\
+    \x05\x04\0\x02\x03\x01\x12\x03@\x12\x16\n\x0c\n\x05\x04\0\x02\x03\x03\
+    \x12\x03@\x19\x1a\nH\n\x04\x04\0\x02\x04\x12\x03A\x04#\";\x20wei\x20per\
+    \x20gas\x20the\x20sender\x20paid,\x20u64\x20max\x20for\x20any\x20higher\
+    \x20price\n\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03A\x04\n\n\x0c\n\x05\x04\
+    \0\x02\x04\x01\x12\x03A\x0b\x1e\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03A!\
+    \"\nt\n\x04\x04\0\x02\x05\x12\x03C\x04(\x1ag\x20set\x20by\x20successful\
+    \x20deployments\x20only,\x20the\x20deployed\x20runtime\x20code\x20is\x20\
+    described\x20by\x20code_hash\x20and\x20code_size\n\n\x0c\n\x05\x04\0\x02\
+    \x05\x06\x12\x03C\x04\x12\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03C\x13#\n\
+    \x0c\n\x05\x04\0\x02\x05\x03\x12\x03C&'\n)\n\x04\x04\0\x02\x06\x12\x03D\
+    \x04\x18\"\x1c\x20use\x20bytes\x20=\x20H256\x20=\x20[u8;32]\n\n\x0c\n\
+    \x05\x04\0\x02\x06\x05\x12\x03D\x04\t\n\x0c\n\x05\x04\0\x02\x06\x01\x12\
+    \x03D\n\x13\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03D\x16\x17\n\x0b\n\x04\
+    \x04\0\x02\x07\x12\x03E\x04\x19\n\x0c\n\x05\x04\0\x02\x07\x05\x12\x03E\
+    \x04\n\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03E\x0b\x14\n\x0c\n\x05\x04\0\
+    \x02\x07\x03\x12\x03E\x17\x18\nK\n\x04\x04\0\x02\x08\x12\x03F\x04\x13\">\
+    \x20use\x20bytes\x20=\x20H256\x20=\x20[u8;32],\x20CREATE2\x20salt\x20of\
+    \x20contract_address\n\n\x0c\n\x05\x04\0\x02\x08\x05\x12\x03F\x04\t\n\
+    \x0c\n\x05\x04\0\x02\x08\x01\x12\x03F\n\x0e\n\x0c\n\x05\x04\0\x02\x08\
+    \x03\x12\x03F\x11\x12\n/\n\x04\x04\0\x02\t\x12\x03G\x04\x1e\"\"\x20human\
+    \x20readable,\x20empty\x20on\x20success\n\n\x0c\n\x05\x04\0\x02\t\x05\
+    \x12\x03G\x04\n\n\x0c\n\x05\x04\0\x02\t\x01\x12\x03G\x0b\x18\n\x0c\n\x05\
+    \x04\0\x02\t\x03\x12\x03G\x1b\x1d\n(\n\x02\x04\x01\x12\x04K\0N\x01\x1a\
+    \x1c\x20output\x20of\x20`call_with_diff`\n\n\n\n\x03\x04\x01\x01\x12\x03\
+    K\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03L\x04\x1d\n\x0c\n\x05\x04\x01\
+    \x02\0\x06\x12\x03L\x04\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03L\x12\
+    \x18\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03L\x1b\x1c\nY\n\x04\x04\x01\x02\
+    \x01\x12\x03M\x04\x1a\"L\x20json\x20of\x20every\x20account\x20change\x20\
+    the\x20transaction\x20wrote,\x20see\x20StateDiff::to_json\n\n\x0c\n\x05\
+    \x04\x01\x02\x01\x05\x12\x03M\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\
+    \x03M\x0b\x15\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03M\x18\x19\n\n\n\x02\
+    \x04\x02\x12\x04P\0d\x01\n\n\n\x03\x04\x02\x01\x12\x03P\x08\x11\n\x0c\n\
+    \x04\x04\x02\x03\0\x12\x04Q\x04T\x05\n\x0c\n\x05\x04\x02\x03\0\x01\x12\
+    \x03Q\x0c\x1b\n\r\n\x06\x04\x02\x03\0\x02\0\x12\x03R\x08#\n\x0e\n\x07\
+    \x04\x02\x03\0\x02\0\x06\x12\x03R\x08\x16\n\x0e\n\x07\x04\x02\x03\0\x02\
+    \0\x01\x12\x03R\x17\x1e\n\x0e\n\x07\x04\x02\x03\0\x02\0\x03\x12\x03R!\"\
+    \n+\n\x06\x04\x02\x03\0\x02\x01\x12\x03S\x08(\"\x1c\x20use\x20bytes\x20=\
+    \x20H256\x20=\x20[u8;32]\n\n\x0e\n\x07\x04\x02\x03\0\x02\x01\x04\x12\x03\
+    S\x08\x10\n\x0e\n\x07\x04\x02\x03\0\x02\x01\x05\x12\x03S\x11\x16\n\x0e\n\
+    \x07\x04\x02\x03\0\x02\x01\x01\x12\x03S\x17#\n\x0e\n\x07\x04\x02\x03\0\
+    \x02\x01\x03\x12\x03S&'\n\x0b\n\x04\x04\x02\x02\0\x12\x03V\x04&\n\x0c\n\
+    \x05\x04\x02\x02\0\x06\x12\x03V\x04\x12\n\x0c\n\x05\x04\x02\x02\0\x01\
+    \x12\x03V\x13!\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03V$%\n\x0b\n\x04\x04\
+    \x02\x02\x01\x12\x03W\x04&\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03W\x04\
+    \x12\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03W\x13!\n\x0c\n\x05\x04\x02\
+    \x02\x01\x03\x12\x03W$%\n\x0b\n\x04\x04\x02\x02\x02\x12\x03X\x04\x15\n\
+    \x0c\n\x05\x04\x02\x02\x02\x05\x12\x03X\x04\n\n\x0c\n\x05\x04\x02\x02\
+    \x02\x01\x12\x03X\x0b\x10\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03X\x13\
+    \x14\n\x0b\n\x04\x04\x02\x02\x03\x12\x03Y\x04\x14\n\x0c\n\x05\x04\x02\
+    \x02\x03\x05\x12\x03Y\x04\t\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03Y\n\
+    \x0f\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03Y\x12\x13\n\x0b\n\x04\x04\
+    \x02\x02\x04\x12\x03Z\x04\x19\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x03Z\
+    \x04\n\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03Z\x0b\x14\n\x0c\n\x05\x04\
+    \x02\x02\x04\x03\x12\x03Z\x17\x18\n\x86\x01\n\x04\x04\x02\x02\x05\x12\
+    \x03]\x04\x19\x1ay\x20fees\x20in\x20wei\x20per\x20gas:\x20EIP-1559\x20wh\
+    en\x20max_fee_per_gas\x20is\x20set,\x20legacy\x20gas_price\x20otherwise,\
+    \n\x20Env::gas_price\x20when\x20none\x20is\x20set\n\n\x0c\n\x05\x04\x02\
+    \x02\x05\x05\x12\x03]\x04\n\n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x03]\x0b\
+    \x14\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03]\x17\x18\n\x0b\n\x04\x04\
+    \x02\x02\x06\x12\x03^\x04\x1f\n\x0c\n\x05\x04\x02\x02\x06\x05\x12\x03^\
+    \x04\n\n\x0c\n\x05\x04\x02\x02\x06\x01\x12\x03^\x0b\x1a\n\x0c\n\x05\x04\
+    \x02\x02\x06\x03\x12\x03^\x1d\x1e\n\x0b\n\x04\x04\x02\x02\x07\x12\x03_\
+    \x04(\n\x0c\n\x05\x04\x02\x02\x07\x05\x12\x03_\x04\n\n\x0c\n\x05\x04\x02\
+    \x02\x07\x01\x12\x03_\x0b#\n\x0c\n\x05\x04\x02\x02\x07\x03\x12\x03_&'\n0\
+    \n\x04\x04\x02\x02\x08\x12\x03`\x04-\"#\x20EIP-2930,\x20warmed\x20before\
+    \x20execution\n\n\x0c\n\x05\x04\x02\x02\x08\x04\x12\x03`\x04\x0c\n\x0c\n\
+    \x05\x04\x02\x02\x08\x06\x12\x03`\r\x1c\n\x0c\n\x05\x04\x02\x02\x08\x01\
+    \x12\x03`\x1d(\n\x0c\n\x05\x04\x02\x02\x08\x03\x12\x03`+,\n1\n\x04\x04\
+    \x02\x02\t\x12\x03a\x04#\"$\x20sender\x20nonce,\x20unchecked\x20when\x20\
+    unset\n\n\x0c\n\x05\x04\x02\x02\t\x06\x12\x03a\x04\x17\n\x0c\n\x05\x04\
+    \x02\x02\t\x01\x12\x03a\x18\x1d\n\x0c\n\x05\x04\x02\x02\t\x03\x12\x03a\
+    \x20\"\n\x1f\n\x04\x04\x02\x02\n\x12\x03b\x04\x19\"\x12\x20unchecked\x20\
+    when\x200\n\n\x0c\n\x05\x04\x02\x02\n\x05\x12\x03b\x04\n\n\x0c\n\x05\x04\
+    \x02\x02\n\x01\x12\x03b\x0b\x13\n\x0c\n\x05\x04\x02\x02\n\x03\x12\x03b\
+    \x16\x18\ni\n\x04\x04\x02\x02\x0b\x12\x03c\x04\x14\"\\\x20use\x20bytes\
+    \x20=\x20H256\x20=\x20[u8;32],\x20CREATE2\x20salt\x20of\x20deployments,\
+    \x20derived\x20from\x20the\x20nonce\x20when\x20empty\n\n\x0c\n\x05\x04\
+    \x02\x02\x0b\x05\x12\x03c\x04\t\n\x0c\n\x05\x04\x02\x02\x0b\x01\x12\x03c\
+    \n\x0e\n\x0c\n\x05\x04\x02\x02\x0b\x03\x12\x03c\x11\x13\n\n\n\x02\x04\
+    \x03\x12\x04f\0l\x01\n\n\n\x03\x04\x03\x01\x12\x03f\x08\x1a\n\x0b\n\x04\
+    \x04\x03\x02\0\x12\x03g\x04\x1b\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03g\
+    \x04\x0f\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03g\x10\x16\n\x0c\n\x05\x04\
+    \x03\x02\0\x03\x12\x03g\x19\x1a\n\x0b\n\x04\x04\x03\x02\x01\x12\x03h\x04\
+    \x1a\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03h\x04\t\n\x0c\n\x05\x04\x03\
+    \x02\x01\x01\x12\x03h\n\x15\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03h\x18\
+    \x19\n\x0b\n\x04\x04\x03\x02\x02\x12\x03i\x04\x18\n\x0c\n\x05\x04\x03\
+    \x02\x02\x05\x12\x03i\x04\n\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03i\x0b\
+    \x13\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03i\x16\x17\n7\n\x04\x04\x03\
+    \x02\x03\x12\x03j\x04\x19\"*\x20minimal\x20gas_limit\x20the\x20call\x20s\
+    ucceeds\x20with\n\n\x0c\n\x05\x04\x03\x02\x03\x05\x12\x03j\x04\n\n\x0c\n\
+    \x05\x04\x03\x02\x03\x01\x12\x03j\x0b\x14\n\x0c\n\x05\x04\x03\x02\x03\
+    \x03\x12\x03j\x17\x18\n\x0b\n\x04\x04\x03\x02\x04\x12\x03k\x04\x1d\n\x0c\
+    \n\x05\x04\x03\x02\x04\x05\x12\x03k\x04\n\n\x0c\n\x05\x04\x03\x02\x04\
+    \x01\x12\x03k\x0b\x18\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03k\x1b\x1c\n\
+    \n\n\x02\x04\x04\x12\x04n\0t\x01\n\n\n\x03\x04\x04\x01\x12\x03n\x08\x19\
+    \n\x0b\n\x04\x04\x04\x02\0\x12\x03o\x04\x1b\n\x0c\n\x05\x04\x04\x02\0\
+    \x06\x12\x03o\x04\x0f\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03o\x10\x16\n\
+    \x0c\n\x05\x04\x04\x02\0\x03\x12\x03o\x19\x1a\n\x0b\n\x04\x04\x04\x02\
+    \x01\x12\x03p\x04\x1a\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03p\x04\t\n\
+    \x0c\n\x05\x04\x04\x02\x01\x01\x12\x03p\n\x15\n\x0c\n\x05\x04\x04\x02\
+    \x01\x03\x12\x03p\x18\x19\n(\n\x04\x04\x04\x02\x02\x12\x03q\x04\x18\"\
+    \x1b\x20with\x20access_list\x20attached\n\n\x0c\n\x05\x04\x04\x02\x02\
+    \x05\x12\x03q\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03q\x0b\x13\n\
+    \x0c\n\x05\x04\x04\x02\x02\x03\x12\x03q\x16\x17\n\x0b\n\x04\x04\x04\x02\
+    \x03\x12\x03r\x047\n\x0c\n\x05\x04\x04\x02\x03\x04\x12\x03r\x04\x0c\n\
+    \x0c\n\x05\x04\x04\x02\x03\x06\x12\x03r\r&\n\x0c\n\x05\x04\x04\x02\x03\
+    \x01\x12\x03r'2\n\x0c\n\x05\x04\x04\x02\x03\x03\x12\x03r56\n\x0b\n\x04\
+    \x04\x04\x02\x04\x12\x03s\x04\x1d\n\x0c\n\x05\x04\x04\x02\x04\x05\x12\
+    \x03s\x04\n\n\x0c\n\x05\x04\x04\x02\x04\x01\x12\x03s\x0b\x18\n\x0c\n\x05\
+    \x04\x04\x02\x04\x03\x12\x03s\x1b\x1c\n\n\n\x02\x04\x05\x12\x04v\0|\x01\
+    \n\n\n\x03\x04\x05\x01\x12\x03v\x08\x1c\n\x0b\n\x04\x04\x05\x02\0\x12\
+    \x03w\x04\x1b\n\x0c\n\x05\x04\x05\x02\0\x06\x12\x03w\x04\x0f\n\x0c\n\x05\
+    \x04\x05\x02\0\x01\x12\x03w\x10\x16\n\x0c\n\x05\x04\x05\x02\0\x03\x12\
+    \x03w\x19\x1a\n\x0b\n\x04\x04\x05\x02\x01\x12\x03x\x04\x1a\n\x0c\n\x05\
+    \x04\x05\x02\x01\x05\x12\x03x\x04\t\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\
+    \x03x\n\x15\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03x\x18\x19\n\x0b\n\x04\
+    \x04\x05\x02\x02\x12\x03y\x04(\n\x0c\n\x05\x04\x05\x02\x02\x06\x12\x03y\
+    \x04\x12\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03y\x13#\n\x0c\n\x05\x04\
+    \x05\x02\x02\x03\x12\x03y&'\n)\n\x04\x04\x05\x02\x03\x12\x03z\x04\x13\"\
+    \x1c\x20use\x20bytes\x20=\x20H256\x20=\x20[u8;32]\n\n\x0c\n\x05\x04\x05\
+    \x02\x03\x05\x12\x03z\x04\t\n\x0c\n\x05\x04\x05\x02\x03\x01\x12\x03z\n\
+    \x0e\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x03z\x11\x12\n\x0b\n\x04\x04\
+    \x05\x02\x04\x12\x03{\x04\x1d\n\x0c\n\x05\x04\x05\x02\x04\x05\x12\x03{\
+    \x04\n\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\x03{\x0b\x18\n\x0c\n\x05\x04\
+    \x05\x02\x04\x03\x12\x03{\x1b\x1c\n/\n\x02\x05\x01\x12\x05\x7f\0\x84\x01\
+    \x01\x1a\"\x20EVM\x20rule\x20sets\x20of\x20`PForkSchedule`\n\n\n\n\x03\
+    \x05\x01\x01\x12\x03\x7f\x05\x0e\n5\n\x04\x05\x01\x02\0\x12\x04\x80\x01\
+    \x04\x0f\"'\x20TOP's\x20rules\x20since\x20its\x20first\x20EVM\x20block\n\
+    \n\r\n\x05\x05\x01\x02\0\x01\x12\x04\x80\x01\x04\n\n\r\n\x05\x05\x01\x02\
+    \0\x02\x12\x04\x80\x01\r\x0e\n\x0c\n\x04\x05\x01\x02\x01\x12\x04\x81\x01\
+    \x04\x11\n\r\n\x05\x05\x01\x02\x01\x01\x12\x04\x81\x01\x04\x0c\n\r\n\x05\
+    \x05\x01\x02\x01\x02\x12\x04\x81\x01\x0f\x10\n\x0c\n\x04\x05\x01\x02\x02\
+    \x12\x04\x82\x01\x04\x11\n\r\n\x05\x05\x01\x02\x02\x01\x12\x04\x82\x01\
+    \x04\x0c\n\r\n\x05\x05\x01\x02\x02\x02\x12\x04\x82\x01\x0f\x10\n\x0c\n\
+    \x04\x05\x01\x02\x03\x12\x04\x83\x01\x04\x0f\n\r\n\x05\x05\x01\x02\x03\
+    \x01\x12\x04\x83\x01\x04\n\n\r\n\x05\x05\x01\x02\x03\x02\x12\x04\x83\x01\
+    \r\x0e\nc\n\x02\x04\x06\x12\x06\x87\x01\0\x8e\x01\x01\x1aU\x20input\x20o\
+    f\x20`set_fork_schedule`,\x20London\x20from\x20height\x200\x20unless\x20\
+    an\x20activation\x20replaces\x20it\n\n\x0b\n\x03\x04\x06\x01\x12\x04\x87\
+    \x01\x08\x15\n\x0e\n\x04\x04\x06\x03\0\x12\x06\x88\x01\x04\x8b\x01\x05\n\
+    \r\n\x05\x04\x06\x03\0\x01\x12\x04\x88\x01\x0c\x17\n\x0e\n\x06\x04\x06\
+    \x03\0\x02\0\x12\x04\x89\x01\x08\x1a\n\x0f\n\x07\x04\x06\x03\0\x02\0\x05\
+    \x12\x04\x89\x01\x08\x0e\n\x0f\n\x07\x04\x06\x03\0\x02\0\x01\x12\x04\x89\
+    \x01\x0f\x15\n\x0f\n\x07\x04\x06\x03\0\x02\0\x03\x12\x04\x89\x01\x18\x19\
+    \n\x0e\n\x06\x04\x06\x03\0\x02\x01\x12\x04\x8a\x01\x08\x1f\n\x0f\n\x07\
+    \x04\x06\x03\0\x02\x01\x06\x12\x04\x8a\x01\x08\x11\n\x0f\n\x07\x04\x06\
+    \x03\0\x02\x01\x01\x12\x04\x8a\x01\x12\x1a\n\x0f\n\x07\x04\x06\x03\0\x02\
+    \x01\x03\x12\x04\x8a\x01\x1d\x1e\n\x0c\n\x04\x04\x06\x02\0\x12\x04\x8d\
+    \x01\x04)\n\r\n\x05\x04\x06\x02\0\x04\x12\x04\x8d\x01\x04\x0c\n\r\n\x05\
+    \x04\x06\x02\0\x06\x12\x04\x8d\x01\r\x18\n\r\n\x05\x04\x06\x02\0\x01\x12\
+    \x04\x8d\x01\x19$\n\r\n\x05\x04\x06\x02\0\x03\x12\x04\x8d\x01'(b\x06prot\
+    o3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -772,12 +2677,21 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(1);
             deps.push(super::pbasic::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(4);
+            let mut messages = ::std::vec::Vec::with_capacity(11);
             messages.push(PReturnResult::generated_message_descriptor_data());
+            messages.push(PStateDiffResult::generated_message_descriptor_data());
             messages.push(PCallArgs::generated_message_descriptor_data());
+            messages.push(PEstimateGasResult::generated_message_descriptor_data());
+            messages.push(PAccessListResult::generated_message_descriptor_data());
+            messages.push(PDeployAddressResult::generated_message_descriptor_data());
+            messages.push(PForkSchedule::generated_message_descriptor_data());
             messages.push(preturn_result::PU256::generated_message_descriptor_data());
             messages.push(preturn_result::PLog::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(0);
+            messages.push(pcall_args::PAccessListItem::generated_message_descriptor_data());
+            messages.push(pfork_schedule::PActivation::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(2);
+            enums.push(PStatusCode::generated_enum_descriptor_data());
+            enums.push(PHardfork::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
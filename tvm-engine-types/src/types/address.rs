@@ -1,3 +1,7 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::shard::{AccountKind, ShardId};
 use crate::PAddress;
 
 use primitive_types::H160;
@@ -7,20 +11,38 @@ pub struct Address(H160);
 
 /// account address, should be compatible with both `0x` and `T80000` prefix
 impl Address {
+    const TOP_T8_PREFIX: &'static str = "T80000";
+
     /// Build from H160
     pub const fn build_from_hash160(val: H160) -> Self {
         Self(val)
     }
 
-    /// Build(decode) from str
+    /// Build(decode) from str, either bare hex, `0x` or `T80000` prefixed.
+    /// Mixed-case hex must carry a valid EIP-55 checksum.
     pub fn build_from_str(val: &str) -> Result<Self, error::AddressError> {
-        if val.len() != 40 {
+        let hex_str = if let Some(hex_str) = val.strip_prefix("0x").or_else(|| val.strip_prefix("0X")) {
+            hex_str
+        } else if let Some(hex_str) = val.strip_prefix(Self::TOP_T8_PREFIX) {
+            hex_str
+        } else if val.len() > 40 && val.starts_with('T') {
+            return Err(error::AddressError::UnsupportedPrefix);
+        } else {
+            val
+        };
+        if hex_str.len() != 40 {
             return Err(error::AddressError::IncorrectLength);
         }
 
         let mut res = [0u8; 20];
-        hex::decode_to_slice(val, &mut res).map_err(|_| error::AddressError::DecodeFailure)?;
-        Ok(Self::build_from_hash160(H160(res)))
+        hex::decode_to_slice(hex_str, &mut res).map_err(|_| error::AddressError::DecodeFailure)?;
+        let address = Self::build_from_hash160(H160(res));
+        let has_lower = hex_str.bytes().any(|b| b.is_ascii_lowercase());
+        let has_upper = hex_str.bytes().any(|b| b.is_ascii_uppercase());
+        if has_lower && has_upper && address.to_checksum_string()[2..] != *hex_str {
+            return Err(error::AddressError::InvalidChecksum);
+        }
+        Ok(address)
     }
 
     /// Build from slice
@@ -49,15 +71,62 @@ impl Address {
         self.0.as_bytes()
     }
 
+    /// Bare lowercase hex, without prefix.
     pub fn encode(&self) -> String {
         hex::encode(self.as_slice())
     }
 
+    /// `0x` prefixed lowercase hex.
+    pub fn to_hex_string(&self) -> String {
+        format!("0x{}", self.encode())
+    }
+
+    /// `0x` prefixed EIP-55 mixed-case checksum form.
+    pub fn to_checksum_string(&self) -> String {
+        use sha3::Digest;
+        let lower = self.encode();
+        let hash = sha3::Keccak256::digest(lower.as_bytes());
+        let checksummed: String = lower
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect();
+        format!("0x{}", checksummed)
+    }
+
+    /// TOP T8 form: `T80000` prefixed lowercase hex. This is the canonical text form, see `Display`.
+    pub fn to_top_string(&self) -> String {
+        format!("{}{}", Self::TOP_T8_PREFIX, self.encode())
+    }
+
+    /// TOP account string of this address as account type `kind` in the default ledger (`0000`).
+    /// `None` for the kinds whose body is not the hex address.
+    pub fn to_top_account(&self, kind: AccountKind) -> Option<String> {
+        kind.has_hex_body()
+            .then(|| format!("T{}0000{}", kind.as_char(), self.encode()))
+    }
+
     /// Top T8 address, use T80000 prefix than do xxhash64 (NOT xxh3) on hex-string address.
     /// Get a u64 value x, tableid = x & 63
     pub fn get_top_address_tableid(&self) -> u8 {
-        // let top_address = format!("T80000{}", self.encode());
-        (xxhash_rust::const_xxh64::xxh64([b"T80000", self.encode().as_bytes()].concat().as_slice(), 0) & 63) as u8
+        ShardId::table_of(&self.to_top_string())
+    }
+
+    /// Book of the T8 account, see [`ShardId`].
+    pub fn get_top_address_bookid(&self) -> u8 {
+        ShardId::book_of_subaddr(ShardId::subaddr_of(&self.to_top_string()))
+    }
+
+    /// Every shard coordinate of this address as account type `kind`, `None` as for `to_top_account`.
+    pub fn top_shard_id(&self, kind: AccountKind) -> Option<ShardId> {
+        ShardId::from_account(&self.to_top_account(kind)?).ok()
     }
 }
 
@@ -67,13 +136,47 @@ impl Default for Address {
     }
 }
 
+impl FromStr for Address {
+    type Err = error::AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::build_from_str(s)
+    }
+}
+
+/// Canonical text form, the TOP T8 `T80000<hex>` string.
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_top_string())
+    }
+}
+
 pub mod error {
+    use std::fmt;
 
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub enum AddressError {
         IncorrectLength,
         DecodeFailure,
+        /// mixed-case hex that does not match its EIP-55 checksum
+        InvalidChecksum,
+        /// a TOP address of another account type than T8 (`T80000`)
+        UnsupportedPrefix,
+    }
+
+    impl fmt::Display for AddressError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let msg = match self {
+                Self::IncorrectLength => "incorrect address length",
+                Self::DecodeFailure => "invalid hex in address",
+                Self::InvalidChecksum => "invalid EIP-55 address checksum",
+                Self::UnsupportedPrefix => "unsupported address prefix",
+            };
+            f.write_str(msg)
+        }
     }
+
+    impl std::error::Error for AddressError {}
 }
 
 impl From<&PAddress> for Address {
@@ -117,7 +220,14 @@ mod tests {
 
     #[test]
     fn test_wrong_length_addres() {
-        todo!()
+        assert_eq!(
+            Address::build_from_str("7156526fbd7a3c72969b54f64e42c10fbb768c").unwrap_err(),
+            error::AddressError::IncorrectLength
+        );
+        assert_eq!(
+            Address::build_from_slice(&[0u8; 21]).unwrap_err(),
+            error::AddressError::IncorrectLength
+        );
     }
 
     #[test]
@@ -145,4 +255,154 @@ mod tests {
             4
         );
     }
+
+    #[test]
+    fn test_address_string_forms() {
+        let addr = Address::build_from_str("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+        assert_eq!(addr.to_hex_string(), "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+        assert_eq!(addr.to_checksum_string(), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+        assert_eq!(addr.to_top_string(), "T800005aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+        assert_eq!(addr.to_string(), addr.to_top_string());
+        for s in [
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+            "0X5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "T800005aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+        ] {
+            assert_eq!(s.parse::<Address>(), Ok(addr));
+        }
+        assert_eq!(
+            Address::build_from_str("0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb")
+                .unwrap()
+                .to_checksum_string(),
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb"
+        );
+    }
+
+    #[test]
+    fn test_address_parse_errors() {
+        assert_eq!(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".parse::<Address>(),
+            Err(error::AddressError::InvalidChecksum)
+        );
+        assert_eq!(
+            "T200005aaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse::<Address>(),
+            Err(error::AddressError::UnsupportedPrefix)
+        );
+        assert_eq!(
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea".parse::<Address>(),
+            Err(error::AddressError::IncorrectLength)
+        );
+        assert_eq!(
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beazz".parse::<Address>(),
+            Err(error::AddressError::DecodeFailure)
+        );
+    }
+
+    #[test]
+    fn test_top_shard_id() {
+        let addr = Address::build_from_str("56d9407e0ae1246a2aafcfa57f3fc1bd7023df81").unwrap();
+        let shard = addr.top_shard_id(AccountKind::T8).unwrap();
+        assert_eq!(
+            shard,
+            ShardId {
+                kind: AccountKind::T8,
+                chain_id: 0,
+                zone: 0,
+                subaddr: 388,
+                book: 48,
+                table: 4,
+            }
+        );
+        assert_eq!(addr.get_top_address_bookid(), 48);
+        assert_eq!(
+            addr.to_top_account(AccountKind::T6).unwrap(),
+            "T6000056d9407e0ae1246a2aafcfa57f3fc1bd7023df81"
+        );
+        assert_eq!(addr.to_top_account(AccountKind::T0), None);
+        assert_eq!(addr.top_shard_id(AccountKind::T6).unwrap().table, 14);
+    }
+
+    #[test]
+    fn test_top_shard_id_of_accounts() {
+        // xxh64 of each account string, the first as in the original `get_top_address_tableid` test and the
+        // others from a separate XXH64 implementation. subaddress = hash & 1023, book = subaddress >> 3, table = hash & 63
+        for (account, hash, kind, chain_id, zone, subaddr, book, table) in [
+            (
+                "T8000056d9407e0ae1246a2aafcfa57f3fc1bd7023df81",
+                13715836294981773700,
+                AccountKind::T8,
+                0,
+                0,
+                388,
+                48,
+                4,
+            ),
+            (
+                "T800005aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+                13289660868628190539,
+                AccountKind::T8,
+                0,
+                0,
+                331,
+                41,
+                11,
+            ),
+            (
+                "T6000056d9407e0ae1246a2aafcfa57f3fc1bd7023df81",
+                5319286197880334478,
+                AccountKind::T6,
+                0,
+                0,
+                142,
+                17,
+                14,
+            ),
+            (
+                "T00000LfhWJA5JPcKPJovoBVtN4seYnnsVjx2VuB",
+                1881682313729408271,
+                AccountKind::T0,
+                0,
+                0,
+                271,
+                33,
+                15,
+            ),
+            (
+                "T20013Ldd3XgW5yMfJPMVXPK8jsYNDsqv8SuFVZ1",
+                4356737693785247209,
+                AccountKind::T2,
+                1,
+                3,
+                489,
+                61,
+                41,
+            ),
+        ] {
+            assert_eq!(xxhash_rust::const_xxh64::xxh64(account.as_bytes(), 0), hash);
+            assert_eq!(
+                ShardId::from_account(account).unwrap(),
+                ShardId {
+                    kind,
+                    chain_id,
+                    zone,
+                    subaddr,
+                    book,
+                    table
+                }
+            );
+        }
+        assert_eq!(
+            ShardId::from_account("T90000abc").unwrap_err(),
+            error::AddressError::UnsupportedPrefix
+        );
+        assert_eq!(
+            ShardId::from_account("T80000").unwrap_err(),
+            error::AddressError::IncorrectLength
+        );
+        assert_eq!(
+            ShardId::from_account("T8000g56d9").unwrap_err(),
+            error::AddressError::DecodeFailure
+        );
+    }
 }
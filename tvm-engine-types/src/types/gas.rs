@@ -1,6 +1,11 @@
+use std::fmt;
+use std::num::ParseIntError;
 use std::ops::{Add, AddAssign, Div, Mul};
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// Amount of gas. The operators saturate at `u64::MAX` so that a cost computed from an
+/// untrusted input length turns into an out of gas error instead of wrapping around.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Gas(u64);
 
 impl Gas {
@@ -10,19 +15,63 @@ impl Gas {
     pub fn as_u64(self) -> u64 {
         self.0
     }
+
+    pub fn checked_add(self, rhs: Gas) -> Option<Gas> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    pub fn checked_sub(self, rhs: Gas) -> Option<Gas> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    pub fn checked_mul(self, rhs: u64) -> Option<Gas> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    pub fn saturating_add(self, rhs: Gas) -> Gas {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    pub fn saturating_sub(self, rhs: Gas) -> Gas {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    pub fn saturating_mul(self, rhs: u64) -> Gas {
+        Self(self.0.saturating_mul(rhs))
+    }
+}
+
+impl From<u64> for Gas {
+    fn from(gas: u64) -> Self {
+        Self::new(gas)
+    }
+}
+
+impl fmt::Display for Gas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for Gas {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim().parse().map(Self)
+    }
 }
 
 impl Add<Gas> for Gas {
     type Output = Gas;
 
     fn add(self, rhs: Gas) -> Self::Output {
-        Gas(self.0 + rhs.0)
+        self.saturating_add(rhs)
     }
 }
 
 impl AddAssign<Gas> for Gas {
     fn add_assign(&mut self, rhs: Gas) {
-        self.0 += rhs.0
+        *self = self.saturating_add(rhs)
     }
 }
 
@@ -38,7 +87,7 @@ impl Mul<Gas> for u32 {
     type Output = Gas;
 
     fn mul(self, rhs: Gas) -> Self::Output {
-        Gas(u64::from(self) * rhs.0)
+        rhs.saturating_mul(u64::from(self))
     }
 }
 
@@ -46,7 +95,7 @@ impl Mul<u32> for Gas {
     type Output = Gas;
 
     fn mul(self, rhs: u32) -> Self::Output {
-        Gas(self.0 * u64::from(rhs))
+        self.saturating_mul(u64::from(rhs))
     }
 }
 
@@ -54,7 +103,7 @@ impl Mul<u64> for Gas {
     type Output = Gas;
 
     fn mul(self, rhs: u64) -> Self::Output {
-        Gas(self.0 * rhs)
+        self.saturating_mul(rhs)
     }
 }
 
@@ -62,6 +111,34 @@ impl Mul<Gas> for u64 {
     type Output = Gas;
 
     fn mul(self, rhs: Gas) -> Self::Output {
-        Gas(self * rhs.0)
+        rhs.saturating_mul(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_arithmetic() {
+        let max = Gas::new(u64::MAX);
+        assert_eq!(Gas::new(1) + Gas::new(2), Gas::new(3));
+        assert_eq!(max + Gas::new(1), max);
+        assert_eq!(u64::MAX * Gas::new(3), max);
+        assert_eq!(Gas::new(3) * 2u32, Gas::new(6));
+        assert_eq!(max.checked_add(Gas::new(1)), None);
+        assert_eq!(Gas::new(1).checked_sub(Gas::new(2)), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(Gas::new(1).saturating_sub(Gas::new(2)), Gas::new(0));
+        let mut gas = max;
+        gas += Gas::new(1);
+        assert_eq!(gas, max);
+    }
+
+    #[test]
+    fn test_gas_display_and_parse() {
+        assert_eq!(Gas::new(21_000).to_string(), "21000");
+        assert_eq!("21000".parse(), Ok(Gas::new(21_000)));
+        assert!("-1".parse::<Gas>().is_err());
     }
 }
@@ -1,7 +1,9 @@
 mod address;
 mod gas;
+mod shard;
 mod utop;
 
-pub use address::Address;
+pub use address::{error::AddressError, Address};
 pub use gas::Gas;
-pub use utop::uTop;
+pub use shard::{AccountKind, ShardId};
+pub use utop::{uTop, ParseUTopError};
@@ -0,0 +1,122 @@
+use super::address::error::AddressError;
+
+/// TOP account types, the character after the leading `T` of an account string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccountKind {
+    /// `T0`: secp256k1 user account, base58 body
+    T0,
+    /// `T2`: native (system) contract account
+    T2,
+    /// `T6`: EVM contract account, 20-byte hex body
+    T6,
+    /// `T8`: Ethereum compatible user account, 20-byte hex body
+    T8,
+}
+
+impl AccountKind {
+    pub fn as_char(&self) -> char {
+        match self {
+            Self::T0 => '0',
+            Self::T2 => '2',
+            Self::T6 => '6',
+            Self::T8 => '8',
+        }
+    }
+
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '0' => Some(Self::T0),
+            '2' => Some(Self::T2),
+            '6' => Some(Self::T6),
+            '8' => Some(Self::T8),
+            _ => None,
+        }
+    }
+
+    /// Whether the account body is the 20-byte hex of an `Address`.
+    pub fn has_hex_body(&self) -> bool {
+        matches!(self, Self::T6 | Self::T8)
+    }
+}
+
+/// Shard coordinates of a TOP account, as the node's `base::xvaccount_t` (`xvledger/xvaccount.h`) derives them.
+///
+/// The account string is `T` + kind + 4 hex digits of ledger id + body. The ledger id holds the
+/// chain id in its high 12 bits and the zone in its low 4 bits. The node hashes the whole account string
+/// with xxhash64 (NOT xxh3) into [`ShardId::SUBADDR_COUNT`] ledger subaddresses, the hash `& 1023`.
+/// A subaddress is slot `& 7` of book `>> 3`, [`ShardId::TABLES_PER_BOOK`] slots in each of the
+/// [`ShardId::BOOK_COUNT`] books.
+///
+/// A zone runs [`ShardId::TABLE_COUNT`] tables and serves subaddress `n` in table `n & 63`, the hash `& 63`,
+/// so the table is not the slot within the book: `T8000056d9…` is subaddress 388, book 48 and table 4.
+///
+/// The cluster and group validating a table are elected on chain and change with every election,
+/// they cannot be derived from the account string and are left to the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShardId {
+    pub kind: AccountKind,
+    pub chain_id: u16,
+    pub zone: u8,
+    pub subaddr: u16,
+    pub book: u8,
+    pub table: u8,
+}
+
+impl ShardId {
+    /// ledger subaddresses, `enum_vbucket_has_tables_count` of the node.
+    pub const SUBADDR_COUNT: u16 = 1024;
+    /// `enum_vbook_has_tables_count` of the node.
+    pub const TABLES_PER_BOOK: u8 = 8;
+    /// `enum_vbucket_has_books_count` of the node.
+    pub const BOOK_COUNT: u8 = 128;
+    /// tables of a zone, the table id is the account hash `& 63`.
+    pub const TABLE_COUNT: u8 = 64;
+
+    /// Shard coordinates of any TOP account string, e.g. `T80000<hex>` or `T00000<base58>`.
+    pub fn from_account(account: &str) -> Result<Self, AddressError> {
+        let mut chars = account.chars();
+        if chars.next() != Some('T') {
+            return Err(AddressError::UnsupportedPrefix);
+        }
+        let kind = chars
+            .next()
+            .and_then(AccountKind::from_char)
+            .ok_or(AddressError::UnsupportedPrefix)?;
+        let ledger_id = account.get(2..6).ok_or(AddressError::IncorrectLength)?;
+        if account.len() == 6 {
+            return Err(AddressError::IncorrectLength);
+        }
+        if !ledger_id.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(AddressError::DecodeFailure);
+        }
+        let ledger_id = u16::from_str_radix(ledger_id, 16).map_err(|_| AddressError::DecodeFailure)?;
+        let subaddr = Self::subaddr_of(account);
+        Ok(Self {
+            kind,
+            chain_id: ledger_id >> 4,
+            zone: (ledger_id & 0x0f) as u8,
+            subaddr,
+            book: Self::book_of_subaddr(subaddr),
+            table: Self::table_of(account),
+        })
+    }
+
+    /// Ledger subaddress of an account string, `xxh64(account) & 1023`.
+    pub fn subaddr_of(account: &str) -> u16 {
+        (Self::hash(account) % u64::from(Self::SUBADDR_COUNT)) as u16
+    }
+
+    /// Book of a ledger subaddress, `subaddr >> 3`.
+    pub fn book_of_subaddr(subaddr: u16) -> u8 {
+        (subaddr / u16::from(Self::TABLES_PER_BOOK) % u16::from(Self::BOOK_COUNT)) as u8
+    }
+
+    /// Table id of an account string, `xxh64(account) & 63`.
+    pub fn table_of(account: &str) -> u8 {
+        (Self::hash(account) % u64::from(Self::TABLE_COUNT)) as u8
+    }
+
+    fn hash(account: &str) -> u64 {
+        xxhash_rust::const_xxh64::xxh64(account.as_bytes(), 0)
+    }
+}
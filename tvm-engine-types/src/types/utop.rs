@@ -1,14 +1,20 @@
+use std::fmt;
 use std::ops::{Div, Mul};
+use std::str::FromStr;
 
 use primitive_types::U256;
 
 /// Newtype of TOP's balance: uTOP. 1 TOP = 1 * 10^6 uTOP
 #[allow(non_camel_case_types)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct uTop(u64);
 
 impl uTop {
     const UTOP_TO_WEI: U256 = U256([1_000_000_000_000, 0, 0, 0]);
     const UTOP_MAX: U256 = U256([u64::MAX, 0, 0, 0]);
+    /// decimals of TOP, 1 TOP = 10^6 uTop
+    pub const DECIMALS: u32 = 6;
+    const UTOP_PER_TOP: u64 = 10u64.pow(Self::DECIMALS);
 
     pub const fn zero() -> Self {
         Self(0)
@@ -28,14 +34,35 @@ impl uTop {
         self.0.to_be_bytes()
     }
 
-    // pub fn check_add(self, rhs: Self) -> Option<Self> {
-    //     self.0.checked_add(rhs.0).map(Self)
-    // }
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    pub fn checked_mul(self, rhs: u64) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    pub fn saturating_mul(self, rhs: u64) -> Self {
+        Self(self.0.saturating_mul(rhs))
+    }
 
     pub fn into_wei_raw(self) -> U256 {
         U256([self.0, 0, 0, 0]).mul(Self::UTOP_TO_WEI)
     }
 
+    /// whole uTop of `value`, the part below 1 uTop is dropped. `None` if it does not fit in u64.
     pub fn from_wei_value(value: U256) -> Option<Self> {
         let r = value.div(Self::UTOP_TO_WEI);
         // should never be bigger than u64::MAX
@@ -45,6 +72,20 @@ impl uTop {
             Some(Self::new(r.as_u64()))
         }
     }
+
+    /// Exact conversion of `value`, `None` unless it is a whole number of uTop that fits in u64.
+    pub fn try_from_wei(value: U256) -> Option<Self> {
+        match Self::split_wei(value)? {
+            (utop, rest) if rest.is_zero() => Some(utop),
+            _ => None,
+        }
+    }
+
+    /// Lossless split of `value` into whole uTop and the wei below 1 uTop, `None` if the uTop do not fit in u64.
+    pub fn split_wei(value: U256) -> Option<(Self, U256)> {
+        let utop = Self::from_wei_value(value)?;
+        Some((utop, value - utop.into_wei_raw()))
+    }
 }
 
 impl From<u64> for uTop {
@@ -52,3 +93,110 @@ impl From<u64> for uTop {
         Self::new(value)
     }
 }
+
+/// Formats as TOP with all six decimals, e.g. `1.234567 TOP`.
+impl fmt::Display for uTop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{:06} TOP",
+            self.0 / Self::UTOP_PER_TOP,
+            self.0 % Self::UTOP_PER_TOP
+        )
+    }
+}
+
+/// Parses a decimal TOP amount such as `1.234567` or `1.234567 TOP`, at most six decimals.
+impl FromStr for uTop {
+    type Err = ParseUTopError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_suffix("TOP").map(str::trim_end).unwrap_or(s);
+        let (whole, frac) = s.split_once('.').unwrap_or((s, "0"));
+        let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(whole) || !is_digits(frac) {
+            return Err(ParseUTopError::InvalidDigit);
+        }
+        if frac.len() > Self::DECIMALS as usize {
+            return Err(ParseUTopError::TooManyDecimals);
+        }
+        let whole: u64 = whole.parse().map_err(|_| ParseUTopError::Overflow)?;
+        let frac =
+            frac.parse::<u64>().map_err(|_| ParseUTopError::Overflow)? * 10u64.pow(Self::DECIMALS - frac.len() as u32);
+        whole
+            .checked_mul(Self::UTOP_PER_TOP)
+            .and_then(|utop| utop.checked_add(frac))
+            .map(Self)
+            .ok_or(ParseUTopError::Overflow)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseUTopError {
+    InvalidDigit,
+    TooManyDecimals,
+    Overflow,
+}
+
+impl fmt::Display for ParseUTopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::InvalidDigit => "invalid digit in TOP amount",
+            Self::TooManyDecimals => "TOP amount with more than 6 decimals",
+            Self::Overflow => "TOP amount too large",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for ParseUTopError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utop_arithmetic() {
+        let max = uTop::new(u64::MAX);
+        assert_eq!(uTop::new(1).checked_add(uTop::new(2)), Some(uTop::new(3)));
+        assert_eq!(max.checked_add(uTop::new(1)), None);
+        assert_eq!(uTop::new(1).checked_sub(uTop::new(2)), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(max.saturating_add(uTop::new(1)), max);
+        assert_eq!(uTop::new(1).saturating_sub(uTop::new(2)), uTop::zero());
+        assert_eq!(uTop::new(3).saturating_mul(2), uTop::new(6));
+        assert!(uTop::new(1) < uTop::new(2));
+    }
+
+    #[test]
+    fn test_utop_display_and_parse() {
+        assert_eq!(uTop::new(1_234_567).to_string(), "1.234567 TOP");
+        assert_eq!(uTop::new(5).to_string(), "0.000005 TOP");
+        assert_eq!("1.234567 TOP".parse(), Ok(uTop::new(1_234_567)));
+        assert_eq!("1.234567".parse(), Ok(uTop::new(1_234_567)));
+        assert_eq!("0.05".parse(), Ok(uTop::new(50_000)));
+        assert_eq!("12".parse(), Ok(uTop::new(12_000_000)));
+        let max = uTop::new(u64::MAX);
+        assert_eq!(max.to_string().parse(), Ok(max));
+        assert_eq!("1.2345678".parse::<uTop>(), Err(ParseUTopError::TooManyDecimals));
+        assert_eq!(
+            ParseUTopError::TooManyDecimals.to_string(),
+            "TOP amount with more than 6 decimals"
+        );
+        assert_eq!("1.".parse::<uTop>(), Err(ParseUTopError::InvalidDigit));
+        assert_eq!("-1".parse::<uTop>(), Err(ParseUTopError::InvalidDigit));
+        assert_eq!("".parse::<uTop>(), Err(ParseUTopError::InvalidDigit));
+        assert_eq!("18446744073710".parse::<uTop>(), Err(ParseUTopError::Overflow));
+    }
+
+    #[test]
+    fn test_utop_wei() {
+        let wei = uTop::new(7).into_wei_raw();
+        assert_eq!(wei, U256::from(7_000_000_000_000u64));
+        assert_eq!(uTop::try_from_wei(wei), Some(uTop::new(7)));
+        assert_eq!(uTop::try_from_wei(wei + 1), None);
+        assert_eq!(uTop::split_wei(wei + 1), Some((uTop::new(7), U256::one())));
+        assert_eq!(uTop::try_from_wei(U256::MAX), None);
+    }
+}
@@ -1,38 +1,332 @@
 mod interface {
 
+    use std::sync::Mutex;
+
     use protobuf::Message;
     use tvm_engine_runtime::{
+        env::Env,
         io::{StorageIntermediate, IO},
         runtime::Runtime,
     };
-    use tvm_engine_types::{PCallArgs, PReturnResult};
+    use tvm_engine_types::{
+        PAccessListResult, PCallArgs, PDeployAddressResult, PEstimateGasResult, PForkSchedule, PReturnResult,
+        PStateDiffResult,
+    };
+
+    use crate::{
+        engine::Engine, types::EngineInterfaceExpect, CallArgs, EngineError, ForkSchedule, InvalidTransaction,
+        StateDiff,
+    };
+
+    /// set once by the host with `set_fork_schedule`, `ForkSchedule::default` until then.
+    static FORK_SCHEDULE: Mutex<Option<ForkSchedule>> = Mutex::new(None);
 
-    use crate::{engine::Engine, types::EngineInterfaceExpect};
+    /// engine running the rules of the host's fork schedule.
+    fn engine<I: IO, E: Env>(io: I, env: &E) -> Engine<'_, I, E> {
+        let fork_schedule = FORK_SCHEDULE
+            .lock()
+            .engine_interface_expect("Err ForkSchedule Lock")
+            .clone()
+            .unwrap_or_default();
+        Engine::new(io, env).with_fork_schedule(fork_schedule)
+    }
+
+    /// decode `PCallArgs` from input, undecodable bytes or malformed field lengths reject the transaction.
+    fn read_args<I: IO>(io: &I) -> Result<CallArgs, InvalidTransaction> {
+        let input = io.get_input().to_vec();
+        PCallArgs::parse_from_bytes(&input)
+            .map_err(|_| InvalidTransaction::MalformedArgs)?
+            .try_into()
+    }
+
+    /// output the `PReturnResult` of args `read_args` rejected, for the exports whose output is not protobuf.
+    #[cfg(feature = "tracing")]
+    fn reject_args<I: IO>(io: &I, invalid: InvalidTransaction) -> bool {
+        let r = PReturnResult::from(invalid.into_engine_error());
+        let bytes = r.write_to_bytes().engine_interface_expect("Err Result Serialize");
+        io.set_output(&bytes);
+        false
+    }
+
+    /// decode `PCallArgs` from input, run `f` and encode its result as output.
+    fn run_with_args<T, P, F>(f: F) -> bool
+    where
+        P: Message + From<T> + From<EngineError>,
+        F: FnOnce(&mut Engine<Runtime, Runtime>, CallArgs) -> Result<T, EngineError>,
+    {
+        run_with_args_on::<_, _, _, P, _>(Runtime, &Runtime, f)
+    }
+
+    /// `run_with_args` on any backend.
+    fn run_with_args_on<I, E, T, P, F>(io: I, env: &E, f: F) -> bool
+    where
+        I: IO + Clone,
+        E: Env,
+        P: Message + From<T> + From<EngineError>,
+        F: FnOnce(&mut Engine<I, E>, CallArgs) -> Result<T, EngineError>,
+    {
+        let mut engine = engine(io.clone(), env);
+        let result = read_args(&io)
+            .map_err(InvalidTransaction::into_engine_error)
+            .and_then(|args| f(&mut engine, args));
+        let (r, b) = match result {
+            Ok(r) => (P::from(r), true),
+            Err(err) => (P::from(err), false),
+        };
+        let bytes = r.write_to_bytes().engine_interface_expect("Err Result Serialize");
+        io.set_output(&bytes);
+        b
+    }
+
+    /// decode `PForkSchedule` from input, every later call runs the rules it activates at `Env::block_height`.
+    /// Called by the host once at startup, false and nothing changed when a hardfork is unknown.
+    #[no_mangle]
+    pub extern "C" fn set_fork_schedule() -> bool {
+        configure_fork_schedule(&Runtime)
+    }
+
+    fn configure_fork_schedule<I: IO>(io: &I) -> bool {
+        let input = io.get_input().to_vec();
+        let schedule = PForkSchedule::parse_from_bytes(&input).engine_interface_expect("Err ForkSchedule Deserialize");
+        match ForkSchedule::try_from(schedule) {
+            Ok(schedule) => {
+                *FORK_SCHEDULE.lock().engine_interface_expect("Err ForkSchedule Lock") = Some(schedule);
+                true
+            }
+            Err(_) => false,
+        }
+    }
 
     #[no_mangle]
     pub extern "C" fn call() -> bool {
+        run_with_args::<_, PReturnResult, _>(|engine, args| engine.call(args))
+    }
+
+    /// same as `call`, but never writes state.
+    #[no_mangle]
+    pub extern "C" fn view() -> bool {
+        run_with_args::<_, PReturnResult, _>(|engine, args| engine.view(args))
+    }
+
+    /// output is `PEstimateGasResult`, never writes state.
+    #[no_mangle]
+    pub extern "C" fn estimate_gas() -> bool {
+        run_with_args::<_, PEstimateGasResult, _>(|engine, args| engine.estimate_gas(args))
+    }
+
+    /// output is `PAccessListResult`, never writes state.
+    #[no_mangle]
+    pub extern "C" fn create_access_list() -> bool {
+        run_with_args::<_, PAccessListResult, _>(|engine, args| engine.create_access_list(args))
+    }
+
+    /// output is `PDeployAddressResult`, the address and salt `call` will deploy the input code at.
+    #[no_mangle]
+    pub extern "C" fn predict_deploy_address() -> bool {
+        run_with_args::<_, PDeployAddressResult, _>(|engine, args| engine.predict_deploy_address(args))
+    }
+
+    /// same as `view`, output is the json of the account changes `call` would write.
+    #[no_mangle]
+    pub extern "C" fn trace_state_diff() -> bool {
         let rt = Runtime;
-        let mut engine = Engine::new(rt, &rt);
-        let input = rt.get_input().to_vec();
-        let args = PCallArgs::parse_from_bytes(&input).engine_interface_expect("Err CallArgs Deserialize");
-        let (bytes, b) = match engine.call(args.into()) {
-            Ok(r) => {
-                let bytes =
-                    PReturnResult::write_to_bytes(&r.into()).engine_interface_expect("Err ReturnResult Serialize");
-                (bytes, true)
-            }
-            Err(err) => {
-                let r = PReturnResult {
-                    status: u32::MAX,
-                    status_data: err.kind.as_bytes().to_vec(),
-                    gas_used: err.gas_used,
-                    ..Default::default()
-                };
-                let bytes = PReturnResult::write_to_bytes(&r).engine_interface_expect("Err ReturnResult Serialize");
-                (bytes, false)
-            }
+        let engine = engine(rt, &rt);
+        let (result, diff) = match read_args(&rt) {
+            Ok(args) => engine.view_with_diff(args),
+            Err(invalid) => (Err(invalid.into_engine_error()), StateDiff::default()),
+        };
+        rt.set_output(diff.to_json().as_bytes());
+        result.is_ok()
+    }
+
+    /// same as `call`, output is `PStateDiffResult`, the result and the json of the account changes it wrote.
+    #[no_mangle]
+    pub extern "C" fn call_with_diff() -> bool {
+        let rt = Runtime;
+        let mut engine = engine(rt, &rt);
+        let (result, diff) = match read_args(&rt) {
+            Ok(args) => engine.call_with_diff(args),
+            Err(invalid) => (Err(invalid.into_engine_error()), StateDiff::default()),
         };
+        let (result, b) = match result {
+            Ok(r) => (PReturnResult::from(r), true),
+            Err(err) => (PReturnResult::from(err), false),
+        };
+        let r = PStateDiffResult {
+            result: Some(result).into(),
+            state_diff: diff.to_json(),
+            ..Default::default()
+        };
+        let bytes = r.write_to_bytes().engine_interface_expect("Err Result Serialize");
         rt.set_output(&bytes);
         b
     }
+
+    /// output is geth's struct logger json, never writes state.
+    /// False with the `PReturnResult` of the rejection as output on malformed args, like `call`.
+    #[cfg(feature = "tracing")]
+    #[no_mangle]
+    pub extern "C" fn trace_struct_logs() -> bool {
+        let rt = Runtime;
+        let engine = engine(rt, &rt);
+        let args = match read_args(&rt) {
+            Ok(args) => args,
+            Err(invalid) => return reject_args(&rt, invalid),
+        };
+        let trace = engine.trace_struct_logs(args);
+        rt.set_output(trace.to_json().as_bytes());
+        true
+    }
+
+    /// output is geth's `callTracer` json, never writes state.
+    /// False with the `PReturnResult` of the rejection as output on malformed args, like `call`.
+    #[cfg(feature = "tracing")]
+    #[no_mangle]
+    pub extern "C" fn trace_calls() -> bool {
+        let rt = Runtime;
+        let engine = engine(rt, &rt);
+        let args = match read_args(&rt) {
+            Ok(args) => args,
+            Err(invalid) => return reject_args(&rt, invalid),
+        };
+        let trace = engine.trace_calls(args);
+        rt.set_output(trace.to_json().as_bytes());
+        true
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use protobuf::EnumOrUnknown;
+        use tvm_engine_runtime::{MemoryEnv, MemoryIO};
+        use tvm_engine_types::{Address, PAccessListItem, PActivation, PHardfork, PStatusCode, PU256};
+
+        use super::*;
+
+        #[test]
+        fn test_malformed_args() {
+            let io = MemoryIO::new();
+            let sender = Address::build_from_str("56d9407e0ae1246a2aafcfa57f3fc1bd7023df81").unwrap();
+            let args = PCallArgs {
+                sender_address: Some(sender.into()).into(),
+                recver_address: Some(Address::zero().into()).into(),
+                gas_limit: 1_000_000,
+                ..Default::default()
+            };
+            let malformed = [
+                PCallArgs {
+                    salt: vec![1; 31],
+                    ..args.clone()
+                },
+                PCallArgs {
+                    nonce: Some(PU256 {
+                        data: vec![1; 33],
+                        ..Default::default()
+                    })
+                    .into(),
+                    ..args.clone()
+                },
+                PCallArgs {
+                    access_list: vec![PAccessListItem {
+                        address: Some(sender.into()).into(),
+                        storage_keys: vec![vec![1; 33]],
+                        ..Default::default()
+                    }],
+                    ..args.clone()
+                },
+                PCallArgs {
+                    recver_address: Default::default(),
+                    ..args.clone()
+                },
+            ];
+            // then bytes which are no protobuf: an invalid wire type and a truncated varint
+            let inputs = malformed
+                .iter()
+                .map(|args| args.write_to_bytes().unwrap())
+                .chain([vec![0xff], vec![0x08]]);
+            for input in inputs {
+                io.set_input(&input);
+                assert!(!run_with_args_on::<_, _, _, PReturnResult, _>(
+                    io.clone(),
+                    &MemoryEnv::default(),
+                    |engine, args| engine.call(args)
+                ));
+                let result = PReturnResult::parse_from_bytes(&io.output()).unwrap();
+                assert_eq!(result.status.enum_value(), Ok(PStatusCode::MALFORMED_ARGS));
+                assert_eq!(result.gas_used, 0);
+            }
+            assert_eq!(io.storage_len(), 0);
+        }
+
+        #[test]
+        fn test_set_fork_schedule() {
+            /// puts back the default schedule when the test ends, even failing, for the other tests.
+            struct ResetForkSchedule;
+            impl Drop for ResetForkSchedule {
+                fn drop(&mut self) {
+                    *FORK_SCHEDULE.lock().unwrap_or_else(|e| e.into_inner()) = None;
+                }
+            }
+            let _reset = ResetForkSchedule;
+
+            let io = MemoryIO::new();
+            let run = |env: &MemoryEnv, args: &PCallArgs| {
+                io.set_input(&args.write_to_bytes().unwrap());
+                run_with_args_on::<_, _, _, PReturnResult, _>(io.clone(), env, |engine, args| engine.call(args));
+                PReturnResult::parse_from_bytes(&io.output()).unwrap()
+            };
+            let set_fork_schedule = |activations: Vec<(u64, EnumOrUnknown<PHardfork>)>| {
+                let schedule = PForkSchedule {
+                    activations: activations
+                        .into_iter()
+                        .map(|(height, hardfork)| PActivation {
+                            height,
+                            hardfork,
+                            ..Default::default()
+                        })
+                        .collect(),
+                    ..Default::default()
+                };
+                io.set_input(&schedule.write_to_bytes().unwrap());
+                configure_fork_schedule(&io)
+            };
+
+            // runtime: `calldatasize == 0 ? return sload(0) : sstore(0, calldataload(0))`
+            let code = hex::decode("601880600b6000396000f33615600c57600035600055005b60005460005260206000f3").unwrap();
+            let sender = Address::build_from_str("56d9407e0ae1246a2aafcfa57f3fc1bd7023df81").unwrap();
+            let deploy = PCallArgs {
+                sender_address: Some(sender.into()).into(),
+                recver_address: Some(Address::zero().into()).into(),
+                input: code,
+                gas_limit: 1_000_000,
+                ..Default::default()
+            };
+            let contract = Address::from(run(&MemoryEnv::default(), &deploy).contract_address.unwrap());
+            let read = PCallArgs {
+                recver_address: Some(contract.into()).into(),
+                input: Vec::new(),
+                ..deploy
+            };
+            let gas_used_at = |block_height| {
+                let env = MemoryEnv {
+                    block_height,
+                    ..Default::default()
+                };
+                run(&env, &read).gas_used
+            };
+            let london = gas_used_at(100);
+            assert_eq!(gas_used_at(50), london);
+
+            assert!(set_fork_schedule(vec![
+                (0, PHardfork::ISTANBUL.into()),
+                (100, PHardfork::LONDON.into())
+            ]));
+            // a cold `SLOAD` costs 2100 since Berlin, 800 before
+            assert_eq!(gas_used_at(50) + 1300, london);
+            assert_eq!(gas_used_at(100), london);
+
+            // an unknown hardfork keeps the schedule
+            assert!(!set_fork_schedule(vec![(0, EnumOrUnknown::from_i32(99))]));
+            assert_eq!(gas_used_at(50) + 1300, london);
+        }
+    }
 }
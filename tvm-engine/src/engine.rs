@@ -1,49 +1,161 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
 
-use evm::backend::{Apply, ApplyBackend, Backend};
+use evm::backend::{Apply, ApplyBackend, Backend, Log};
+use evm::executor::stack::{PrecompileSet, StackState};
 use tvm_engine_precompiles::Precompiles;
 use tvm_engine_runtime::{env::Env, io::IO, log_format, methods::*, utils, DupCache, PairDupCache};
-use tvm_engine_types::{uTop, Address, H256, U256};
+use tvm_engine_types::{Address, H160, H256, U256};
 
-use crate::{CallArgs, EngineError, EngineErrorEnum, ReturnResult, TransactionStatus};
+use crate::stack_state::CreatorStackState;
+use crate::{
+    AccessListResult, CallArgs, DeployAddressResult, Deployment, EngineError, EngineErrorEnum, EstimateGasResult,
+    ForkSchedule, GasPrice, InvalidTransaction, ReturnResult, StateDiff, TransactionStatus,
+};
 
 struct StackExecutorParams {
     precompiles: Precompiles,
     gas_limit: u64,
+    config: &'static evm::Config,
 }
 
-// todo we can edit config
-const CONFIG: &evm::Config = &evm::Config::london();
+type Executor<'a, 'env, I, E> =
+    evm::executor::stack::StackExecutor<'static, 'a, CreatorStackState<'a, 'static, Engine<'env, I, E>>, Precompiles>;
+
+/// Gas charged before the first opcode: the base cost of a call or create, its input and its access list.
+fn intrinsic_gas(config: &evm::Config, args: &CallArgs) -> u64 {
+    let base = if args.recver_addr.is_zero() {
+        config.gas_transaction_create
+    } else {
+        config.gas_transaction_call
+    };
+    let zeros = args.input.iter().filter(|byte| **byte == 0).count() as u64;
+    let non_zeros = args.input.len() as u64 - zeros;
+    let keys = args.access_list.iter().map(|(_, keys)| keys.len()).sum::<usize>() as u64;
+    base.saturating_add(zeros.saturating_mul(config.gas_transaction_zero_data))
+        .saturating_add(non_zeros.saturating_mul(config.gas_transaction_non_zero_data))
+        .saturating_add((args.access_list.len() as u64).saturating_mul(config.gas_access_list_address))
+        .saturating_add(keys.saturating_mul(config.gas_access_list_storage_key))
+}
+
+/// `CREATE2` address `keccak(0xff ++ caller ++ salt ++ code_hash)`.
+fn create2_address(caller: Address, salt: H256, code_hash: H256) -> Address {
+    let hash = utils::keccak(
+        &[
+            &[0xff][..],
+            caller.raw().as_bytes(),
+            salt.as_bytes(),
+            code_hash.as_bytes(),
+        ]
+        .concat(),
+    );
+    Address::build_from_hash160(H160::from(hash))
+}
+
+/// `CREATE2` address of the contract `caller` deploys with `init_code` at a user supplied `salt`,
+/// which must land in the same table as `caller`.
+fn salted_deploy_address(caller: Address, salt: H256, init_code: &[u8]) -> Result<Address, InvalidTransaction> {
+    let contract_address = create2_address(caller, salt, utils::keccak(init_code));
+    if contract_address.get_top_address_tableid() != caller.get_top_address_tableid() {
+        return Err(InvalidTransaction::SaltTableMismatch);
+    }
+    Ok(contract_address)
+}
+
+/// Address and salt of the contract `caller` deploys with `init_code` at `nonce`, as `deploy_code` will produce.
+///
+/// The salt starts at `sha256(nonce)` and is incremented until the `CREATE2` address
+/// `keccak(0xff ++ caller ++ salt ++ keccak(init_code))` lands in the same table as `caller`.
+pub fn predict_deploy_address(caller: Address, nonce: U256, init_code: &[u8]) -> (Address, H256) {
+    // 1. code hash
+    let code_hash = utils::keccak(init_code);
+
+    // 2. nonce hash as salt begin value
+    let mut temp_bytes = vec![0u8; 32];
+    nonce.to_big_endian(&mut temp_bytes);
+    let mut salt_value = utils::sha256(&temp_bytes);
+
+    // 3. target table id
+    let caller_table_id = caller.get_top_address_tableid();
+
+    // 4. loop to calc the same table id contract address, find the salt
+    loop {
+        let contract_address = create2_address(caller, salt_value, code_hash);
+        if contract_address.get_top_address_tableid() == caller_table_id {
+            log_format!(
+                "generate contract_address with nonce:{}, address:{:?}",
+                nonce,
+                contract_address
+            );
+            return (contract_address, salt_value);
+        }
+        // salt_hash_value++
+        salt_value = H256::from_slice({
+            let u256_value = U256::from_big_endian(salt_value.as_bytes())
+                .overflowing_add(U256::from(1))
+                .0;
+            u256_value.to_big_endian(&mut temp_bytes);
+            temp_bytes.as_slice()
+        });
+    }
+}
+
+/// `BLOCKHASH` only sees this many previous blocks.
+const BLOCK_HASH_WINDOW: u64 = 256;
+
+/// upper bound of `estimate_gas` when the args carry no `gas_limit`.
+const ESTIMATE_GAS_CAP: u64 = 50_000_000;
+
+/// Wei per gas a transaction pays, of which `base_fee` is burned and the rest goes to the coinbase.
+#[derive(Debug, Clone, Copy, Default)]
+struct GasFee {
+    gas_price: U256,
+    base_fee: U256,
+}
+
+impl GasFee {
+    /// cost of `gas` in wei, `None` if it overflows.
+    fn cost(&self, gas: u64) -> Option<U256> {
+        U256::from(gas).checked_mul(self.gas_price)
+    }
+
+    /// part of the cost of `gas` paid to the coinbase, all but the burned base fee.
+    fn tip(&self, gas: u64) -> U256 {
+        U256::from(gas).saturating_mul(self.gas_price.saturating_sub(self.base_fee))
+    }
+}
 
 impl StackExecutorParams {
-    fn new(gas_limit: u64) -> Self {
+    fn new(gas_limit: u64, config: &'static evm::Config) -> Self {
         Self {
             precompiles: Precompiles::new(),
             gas_limit,
+            config,
         }
     }
-    fn make_executor<'a, 'env, I, E>(
-        &'a self,
-        engine: &'a Engine<'env, I, E>,
-    ) -> evm::executor::stack::StackExecutor<
-        'static,
-        'a,
-        evm::executor::stack::MemoryStackState<Engine<'env, I, E>>,
-        Precompiles,
-    >
+    fn make_executor<'a, 'env, I, E>(&'a self, engine: &'a Engine<'env, I, E>) -> Executor<'a, 'env, I, E>
     where
         I: IO,
         E: Env,
     {
-        let metadata = evm::executor::stack::StackSubstateMetadata::new(self.gas_limit, CONFIG);
-        let state = evm::executor::stack::MemoryStackState::new(metadata, engine);
-        evm::executor::stack::StackExecutor::new_with_precompiles(state, CONFIG, &self.precompiles)
+        let metadata = evm::executor::stack::StackSubstateMetadata::new(self.gas_limit, self.config);
+        let state = CreatorStackState::new(metadata, engine);
+        evm::executor::stack::StackExecutor::new_with_precompiles(state, self.config, &self.precompiles)
     }
 }
 
+/// The TOP virtual machine engine, executes one transaction against the state behind `IO`.
+///
+/// State is only read through `IO` and written back once the transaction finishes.
 pub struct Engine<'env, I, E> {
     io: I,
     env: &'env E,
+    fork_schedule: ForkSchedule,
+    /// effective gas price of the transaction being executed, read by `GASPRICE`.
+    gas_price: Cell<U256>,
+    /// addresses and slots read from the backend, only recorded for `create_access_list`.
+    accessed: RefCell<Option<BTreeMap<H160, BTreeSet<H256>>>>,
     account_info_cache: RefCell<DupCache<Address, evm::backend::Basic>>,
     storage_cache: RefCell<PairDupCache<Address, H256, H256>>,
 }
@@ -73,155 +185,667 @@ impl EvmExitIntoResult for evm::ExitReason {
     }
 }
 
-pub(crate) type EngineResult = Result<ReturnResult, EngineError>;
+pub type EngineResult = Result<ReturnResult, EngineError>;
+
+/// Everything a transaction produced in the executor, before any of it is written through `IO`.
+struct ExecuteOutcome {
+    status: Result<TransactionStatus, EngineErrorEnum>,
+    used_gas: u64,
+    values: Vec<Apply<BTreeMap<H256, H256>>>,
+    logs: Vec<Log>,
+    fee: GasFee,
+    deployment: Option<Deployment>,
+}
+
+impl ExecuteOutcome {
+    fn new<A, S, L>(
+        status: Result<TransactionStatus, EngineErrorEnum>,
+        used_gas: u64,
+        values: A,
+        logs: L,
+        fee: GasFee,
+    ) -> Self
+    where
+        A: IntoIterator<Item = Apply<S>>,
+        S: IntoIterator<Item = (H256, H256)>,
+        L: IntoIterator<Item = Log>,
+    {
+        // engine errors discard all state changes
+        let values = match status {
+            Ok(_) => values
+                .into_iter()
+                .map(|apply| match apply {
+                    Apply::Modify {
+                        address,
+                        basic,
+                        code,
+                        storage,
+                        reset_storage,
+                    } => Apply::Modify {
+                        address,
+                        basic,
+                        code,
+                        storage: storage.into_iter().collect(),
+                        reset_storage,
+                    },
+                    Apply::Delete { address } => Apply::Delete { address },
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        Self {
+            status,
+            used_gas,
+            values,
+            logs: logs.into_iter().collect(),
+            fee,
+            deployment: None,
+        }
+    }
+
+    /// Records what a successful deployment created at `contract_address`, read from its written code.
+    fn with_deployment(mut self, contract_address: H160, salt: H256) -> Self {
+        if matches!(self.status, Ok(TransactionStatus::Succeed(_))) {
+            let code = self.values.iter().find_map(|apply| match apply {
+                Apply::Modify {
+                    address,
+                    code: Some(code),
+                    ..
+                } if *address == contract_address => Some(code.as_slice()),
+                _ => None,
+            });
+            let code = code.unwrap_or_default();
+            self.deployment = Some(Deployment {
+                contract_address: Address::build_from_hash160(contract_address),
+                code_hash: utils::keccak(code),
+                code_size: code.len() as u64,
+                salt,
+            });
+        }
+        self
+    }
+
+    /// Fails the transaction if it created an account outside the table of its creator, the sender or the
+    /// contract which ran `CREATE`/`CREATE2`, as recorded in `creators`. The executor computes nested addresses
+    /// the Ethereum way and offers no hook to steer them, so a factory has to pick a `CREATE2` salt landing in
+    /// its table itself, see `predict_deploy_address`. Top-level deployments always land in the caller's table.
+    fn reject_cross_table_creates(mut self, creators: &BTreeMap<H160, H160>) -> Self {
+        let cross_table = self.values.iter().find_map(|apply| match apply {
+            // a created account later called has been modified again and lost its `reset_storage` flag,
+            // so every account left in the state is looked up in `creators`
+            Apply::Modify { address, .. } => {
+                let creator = Address::build_from_hash160(*creators.get(address)?);
+                let created = Address::build_from_hash160(*address);
+                (created.get_top_address_tableid() != creator.get_top_address_tableid()).then_some(*address)
+            }
+            _ => None,
+        });
+        if let Some(address) = cross_table {
+            log_format!("reject contract created in another table: {:?}", address);
+            self.status = Err(EngineErrorEnum::CrossTableCreate(Address::build_from_hash160(address)));
+            self.values.clear();
+            self.logs.clear();
+        }
+        self
+    }
+
+    /// the transaction was rejected before execution, for the tracers which report it like an engine error.
+    #[cfg(feature = "tracing")]
+    fn rejected(invalid: InvalidTransaction) -> Self {
+        Self {
+            status: Err(invalid.into()),
+            used_gas: 0,
+            values: Vec::new(),
+            logs: Vec::new(),
+            fee: GasFee::default(),
+            deployment: None,
+        }
+    }
+
+    fn into_result(self) -> EngineResult {
+        match self.status {
+            Ok(status) => Ok(ReturnResult::new(
+                status,
+                self.used_gas,
+                self.logs,
+                self.fee.gas_price,
+                self.deployment,
+            )),
+            Err(engine_error) => Err(engine_error.with_gas_used(self.used_gas)),
+        }
+    }
+}
 
 impl<'env, I, E> Engine<'env, I, E>
 where
     I: IO,
     E: Env,
 {
-    pub(crate) fn new(io: I, env: &'env E) -> Self {
+    pub fn new(io: I, env: &'env E) -> Self {
         Self {
             io,
             env,
+            fork_schedule: ForkSchedule::default(),
+            gas_price: Cell::new(U256::zero()),
+            accessed: RefCell::new(None),
             account_info_cache: RefCell::new(DupCache::default()),
             storage_cache: RefCell::new(PairDupCache::default()),
         }
     }
 
-    pub(crate) fn call(&mut self, args: CallArgs) -> EngineResult {
+    /// Run with the rules `fork_schedule` activates at `Env::block_height`, London otherwise.
+    pub fn with_fork_schedule(mut self, fork_schedule: ForkSchedule) -> Self {
+        self.fork_schedule = fork_schedule;
+        self
+    }
+
+    fn config(&self) -> &'static evm::Config {
+        self.fork_schedule.hardfork_at(self.env.block_height()).config()
+    }
+
+    /// Execute a transaction and write its state changes through `IO`.
+    ///
+    /// Deploys `args.input` as init code when `args.recver_addr` is zero, calls the contract otherwise.
+    /// The sender pays `gas_limit` at the effective gas price upfront and gets back what the used gas does not cost.
+    /// Of that cost the block base fee is burned and the tip goes to `Env::block_coinbase`.
+    ///
+    /// A transaction failing the checks before execution is rejected with an `InvalidTransaction` error,
+    /// without any state change. Otherwise the sender's nonce advances once whatever the outcome:
+    /// success, revert, any evm error or fatal.
+    pub fn call(&mut self, args: CallArgs) -> EngineResult {
+        self.commit(args, None)
+    }
+
+    /// Execute a transaction the same way as `call`, and report every account change it writes.
+    pub fn call_with_diff(&mut self, args: CallArgs) -> (EngineResult, StateDiff) {
+        let mut diff = StateDiff::default();
+        let result = self.commit(args, Some(&mut diff));
+        (result, diff)
+    }
+
+    fn commit(&mut self, args: CallArgs, diff: Option<&mut StateDiff>) -> EngineResult {
         let caller = args.sender_addr;
-        let target = args.recver_addr;
-        if target.is_zero() {
+        let outcome = self
+            .execute(args, true)
+            .map_err(InvalidTransaction::into_engine_error)?;
+        match outcome.status {
+            Ok(status) => {
+                if let Some(diff) = diff {
+                    diff.record(self, &outcome.values);
+                }
+                self.apply(outcome.values, Vec::new(), true);
+                Ok(ReturnResult::new(
+                    status,
+                    outcome.used_gas,
+                    outcome.logs,
+                    outcome.fee.gas_price,
+                    outcome.deployment,
+                ))
+            }
+            Err(engine_error) => {
+                let values = self.aborted_values(caller, outcome.used_gas, outcome.fee);
+                if let Some(diff) = diff {
+                    diff.record(self, &values);
+                }
+                self.apply(values, Vec::new(), true);
+                Err(engine_error.with_gas_used(outcome.used_gas))
+            }
+        }
+    }
+
+    /// State changes of a transaction aborted by an engine error: the sender's nonce and the fee of `used_gas`.
+    fn aborted_values(&self, caller: Address, used_gas: u64, fee: GasFee) -> Vec<Apply<BTreeMap<H256, H256>>> {
+        let cost = fee.cost(used_gas).unwrap_or_default();
+        let tip = fee.tip(used_gas);
+        let mut accounts = BTreeMap::new();
+        let mut sender = self.basic(caller.raw());
+        sender.nonce += U256::one();
+        sender.balance = sender.balance.saturating_sub(cost);
+        accounts.insert(caller.raw(), sender);
+        if !tip.is_zero() {
+            let coinbase = self.env.block_coinbase().raw();
+            let account = accounts.entry(coinbase).or_insert_with(|| self.basic(coinbase));
+            account.balance = account.balance.saturating_add(tip);
+        }
+        accounts
+            .into_iter()
+            .map(|(address, basic)| Apply::Modify {
+                address,
+                basic,
+                code: None,
+                storage: BTreeMap::new(),
+                reset_storage: false,
+            })
+            .collect()
+    }
+
+    /// Execute a transaction the same way as `call`, but discard its state changes.
+    ///
+    /// Nothing is written through `IO`, not even the nonce of the sender. Used for `eth_call`,
+    /// which runs for free whatever the base fee when the args and `Env::gas_price` carry no price.
+    pub fn view(&self, args: CallArgs) -> EngineResult {
+        self.execute(args, false)
+            .map_err(InvalidTransaction::into_engine_error)?
+            .into_result()
+    }
+
+    /// Execute a transaction the same way as `view`, and report the account changes `call` would write.
+    pub fn view_with_diff(&self, args: CallArgs) -> (EngineResult, StateDiff) {
+        let mut diff = StateDiff::default();
+        let result = match self.execute(args, false) {
+            Ok(outcome) => {
+                if outcome.status.is_ok() {
+                    diff.record(self, &outcome.values);
+                }
+                outcome.into_result()
+            }
+            Err(invalid) => Err(invalid.into_engine_error()),
+        };
+        (result, diff)
+    }
+
+    /// Find the minimal `gas_limit` the transaction succeeds with, without writing state.
+    ///
+    /// Because of the 63/64 rule for sub calls and of refunds, the gas used by one run is not
+    /// enough as a limit, so it is only the lower bound of a binary search up to `args.gas_limit`.
+    /// Any failure below the limit counts as too little gas, as an out of gas sub call often ends in a revert.
+    pub fn estimate_gas(&self, args: CallArgs) -> Result<EstimateGasResult, EngineError> {
+        let cap = if args.gas_limit == 0 {
+            ESTIMATE_GAS_CAP
+        } else {
+            args.gas_limit
+        };
+        let cap = cap.min(self.gas_allowance(&args)?);
+        let outcome = self
+            .execute(
+                CallArgs {
+                    gas_limit: cap,
+                    ..args.clone()
+                },
+                false,
+            )
+            .map_err(InvalidTransaction::into_engine_error)?;
+        let status = outcome
+            .status
+            .map_err(|engine_error| engine_error.with_gas_used(outcome.used_gas))?;
+        if !matches!(status, TransactionStatus::Succeed(_)) {
+            return Ok(EstimateGasResult {
+                status,
+                gas_used: outcome.used_gas,
+                gas_limit: 0,
+            });
+        }
+
+        // `used_gas` is after refunds, so any limit below it fails
+        let mut lo = outcome.used_gas.saturating_sub(1);
+        let mut hi = cap;
+        let mut succeed = (status, outcome.used_gas);
+        while lo + 1 < hi {
+            let mid = lo + (hi - lo) / 2;
+            let outcome = self.execute(
+                CallArgs {
+                    gas_limit: mid,
+                    ..args.clone()
+                },
+                false,
+            );
+            // a limit above `used_gas` passes the intrinsic gas, and the balance check `cap` passed
+            match outcome.map(|outcome| (outcome.status, outcome.used_gas)) {
+                Ok((Ok(status @ TransactionStatus::Succeed(_)), used_gas)) => {
+                    hi = mid;
+                    succeed = (status, used_gas);
+                }
+                _ => lo = mid,
+            }
+        }
+        log_format!("estimate_gas result: {}", hi);
+
+        Ok(EstimateGasResult {
+            status: succeed.0,
+            gas_used: succeed.1,
+            gas_limit: hi,
+        })
+    }
+
+    /// Most gas the sender can pay for at the fee cap of `args` after `value`, like geth caps `eth_estimateGas`.
+    fn gas_allowance(&self, args: &CallArgs) -> Result<u64, EngineError> {
+        let fee_per_gas = args
+            .gas_price
+            .unwrap_or(GasPrice::Legacy(self.env.gas_price()))
+            .max_fee_per_gas();
+        if fee_per_gas.is_zero() {
+            return Ok(u64::MAX);
+        }
+        let balance = self.basic(args.sender_addr.raw()).balance;
+        let available = balance
+            .checked_sub(args.value.into_wei_raw())
+            .ok_or_else(|| InvalidTransaction::InsufficientFunds.into_engine_error())?;
+        let allowance = available / fee_per_gas;
+        Ok(if allowance > U256::from(u64::MAX) {
+            u64::MAX
+        } else {
+            allowance.as_u64()
+        })
+    }
+
+    /// Address and salt the deployment in `args` will get, without executing it.
+    /// Uses `args.salt` when set, else the salt derived from `args.nonce` or the sender's current nonce.
+    pub fn predict_deploy_address(&self, args: CallArgs) -> Result<DeployAddressResult, EngineError> {
+        let (contract_address, salt) = match args.salt {
+            Some(salt) => (
+                salted_deploy_address(args.sender_addr, salt, &args.input)
+                    .map_err(InvalidTransaction::into_engine_error)?,
+                salt,
+            ),
+            None => {
+                let nonce = args.nonce.unwrap_or_else(|| self.basic(args.sender_addr.raw()).nonce);
+                predict_deploy_address(args.sender_addr, nonce, &args.input)
+            }
+        };
+        Ok(DeployAddressResult { contract_address, salt })
+    }
+
+    /// Find the access list the transaction should carry, like `eth_createAccessList`, without writing state.
+    ///
+    /// Every run attaches the list found so far and adds what it reads through `Backend`, until a run reads
+    /// nothing new. The sender, receiver or created contract, coinbase and precompiles are left out unless
+    /// their slots are read, as they are warm anyway or only read for the fee.
+    pub fn create_access_list(&self, args: CallArgs) -> Result<AccessListResult, EngineError> {
+        let target = if args.recver_addr.is_zero() {
+            self.deploy_address(args.sender_addr, args.salt, &args.input).0
+        } else {
+            args.recver_addr.raw()
+        };
+        let precompiles = Precompiles::new();
+        let excluded = |address: &H160| {
+            [args.sender_addr.raw(), target, self.env.block_coinbase().raw()].contains(address)
+                || precompiles.is_precompile(*address)
+        };
+
+        let mut access_list = BTreeMap::<H160, BTreeSet<H256>>::new();
+        for (address, keys) in &args.access_list {
+            access_list.entry(address.raw()).or_default().extend(keys);
+        }
+        loop {
+            let list = access_list
+                .iter()
+                .map(|(address, keys)| (Address::build_from_hash160(*address), keys.iter().copied().collect()))
+                .collect::<Vec<_>>();
+            *self.accessed.borrow_mut() = Some(BTreeMap::new());
+            let outcome = self.execute(
+                CallArgs {
+                    access_list: list.clone(),
+                    ..args.clone()
+                },
+                false,
+            );
+            let accessed = self.accessed.borrow_mut().take().unwrap_or_default();
+            let outcome = outcome.map_err(InvalidTransaction::into_engine_error)?;
+            let status = outcome
+                .status
+                .map_err(|engine_error| engine_error.with_gas_used(outcome.used_gas))?;
+
+            let previous = access_list.clone();
+            for (address, keys) in accessed {
+                if !keys.is_empty() || !excluded(&address) {
+                    access_list.entry(address).or_default().extend(keys);
+                }
+            }
+            if access_list == previous {
+                log_format!("create_access_list result: {:?}", list);
+                return Ok(AccessListResult {
+                    status,
+                    gas_used: outcome.used_gas,
+                    access_list: list,
+                });
+            }
+        }
+    }
+
+    fn record_access(&self, address: H160, index: Option<H256>) {
+        if let Some(accessed) = self.accessed.borrow_mut().as_mut() {
+            let keys = accessed.entry(address).or_default();
+            keys.extend(index);
+        }
+    }
+
+    /// Execute a transaction like `view` and record every opcode it runs, in geth's struct logger shape.
+    #[cfg(feature = "tracing")]
+    pub fn trace_struct_logs(&self, args: CallArgs) -> crate::tracing::StructLogTrace {
+        let (outcome, logger) = crate::tracing::trace_with(crate::tracing::StructLogger::default(), || {
+            self.execute(args, false).unwrap_or_else(ExecuteOutcome::rejected)
+        });
+        logger.into_trace(outcome.status, outcome.used_gas)
+    }
+
+    /// Execute a transaction like `view` and record its nested calls and creates, in geth's `callTracer` shape.
+    #[cfg(feature = "tracing")]
+    pub fn trace_calls(&self, args: CallArgs) -> crate::tracing::CallFrame {
+        let (outcome, tracer) = crate::tracing::trace_with(crate::tracing::CallTracer::default(), || {
+            self.execute(args.clone(), false)
+                .unwrap_or_else(ExecuteOutcome::rejected)
+        });
+        tracer.into_trace(args, outcome.status, outcome.used_gas)
+    }
+
+    /// Execute a transaction, `Err` if it is rejected before execution.
+    ///
+    /// `committed` is false for the runs whose state changes are discarded.
+    fn execute(&self, args: CallArgs, committed: bool) -> Result<ExecuteOutcome, InvalidTransaction> {
+        let fee = self.validate(&args, committed)?;
+        self.gas_price.set(fee.gas_price);
+        let outcome = if args.recver_addr.is_zero() {
             // deploy contract
-            self.deploy_code(caller, args.value, args.input, args.gas_limit)
+            self.deploy_code(args, fee)
         } else {
             // call contract
-            self.call_contract(caller, target, args.value, args.input, args.gas_limit)
+            self.call_contract(args, fee)
+        };
+        Ok(outcome)
+    }
+
+    /// Checks before execution, a transaction failing them is rejected without touching state or consuming gas.
+    ///
+    /// Returns the fee the transaction pays: its effective gas price against the block base fee, none before London.
+    /// Runs which are not `committed` skip the nonce and chain id, and run for free without a price, like `eth_call`.
+    fn validate(&self, args: &CallArgs, committed: bool) -> Result<GasFee, InvalidTransaction> {
+        let config = self.config();
+        let sender = self.basic(args.sender_addr.raw());
+        if committed {
+            if matches!(args.chain_id, Some(chain_id) if chain_id != self.env.chain_id()) {
+                return Err(InvalidTransaction::ChainIdMismatch);
+            }
+            if let Some(nonce) = args.nonce {
+                match nonce.cmp(&sender.nonce) {
+                    Ordering::Less => return Err(InvalidTransaction::NonceTooLow),
+                    Ordering::Greater => return Err(InvalidTransaction::NonceTooHigh),
+                    Ordering::Equal => (),
+                }
+            }
+        }
+        if args.recver_addr.is_zero()
+            && matches!(config.create_contract_limit, Some(limit) if args.input.len() > 2 * limit)
+        {
+            return Err(InvalidTransaction::InitCodeTooLarge);
+        }
+        if let (true, Some(salt)) = (args.recver_addr.is_zero(), args.salt) {
+            salted_deploy_address(args.sender_addr, salt, &args.input)?;
         }
+        if args.gas_limit < intrinsic_gas(config, args) {
+            return Err(InvalidTransaction::IntrinsicGasTooLow);
+        }
+
+        let gas_price = args.gas_price.unwrap_or(GasPrice::Legacy(self.env.gas_price()));
+        let fee = if !committed && gas_price.max_fee_per_gas().is_zero() {
+            GasFee::default()
+        } else {
+            let base_fee = if config.has_base_fee {
+                self.env.block_base_fee_per_gas()
+            } else {
+                U256::zero()
+            };
+            GasFee {
+                gas_price: gas_price.effective(base_fee)?,
+                base_fee,
+            }
+        };
+        // the upfront cost is at the effective price, but the sender must afford the fee cap
+        let max_cost = GasFee {
+            gas_price: gas_price.max_fee_per_gas(),
+            ..fee
+        }
+        .cost(args.gas_limit)
+        .ok_or(InvalidTransaction::InsufficientFunds)?;
+        if sender.balance < args.value.into_wei_raw().saturating_add(max_cost) {
+            return Err(InvalidTransaction::InsufficientFunds);
+        }
+        Ok(fee)
+    }
+
+    /// Charge the sender the cost of the whole `gas_limit` before execution.
+    fn buy_gas(&self, executor: &mut Executor<'_, 'env, I, E>, caller: Address, gas_limit: u64, fee: GasFee) -> U256 {
+        let prepaid = fee.cost(gas_limit).unwrap_or_default();
+        if prepaid.is_zero() {
+            return prepaid;
+        }
+        // `validate` checked the balance, if it still fails nothing is charged and so nothing refunded
+        match executor.state_mut().withdraw(caller.raw(), prepaid) {
+            Ok(()) => prepaid,
+            Err(_) => U256::zero(),
+        }
+    }
+
+    /// Advance the sender's nonce from `nonce`, if the executor failed before it does, on intrinsic gas or value.
+    fn advance_nonce(&self, executor: &mut Executor<'_, 'env, I, E>, caller: Address, nonce: U256) {
+        if executor.nonce(caller.raw()) == nonce {
+            executor.state_mut().inc_nonce(caller.raw());
+        }
+    }
+
+    /// Refund the sender what `prepaid` exceeds the cost of the used gas, and pay the tip to the coinbase.
+    ///
+    /// The used gas already has the `SSTORE` refunds taken off. The rest of the cost is burned.
+    fn pay_fee(&self, executor: &mut Executor<'_, 'env, I, E>, caller: Address, prepaid: U256, fee: GasFee) {
+        if prepaid.is_zero() {
+            return;
+        }
+        // never more than `prepaid`, as the used gas never exceeds the gas limit
+        let used_gas = executor.used_gas();
+        let cost = fee.cost(used_gas).unwrap_or(prepaid);
+        let refund = prepaid.saturating_sub(cost);
+        executor.state_mut().deposit(caller.raw(), refund);
+        executor
+            .state_mut()
+            .deposit(self.env.block_coinbase().raw(), fee.tip(used_gas));
     }
 
-    fn deploy_code(&mut self, caller: Address, value: uTop, input: Vec<u8>, gas_limit: u64) -> EngineResult {
+    fn deploy_code(&self, args: CallArgs, fee: GasFee) -> ExecuteOutcome {
+        let CallArgs {
+            sender_addr: caller,
+            value,
+            input,
+            gas_limit,
+            access_list,
+            salt,
+            ..
+        } = args;
+
         // 1. make evm executor
-        let executor_params = StackExecutorParams::new(gas_limit);
+        let executor_params = StackExecutorParams::new(gas_limit, self.config());
         let mut executor = executor_params.make_executor(self);
 
         // 2. calc contract address
-        // 2.1 code hash
-        let code_hash = utils::keccak(&input);
+        let (expected_contract_address, salt_value) = self.deploy_address(caller, salt, &input);
 
-        // 2.2 nonce hash as salt begin value
+        // 3. execute tx, with the fee charged upfront
         let nonce = self.basic(caller.raw()).nonce;
-        log_format!("get address {:?} 's nonce: {:?}", caller, nonce);
-        let mut temp_bytes = Vec::new();
-        temp_bytes.resize(32, 0u8);
-        nonce.to_big_endian(&mut temp_bytes);
-        let mut salt_value = utils::sha256(&temp_bytes);
-
-        // 2.3 target table id
-        let caller_table_id = caller.get_top_address_tableid();
-
-        // 2.4 loop to calc the same table id contract address, find the salt
-        let expected_contract_address = loop {
-            let contract_address = executor.create_address(evm::CreateScheme::Create2 {
-                caller: caller.raw(),
-                code_hash,
-                salt: salt_value,
-            });
-            if Address::build_from_hash160(contract_address).get_top_address_tableid() == caller_table_id {
-                log_format!(
-                    "generate contract_address with nonce:{}, address:{:?}",
-                    nonce,
-                    contract_address
-                );
-                break contract_address;
-            }
-            // salt_hash_value++
-            salt_value = H256::from_slice({
-                let u256_value = U256::from_big_endian(salt_value.as_bytes())
-                    .overflowing_add(U256::from(1))
-                    .0;
-                u256_value.to_big_endian(&mut temp_bytes);
-                temp_bytes.as_slice()
-            });
-        };
-
-        // 3. execute tx
+        let prepaid = self.buy_gas(&mut executor, caller, gas_limit, fee);
         let (exit_reason, return_value) = executor.transact_create2(
             caller.raw(),
             value.into_wei_raw(),
             input,
             salt_value,
             gas_limit,
-            Vec::new(),
+            access_list
+                .into_iter()
+                .map(|(address, keys)| (address.raw(), keys))
+                .collect(),
         );
+        self.advance_nonce(&mut executor, caller, nonce);
+        self.pay_fee(&mut executor, caller, prepaid, fee);
 
-        let result = if exit_reason.is_succeed() {
+        if exit_reason.is_succeed() {
             log_format!("deploy_code success: {:?}", expected_contract_address);
-            expected_contract_address.0.to_vec()
         } else {
             log_format!("deploy_code failed: {:?} : {:?}", exit_reason, return_value);
-            return_value
-        };
+        }
 
-        // 4. get tx status or engine error.
+        // 4. get tx status or engine error, the address goes into `ReturnResult::deployment`.
         let used_gas = executor.used_gas();
-        let status = match exit_reason.into_result(result) {
-            Ok(status) => status,
-            Err(engine_error) => {
-                increment_nonce(&mut self.io, &caller);
-                return Err(engine_error.with_gas_used(used_gas));
-            }
-        };
+        let status = exit_reason.into_result(return_value);
 
         log_format!("deploy_code result status: {:?}", status);
 
-        // 5. apply changes && return result
-        let (values, logs) = executor.into_state().deconstruct();
+        // 5. collect changes, written by `call` only
+        let (values, logs, creators) = executor.into_state().deconstruct();
 
-        self.apply(values, Vec::new(), true);
+        ExecuteOutcome::new(status, used_gas, values, logs, fee)
+            .reject_cross_table_creates(&creators)
+            .with_deployment(expected_contract_address, salt_value)
+    }
 
-        Ok(ReturnResult::new(status, used_gas, logs.into_iter().collect()))
+    /// `CREATE2` address and salt of a contract `caller` deploys with `init_code`: at `salt` when set,
+    /// `validate` checked its table, else in the same table as `caller` from its current nonce.
+    fn deploy_address(&self, caller: Address, salt: Option<H256>, init_code: &[u8]) -> (H160, H256) {
+        if let Some(salt) = salt {
+            return (create2_address(caller, salt, utils::keccak(init_code)).raw(), salt);
+        }
+        let nonce = self.basic(caller.raw()).nonce;
+        log_format!("get address {:?} 's nonce: {:?}", caller, nonce);
+        let (contract_address, salt) = predict_deploy_address(caller, nonce, init_code);
+        (contract_address.raw(), salt)
     }
 
-    fn call_contract(
-        &mut self,
-        caller: Address,
-        target: Address,
-        value: uTop,
-        input: Vec<u8>,
-        gas_limit: u64,
-    ) -> EngineResult {
+    fn call_contract(&self, args: CallArgs, fee: GasFee) -> ExecuteOutcome {
+        let CallArgs {
+            sender_addr: caller,
+            recver_addr: target,
+            value,
+            input,
+            gas_limit,
+            access_list,
+            ..
+        } = args;
+
         // 1. make evm executor
-        let executor_params = StackExecutorParams::new(gas_limit);
+        let executor_params = StackExecutorParams::new(gas_limit, self.config());
         let mut executor = executor_params.make_executor(self);
 
-        // 2. execute tx
+        // 2. execute tx, with the fee charged upfront
+        let nonce = self.basic(caller.raw()).nonce;
+        let prepaid = self.buy_gas(&mut executor, caller, gas_limit, fee);
         let (exit_reason, return_value) = executor.transact_call(
             caller.raw(),
             target.raw(),
             value.into_wei_raw(),
             input,
             gas_limit,
-            Vec::new(),
+            access_list
+                .into_iter()
+                .map(|(address, keys)| (address.raw(), keys))
+                .collect(),
         );
+        self.advance_nonce(&mut executor, caller, nonce);
+        self.pay_fee(&mut executor, caller, prepaid, fee);
 
         // 3. get tx status or engine error
         let used_gas = executor.used_gas();
-        let status = match exit_reason.into_result(return_value) {
-            Ok(status) => status,
-            Err(engine_error) => {
-                increment_nonce(&mut self.io, &caller);
-                return Err(engine_error.with_gas_used(used_gas));
-            }
-        };
+        let status = exit_reason.into_result(return_value);
 
-        // 4. apply changes && return result
-        let (values, logs) = executor.into_state().deconstruct();
+        // 4. collect changes, written by `call` only
+        let (values, logs, creators) = executor.into_state().deconstruct();
 
-        self.apply(values, Vec::new(), true);
-
-        Ok(ReturnResult::new(status, used_gas, logs.into_iter().collect()))
+        ExecuteOutcome::new(status, used_gas, values, logs, fee).reject_cross_table_creates(&creators)
     }
 }
 
@@ -230,9 +854,9 @@ where
     I: IO,
     E: Env,
 {
-    /// current gas_price
+    /// effective gas price of the executing transaction
     fn gas_price(&self) -> tvm_engine_types::U256 {
-        self.env.gas_price()
+        self.gas_price.get()
     }
 
     /// origin address that create this call
@@ -240,10 +864,13 @@ where
         self.env.origin().raw()
     }
 
-    /// hash value of some block height
-    fn block_hash(&self, _number: tvm_engine_types::U256) -> tvm_engine_types::H256 {
-        // query real block hash is expensive, figure out some method to calc a fake but certain hash
-        todo!()
+    /// hash value of some block height, zero outside of the 256 blocks before the current one
+    fn block_hash(&self, number: tvm_engine_types::U256) -> tvm_engine_types::H256 {
+        let current = U256::from(self.env.block_height());
+        if number >= current || current - number > U256::from(BLOCK_HASH_WINDOW) {
+            return H256::zero();
+        }
+        self.env.block_hash(number.as_u64())
     }
 
     /// current block height
@@ -261,16 +888,19 @@ where
         U256::from(self.env.block_timestamp().secs())
     }
 
+    /// randomness of the current block (`PREVRANDAO`), TOP has no proof of work difficulty
     fn block_difficulty(&self) -> tvm_engine_types::U256 {
-        todo!()
+        U256::from_big_endian(self.env.block_randomness().as_bytes())
     }
 
+    /// current block gas limit
     fn block_gas_limit(&self) -> tvm_engine_types::U256 {
-        todo!()
+        U256::from(self.env.block_gas_limit())
     }
 
+    /// current block base fee per gas
     fn block_base_fee_per_gas(&self) -> tvm_engine_types::U256 {
-        todo!()
+        self.env.block_base_fee_per_gas()
     }
 
     /// current chain id
@@ -285,23 +915,26 @@ where
 
     /// return one address' basic infomation (balance && nonce)
     fn basic(&self, address: tvm_engine_types::H160) -> evm::backend::Basic {
+        self.record_access(address, None);
         let address = Address::build_from_hash160(address);
         self.account_info_cache
             .borrow_mut()
             .get_or_insert_with(&address, || evm::backend::Basic {
                 nonce: get_nonce(&self.io, &address),
-                balance: get_balance(&self.io, &address).into_wei_raw(),
+                balance: get_balance(&self.io, &address),
             })
             .clone()
     }
 
     /// return the code of some address
     fn code(&self, address: tvm_engine_types::H160) -> Vec<u8> {
+        self.record_access(address, None);
         get_code(&self.io, &Address::build_from_hash160(address))
     }
 
     /// get storage of some address at some index
     fn storage(&self, address: tvm_engine_types::H160, index: tvm_engine_types::H256) -> tvm_engine_types::H256 {
+        self.record_access(address, Some(index));
         let address = Address::build_from_hash160(address);
         *self
             .storage_cache
@@ -345,11 +978,7 @@ where
                 } => {
                     let address = Address::build_from_hash160(address);
                     set_nonce(&mut self.io, &address, &basic.nonce);
-                    set_balance(
-                        &mut self.io,
-                        &address,
-                        &uTop::from_wei_value(basic.balance).unwrap_or(uTop::zero()),
-                    );
+                    set_balance(&mut self.io, &address, &basic.balance);
                     if let Some(code) = code {
                         set_code(&mut self.io, &address, &code);
                         log_format!("code write at {:?}, size:{}", address, code.len());
@@ -375,5 +1004,958 @@ where
                 }
             }
         }
+        // the next transaction on this engine must read what was just written
+        *self.account_info_cache.get_mut() = DupCache::default();
+        *self.storage_cache.get_mut() = PairDupCache::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GasPrice, Hardfork};
+    use tvm_engine_runtime::{MemoryEnv, MemoryIO};
+    use tvm_engine_types::{uTop, PReturnResult, PStatusCode};
+
+    /// runtime: `calldatasize == 0 ? return sload(0) : sstore(0, calldataload(0))`
+    const RUNTIME_CODE: &str = "3615600c57600035600055005b60005460005260206000f3";
+    /// init code which copies `RUNTIME_CODE` into memory and returns it
+    const INIT_CODE: &str = "601880600b6000396000f3";
+
+    fn caller() -> Address {
+        Address::build_from_str("56d9407e0ae1246a2aafcfa57f3fc1bd7023df81").unwrap()
+    }
+
+    fn call_args(recver_addr: Address, input: Vec<u8>) -> CallArgs {
+        CallArgs::new(caller(), recver_addr, input, uTop::zero(), 1_000_000)
+    }
+
+    fn deploy(io: &MemoryIO, env: &MemoryEnv) -> Address {
+        deploy_runtime(io, env, RUNTIME_CODE)
+    }
+
+    /// deploy `runtime` behind an init code which copies and returns it
+    fn deploy_runtime(io: &MemoryIO, env: &MemoryEnv, runtime: &str) -> Address {
+        let init_code = format!("60{:02x}80600b6000396000f3", runtime.len() / 2);
+        deploy_init_code(io, env, hex::decode([&init_code, runtime].concat()).unwrap())
+    }
+
+    fn deploy_init_code(io: &MemoryIO, env: &MemoryEnv, code: Vec<u8>) -> Address {
+        let mut engine = Engine::new(io.clone(), env);
+        let result = engine.call(call_args(Address::zero(), code)).unwrap();
+        match result.deployment {
+            Some(deployment) => deployment.contract_address,
+            None => panic!("deploy failed: {:?}", result.status),
+        }
+    }
+
+    /// deploy a proxy forwarding its calldata to `target`
+    fn deploy_proxy(io: &MemoryIO, env: &MemoryEnv, target: Address) -> Address {
+        deploy_init_code(io, env, proxy_init_code(target))
+    }
+
+    fn fund(io: &MemoryIO, address: &Address, balance: u64) {
+        set_balance(&mut io.clone(), address, &uTop::new(balance).into_wei_raw());
+    }
+
+    /// calldata of a single 32-byte word
+    fn word(value: u64) -> Vec<u8> {
+        H256::from_low_u64_be(value).as_bytes().to_vec()
+    }
+
+    #[test]
+    fn test_deploy_and_call() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+
+        let contract = deploy(&io, &env);
+        assert_eq!(contract.get_top_address_tableid(), caller().get_top_address_tableid());
+        assert_eq!(get_code(&io, &contract), hex::decode(RUNTIME_CODE).unwrap());
+
+        let value = H256::from_low_u64_be(0x1234);
+        let mut engine = Engine::new(io.clone(), &env);
+        let result = engine.call(call_args(contract, value.as_bytes().to_vec())).unwrap();
+        assert_eq!(result.status, TransactionStatus::Succeed(Vec::new()));
+        assert_eq!(get_storage(&io, &contract, &H256::zero()), value);
+
+        let mut engine = Engine::new(io.clone(), &env);
+        let result = engine.call(call_args(contract, Vec::new())).unwrap();
+        assert_eq!(result.status, TransactionStatus::Succeed(value.as_bytes().to_vec()));
+    }
+
+    #[test]
+    fn test_view_discards_state() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let contract = deploy(&io, &env);
+        let nonce = get_nonce(&io, &caller());
+
+        let engine = Engine::new(io.clone(), &env);
+        let value = H256::from_low_u64_be(0x1234);
+        let result = engine.view(call_args(contract, value.as_bytes().to_vec())).unwrap();
+        assert_eq!(result.status, TransactionStatus::Succeed(Vec::new()));
+        assert_eq!(get_storage(&io, &contract, &H256::zero()), H256::zero());
+        assert_eq!(get_nonce(&io, &caller()), nonce);
+
+        let result = engine.view(call_args(contract, Vec::new())).unwrap();
+        assert_eq!(
+            result.status,
+            TransactionStatus::Succeed(H256::zero().as_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn test_call_with_diff() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+
+        let mut engine = Engine::new(io.clone(), &env);
+        let code = hex::decode([INIT_CODE, RUNTIME_CODE].concat()).unwrap();
+        let (result, diff) = engine.call_with_diff(call_args(Address::zero(), code));
+        let contract = result.unwrap().deployment.unwrap().contract_address;
+        let created = &diff.accounts[&contract];
+        assert_eq!(created.pre.code_hash, utils::keccak(&[]));
+        let post = created.post.unwrap();
+        assert_eq!(post.code_hash, utils::keccak(&hex::decode(RUNTIME_CODE).unwrap()));
+        assert_eq!(post.nonce, U256::one());
+        assert_eq!(diff.accounts[&caller()].post.unwrap().nonce, U256::one());
+
+        let value = H256::from_low_u64_be(0x1234);
+        let args = call_args(contract, value.as_bytes().to_vec());
+        let engine = Engine::new(io.clone(), &env);
+        let (_, viewed) = engine.view_with_diff(args.clone());
+        assert_eq!(get_storage(&io, &contract, &H256::zero()), H256::zero());
+
+        let mut engine = Engine::new(io.clone(), &env);
+        let (result, diff) = engine.call_with_diff(args);
+        assert!(result.unwrap().is_succeed());
+        assert_eq!(diff, viewed);
+        assert_eq!(diff.accounts.len(), 2);
+        let called = &diff.accounts[&contract];
+        assert_eq!(called.post, Some(called.pre));
+        assert_eq!(called.storage, BTreeMap::from([(H256::zero(), (H256::zero(), value))]));
+        let sender = &diff.accounts[&caller()];
+        assert_eq!(
+            (sender.pre.nonce, sender.post.unwrap().nonce),
+            (U256::one(), U256::from(2))
+        );
+        assert_eq!(get_storage(&io, &contract, &H256::zero()), value);
+    }
+
+    fn coinbase() -> Address {
+        Address::build_from_str("7156526fbd7a3c72969b54f64e42c10fbb768c8a").unwrap()
+    }
+
+    #[test]
+    fn test_gas_fee() {
+        let io = MemoryIO::new();
+        let mut env = MemoryEnv {
+            block_coinbase: coinbase(),
+            ..Default::default()
+        };
+        let contract = deploy(&io, &env);
+        fund(&io, &caller(), 2_000_000);
+
+        // 1 uTop per gas
+        env.gas_price = uTop::new(1).into_wei_raw();
+        let mut engine = Engine::new(io.clone(), &env);
+        let value = H256::from_low_u64_be(0x1234);
+        let result = engine.call(call_args(contract, value.as_bytes().to_vec())).unwrap();
+        assert!(result.is_succeed());
+        assert_eq!(
+            get_balance(&io, &caller()),
+            uTop::new(2_000_000 - result.gas_used).into_wei_raw()
+        );
+        assert_eq!(get_balance(&io, &coinbase()), uTop::new(result.gas_used).into_wei_raw());
+
+        // clearing the slot is paid with less than the gas it used
+        let mut engine = Engine::new(io.clone(), &env);
+        let (result, diff) = engine.call_with_diff(call_args(contract, H256::zero().as_bytes().to_vec()));
+        let used_gas = result.unwrap().gas_used;
+        assert!(used_gas < 21_000 + 5_000);
+        let paid = diff.accounts[&caller()].pre.balance.raw() - diff.accounts[&caller()].post.unwrap().balance.raw();
+        assert_eq!(paid, used_gas);
+
+        // a fee below 1 uTop is charged exactly, in wei
+        env.gas_price = U256::one();
+        let mut engine = Engine::new(io.clone(), &env);
+        let caller_balance = get_balance(&io, &caller());
+        let coinbase_balance = get_balance(&io, &coinbase());
+        let result = engine.call(call_args(contract, Vec::new())).unwrap();
+        assert!(result.is_succeed());
+        assert_eq!(get_balance(&io, &caller()), caller_balance - result.gas_used);
+        assert_eq!(get_balance(&io, &coinbase()), coinbase_balance + result.gas_used);
+    }
+
+    #[test]
+    fn test_invalid_transaction() {
+        let io = MemoryIO::new();
+        let mut env = MemoryEnv {
+            block_coinbase: coinbase(),
+            ..Default::default()
+        };
+        let contract = deploy(&io, &env);
+        let nonce = get_nonce(&io, &caller());
+        // enough for the gas used, not for the gas limit
+        fund(&io, &caller(), 100_000);
+        env.gas_price = uTop::new(1).into_wei_raw();
+        let value = word(0x1234);
+
+        let mut engine = Engine::new(io.clone(), &env);
+        for (args, invalid) in [
+            (
+                call_args(contract, value.clone()),
+                InvalidTransaction::InsufficientFunds,
+            ),
+            (
+                CallArgs {
+                    value: uTop::new(1),
+                    gas_limit: 100_000,
+                    ..call_args(contract, value.clone())
+                },
+                InvalidTransaction::InsufficientFunds,
+            ),
+            (
+                CallArgs {
+                    gas_limit: 21_000,
+                    ..call_args(contract, value.clone())
+                },
+                InvalidTransaction::IntrinsicGasTooLow,
+            ),
+            (
+                CallArgs {
+                    gas_limit: 100_000,
+                    ..call_args(Address::zero(), vec![0; 2 * 0x6000 + 1])
+                },
+                InvalidTransaction::InitCodeTooLarge,
+            ),
+        ] {
+            let error = engine.call(args).unwrap_err();
+            assert!(matches!(error.kind, EngineErrorEnum::InvalidTransaction(kind) if kind == invalid));
+            assert_eq!(error.gas_used, 0);
+            let message = error.kind.to_string();
+            let presult = PReturnResult::from(error);
+            assert_eq!(presult.status.enum_value(), Ok(invalid.status_code()));
+            assert_eq!(presult.error_message, message);
+            assert!(presult.status_data.is_empty());
+            assert_eq!(get_balance(&io, &caller()), uTop::new(100_000).into_wei_raw());
+            assert_eq!(get_balance(&io, &coinbase()), uTop::new(0).into_wei_raw());
+            assert_eq!(get_nonce(&io, &caller()), nonce);
+            assert_eq!(get_storage(&io, &contract, &H256::zero()), H256::zero());
+        }
+
+        let args = CallArgs {
+            gas_limit: 100_000,
+            ..call_args(contract, value)
+        };
+        assert!(engine.call(args).unwrap().is_succeed());
+    }
+
+    #[test]
+    fn test_eip1559_fee() {
+        let io = MemoryIO::new();
+        let mut env = MemoryEnv {
+            block_coinbase: coinbase(),
+            ..Default::default()
+        };
+        let contract = deploy(&io, &env);
+        fund(&io, &caller(), 20_000_000);
+
+        // 10 uTop per gas burned, the tip is capped by the fee cap to 2 uTop per gas
+        env.block_base_fee_per_gas = uTop::new(10).into_wei_raw();
+        let value = H256::from_low_u64_be(0x1234);
+        let args = CallArgs {
+            gas_price: Some(GasPrice::Eip1559 {
+                max_fee_per_gas: uTop::new(12).into_wei_raw(),
+                max_priority_fee_per_gas: uTop::new(5).into_wei_raw(),
+            }),
+            ..call_args(contract, value.as_bytes().to_vec())
+        };
+        let mut engine = Engine::new(io.clone(), &env);
+        let result = engine.call(args.clone()).unwrap();
+        assert!(result.is_succeed());
+        assert_eq!(result.effective_gas_price, uTop::new(12).into_wei_raw());
+        assert_eq!(
+            get_balance(&io, &caller()),
+            uTop::new(20_000_000 - 12 * result.gas_used).into_wei_raw()
+        );
+        assert_eq!(
+            get_balance(&io, &coinbase()),
+            uTop::new(2 * result.gas_used).into_wei_raw()
+        );
+
+        // the fee cap no longer covers the base fee
+        env.block_base_fee_per_gas = uTop::new(13).into_wei_raw();
+        let balance = get_balance(&io, &caller());
+        let nonce = get_nonce(&io, &caller());
+        let mut engine = Engine::new(io.clone(), &env);
+        let error = engine.call(args.clone()).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            EngineErrorEnum::InvalidTransaction(InvalidTransaction::FeeCapTooLow)
+        ));
+        assert_eq!(error.gas_used, 0);
+        assert_eq!(get_balance(&io, &caller()), balance);
+        assert_eq!(get_nonce(&io, &caller()), nonce);
+
+        let args = CallArgs {
+            gas_price: Some(GasPrice::Eip1559 {
+                max_fee_per_gas: uTop::new(20).into_wei_raw(),
+                max_priority_fee_per_gas: uTop::new(21).into_wei_raw(),
+            }),
+            ..args
+        };
+        let error = engine.call(args).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            EngineErrorEnum::InvalidTransaction(InvalidTransaction::TipAboveFeeCap)
+        ));
+
+        // `eth_call` without a price ignores the base fee
+        let result = engine.view(call_args(contract, Vec::new())).unwrap();
+        assert_eq!(result.status, TransactionStatus::Succeed(value.as_bytes().to_vec()));
+        assert_eq!(result.effective_gas_price, U256::zero());
+        let error = engine.call(call_args(contract, Vec::new())).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            EngineErrorEnum::InvalidTransaction(InvalidTransaction::FeeCapTooLow)
+        ));
+    }
+
+    #[test]
+    fn test_access_list() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let contract = deploy(&io, &env);
+
+        let engine = Engine::new(io.clone(), &env);
+        let args = call_args(contract, word(0x1234));
+        let cold = engine.view(args.clone()).unwrap().gas_used;
+        let with_access_list = |keys: Vec<H256>| {
+            let args = CallArgs {
+                access_list: vec![(contract, keys)],
+                ..args.clone()
+            };
+            engine.view(args).unwrap().gas_used
+        };
+        // 2400 for the address and 1900 for the slot, which saves the 2100 of its cold access
+        assert_eq!(with_access_list(vec![H256::zero()]), cold + 2400 + 1900 - 2100);
+        assert_eq!(with_access_list(vec![H256::from_low_u64_be(1)]), cold + 2400 + 1900);
+    }
+
+    #[test]
+    fn test_nonce() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv {
+            chain_id: 1023,
+            ..Default::default()
+        };
+        let contract = deploy(&io, &env);
+        let nonce = get_nonce(&io, &caller());
+        let value = word(0x1234);
+
+        let mut engine = Engine::new(io.clone(), &env);
+        for (args_nonce, chain_id, kind) in [
+            (nonce - 1, 1023, PStatusCode::NONCE_TOO_LOW),
+            (nonce + 1, 1023, PStatusCode::NONCE_TOO_HIGH),
+            (nonce, 1, PStatusCode::CHAIN_ID_MISMATCH),
+        ] {
+            let args = CallArgs {
+                nonce: Some(args_nonce),
+                chain_id: Some(chain_id),
+                ..call_args(contract, value.clone())
+            };
+            let error = engine.call(args).unwrap_err();
+            assert_eq!(error.kind.status_code(), kind);
+            assert_eq!(get_nonce(&io, &caller()), nonce);
+        }
+        // `view` runs whatever the nonce
+        let args = CallArgs {
+            nonce: Some(nonce + 1),
+            ..call_args(contract, value.clone())
+        };
+        assert!(engine.view(args).unwrap().is_succeed());
+
+        let args = CallArgs {
+            nonce: Some(nonce),
+            chain_id: Some(1023),
+            ..call_args(contract, value)
+        };
+        assert!(engine.call(args.clone()).unwrap().is_succeed());
+        assert_eq!(get_nonce(&io, &caller()), nonce + 1);
+        // replayed
+        let error = engine.call(args).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            EngineErrorEnum::InvalidTransaction(InvalidTransaction::NonceTooLow)
+        ));
+
+        // the nonce advances on failures too
+        let args = CallArgs {
+            gas_limit: 21_000,
+            ..call_args(contract, Vec::new())
+        };
+        let result = engine.call(args).unwrap();
+        assert_eq!(result.status, TransactionStatus::OutOfGas);
+        assert_eq!(get_nonce(&io, &caller()), nonce + 2);
+    }
+
+    #[test]
+    fn test_wei_balance() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        // runtime: `call(gas(), caller(), 1, 0, 0, 0, 0)`, pays back 1 wei
+        let contract = deploy_runtime(&io, &env, "60008080806001335af100");
+        fund(&io, &caller(), 10);
+
+        let mut engine = Engine::new(io.clone(), &env);
+        let args = CallArgs {
+            value: uTop::new(1),
+            ..call_args(contract, Vec::new())
+        };
+        assert!(engine.call(args).unwrap().is_succeed());
+        assert_eq!(get_balance(&io, &caller()), uTop::new(9).into_wei_raw() + 1);
+        assert_eq!(get_balance(&io, &contract), uTop::new(1).into_wei_raw() - 1);
+        assert_eq!(
+            uTop::split_wei(get_balance(&io, &contract)),
+            Some((uTop::zero(), uTop::new(1).into_wei_raw() - 1))
+        );
+    }
+
+    #[test]
+    fn test_fork_schedule() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let contract = deploy(&io, &env);
+        let schedule = ForkSchedule::new(Hardfork::Istanbul).activate(100, Hardfork::London);
+
+        let gas_used_at = |block_height| {
+            let env = MemoryEnv {
+                block_height,
+                ..env.clone()
+            };
+            let engine = Engine::new(io.clone(), &env).with_fork_schedule(schedule.clone());
+            engine.view(call_args(contract, Vec::new())).unwrap().gas_used
+        };
+        // a cold `SLOAD` costs 2100 since Berlin, 800 before
+        assert_eq!(gas_used_at(99) + 1300, gas_used_at(100));
+        assert_eq!(
+            gas_used_at(100),
+            Engine::new(io.clone(), &env)
+                .view(call_args(contract, Vec::new()))
+                .unwrap()
+                .gas_used
+        );
+    }
+
+    #[test]
+    fn test_block_hash() {
+        let io = MemoryIO::new();
+        let mut env = MemoryEnv::default();
+        // runtime: `return blockhash(calldataload(0))`
+        let contract = deploy_runtime(&io, &env, "6000354060005260206000f3");
+
+        env.block_height = 300;
+        for height in [43, 44, 299, 300] {
+            env.block_hashes.insert(height, H256::from_low_u64_be(height + 1));
+        }
+        let engine = Engine::new(io.clone(), &env);
+        let block_hash = |height: u64| {
+            let input = word(height);
+            let result = engine.view(call_args(contract, input)).unwrap();
+            H256::from_slice(&result.status.into_data())
+        };
+        assert_eq!(block_hash(43), H256::zero());
+        assert_eq!(block_hash(44), H256::from_low_u64_be(45));
+        assert_eq!(block_hash(299), H256::from_low_u64_be(300));
+        assert_eq!(block_hash(300), H256::zero());
+        assert_eq!(block_hash(u64::MAX), H256::zero());
+    }
+
+    #[test]
+    fn test_block_context() {
+        let io = MemoryIO::new();
+        let mut env = MemoryEnv::default();
+        // runtime: `return (difficulty, gaslimit, basefee)`
+        let contract = deploy_runtime(&io, &env, "44600052456020524860405260606000f3");
+
+        env.block_randomness = H256::repeat_byte(0xab);
+        env.block_gas_limit = 30_000_000;
+        env.block_base_fee_per_gas = U256::from(7);
+        let engine = Engine::new(io.clone(), &env);
+        let result = engine.view(call_args(contract, Vec::new())).unwrap();
+        let expected = [
+            H256::repeat_byte(0xab).as_bytes(),
+            H256::from_low_u64_be(30_000_000).as_bytes(),
+            H256::from_low_u64_be(7).as_bytes(),
+        ]
+        .concat();
+        assert_eq!(result.status, TransactionStatus::Succeed(expected));
+    }
+
+    /// runtime of a contract forwarding its calldata to `target` with all gas, reverts if the call fails
+    fn proxy_init_code(target: Address) -> Vec<u8> {
+        let runtime = [
+            "36600060003760006000366000600073",
+            &target.encode(),
+            "5af115602b57005b60006000fd",
+        ]
+        .concat();
+        hex::decode(["603180600b6000396000f3", &runtime].concat()).unwrap()
+    }
+
+    /// Same as [`proxy_init_code`] but forwarding with `STATICCALL`.
+    #[cfg(feature = "tracing")]
+    fn static_proxy_init_code(target: Address) -> Vec<u8> {
+        let runtime = [
+            "3660006000376000600036600073",
+            &target.encode(),
+            "5afa15602957005b60006000fd",
+        ]
+        .concat();
+        hex::decode(["602f80600b6000396000f3", &runtime].concat()).unwrap()
+    }
+
+    fn assert_estimated(io: &MemoryIO, env: &MemoryEnv, contract: Address, input: Vec<u8>) -> u64 {
+        let engine = Engine::new(io.clone(), env);
+        let estimated = engine.estimate_gas(call_args(contract, input.clone())).unwrap();
+        assert!(matches!(estimated.status, TransactionStatus::Succeed(_)));
+
+        let mut args = call_args(contract, input);
+        args.gas_limit = estimated.gas_limit;
+        assert!(engine.view(args.clone()).unwrap().is_succeed());
+        args.gas_limit -= 1;
+        assert!(!engine.view(args).unwrap().is_succeed());
+        estimated.gas_limit
+    }
+
+    #[test]
+    fn test_estimate_gas() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let contract = deploy(&io, &env);
+
+        let value = word(0x1234);
+        let estimated = assert_estimated(&io, &env, contract, value.clone());
+        let mut engine = Engine::new(io.clone(), &env);
+        let used = engine.call(call_args(contract, value)).unwrap().gas_used;
+        assert_eq!(estimated, used);
+
+        // clearing the slot is refunded, the limit still has to cover the gas before the refund
+        let estimated = assert_estimated(&io, &env, contract, H256::zero().as_bytes().to_vec());
+        let engine = Engine::new(io.clone(), &env);
+        let used = engine
+            .view(call_args(contract, H256::zero().as_bytes().to_vec()))
+            .unwrap()
+            .gas_used;
+        assert!(estimated > used);
+    }
+
+    #[test]
+    fn test_estimate_gas_with_price() {
+        let io = MemoryIO::new();
+        let mut env = MemoryEnv::default();
+        let contract = deploy(&io, &env);
+        // 1 uTop per gas, a balance far below the default cap
+        fund(&io, &caller(), 100_000);
+        env.gas_price = uTop::new(1).into_wei_raw();
+
+        let value = word(0x1234);
+        let engine = Engine::new(io.clone(), &env);
+        let estimated = engine
+            .estimate_gas(CallArgs {
+                gas_limit: 0,
+                ..call_args(contract, value.clone())
+            })
+            .unwrap();
+        assert!(matches!(estimated.status, TransactionStatus::Succeed(_)));
+        assert_eq!(
+            estimated.gas_limit,
+            assert_estimated(&io, &env, contract, value.clone())
+        );
+
+        // value above the balance
+        let error = engine
+            .estimate_gas(CallArgs {
+                value: uTop::new(100_001),
+                ..call_args(contract, value)
+            })
+            .unwrap_err();
+        assert!(matches!(
+            error.kind,
+            EngineErrorEnum::InvalidTransaction(InvalidTransaction::InsufficientFunds)
+        ));
+    }
+
+    #[test]
+    fn test_estimate_gas_sub_call() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let contract = deploy(&io, &env);
+
+        let proxy = deploy_proxy(&io, &env, contract);
+
+        let value = word(0x1234);
+        let estimated = assert_estimated(&io, &env, proxy, value.clone());
+        let engine = Engine::new(io.clone(), &env);
+        let used = engine.view(call_args(proxy, value)).unwrap().gas_used;
+        // only 63/64 of the remaining gas is passed to the sub call
+        assert!(estimated > used);
+    }
+
+    #[test]
+    fn test_create_access_list() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let contract = deploy(&io, &env);
+        let proxy = deploy_proxy(&io, &env, contract);
+
+        let engine = Engine::new(io.clone(), &env);
+        let args = call_args(proxy, word(0x1234));
+        let result = engine.create_access_list(args.clone()).unwrap();
+        assert!(matches!(result.status, TransactionStatus::Succeed(_)));
+        assert_eq!(result.access_list, vec![(contract, vec![H256::zero()])]);
+        let used = engine.view(args.clone()).unwrap().gas_used;
+        // the listed address and slot save the 2500 and 2100 of their cold access
+        assert_eq!(result.gas_used, used + 2400 + 1900 - 2100 - 2500);
+        let args = CallArgs {
+            access_list: result.access_list,
+            ..args
+        };
+        assert_eq!(engine.view(args).unwrap().gas_used, result.gas_used);
+    }
+
+    #[test]
+    fn test_estimate_gas_revert() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let contract = deploy(&io, &env);
+        let proxy = deploy_proxy(&io, &env, contract);
+        let engine = Engine::new(io.clone(), &env);
+
+        // the proxy reverts when its sub call runs out of gas, even with the whole cap
+        let mut args = call_args(proxy, word(1));
+        args.gas_limit = 30_000;
+        let estimated = engine.estimate_gas(args).unwrap();
+        assert_eq!(estimated.status, TransactionStatus::Revert(Vec::new()));
+        assert_eq!(estimated.gas_limit, 0);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_trace_struct_logs() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let contract = deploy(&io, &env);
+
+        let engine = Engine::new(io.clone(), &env);
+        let value = H256::from_low_u64_be(0x1234);
+        let trace = engine.trace_struct_logs(call_args(contract, value.as_bytes().to_vec()));
+        assert!(!trace.failed);
+        assert_eq!(get_storage(&io, &contract, &H256::zero()), H256::zero());
+
+        let ops: Vec<u8> = trace.struct_logs.iter().map(|log| log.op.as_u8()).collect();
+        assert_eq!(ops, vec![0x36, 0x15, 0x60, 0x57, 0x60, 0x35, 0x60, 0x55, 0x00]);
+        for pair in trace.struct_logs.windows(2) {
+            assert_eq!(pair[0].depth, 1);
+            assert_eq!(pair[1].gas, pair[0].gas - pair[0].gas_cost);
+        }
+        let sstore = &trace.struct_logs[7];
+        assert_eq!(sstore.pc, 10);
+        assert_eq!(sstore.stack, vec![value, H256::zero()]);
+        assert_eq!(sstore.gas_cost, 22_100);
+        assert_eq!(sstore.storage, Some(BTreeMap::from([(H256::zero(), value)])));
+        assert!(trace.struct_logs[6].storage.is_none());
+        assert!(trace.to_json().starts_with(r#"{"gas":43"#));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_trace_struct_logs_sub_call() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let contract = deploy(&io, &env);
+        let proxy = deploy_proxy(&io, &env, contract);
+        let engine = Engine::new(io.clone(), &env);
+
+        let mut args = call_args(proxy, word(1));
+        args.gas_limit = 30_000;
+        let trace = engine.trace_struct_logs(args);
+        assert!(trace.failed);
+
+        let call = trace
+            .struct_logs
+            .iter()
+            .position(|log| log.op == evm::Opcode::CALL)
+            .unwrap();
+        let (call, inner) = (&trace.struct_logs[call], &trace.struct_logs[call + 1]);
+        assert_eq!((call.depth, inner.depth), (1, 2));
+        // the gas passed to the sub call is part of the `CALL` cost
+        assert!(call.gas_cost > inner.gas);
+        let sstore = trace
+            .struct_logs
+            .iter()
+            .find(|log| log.op == evm::Opcode::SSTORE)
+            .unwrap();
+        assert_eq!(sstore.error.as_deref(), Some("out of gas"));
+        let revert = trace.struct_logs.last().unwrap();
+        assert_eq!((revert.op, revert.depth), (evm::Opcode::REVERT, 1));
+        assert_eq!(revert.error.as_deref(), Some("execution reverted"));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_trace_calls() {
+        use crate::tracing::CallType;
+
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let engine = Engine::new(io.clone(), &env);
+        let code = hex::decode([INIT_CODE, RUNTIME_CODE].concat()).unwrap();
+        let trace = engine.trace_calls(call_args(Address::zero(), code.clone()));
+        let contract = deploy(&io, &env);
+        assert_eq!(trace.call_type, CallType::Create2);
+        assert_eq!((trace.from, trace.to), (caller(), contract));
+        assert_eq!(trace.input, code);
+        assert_eq!(trace.output, hex::decode(RUNTIME_CODE).unwrap());
+        assert_eq!(trace.error, None);
+
+        let proxy = deploy_proxy(&io, &env, contract);
+
+        let value = word(1);
+        let result = engine.view(call_args(proxy, value.clone())).unwrap();
+        let trace = engine.trace_calls(call_args(proxy, value.clone()));
+        assert_eq!(trace.call_type, CallType::Call);
+        assert_eq!((trace.from, trace.to), (caller(), proxy));
+        assert_eq!((trace.gas, trace.gas_used), (1_000_000, result.gas_used));
+        assert_eq!(trace.calls.len(), 1);
+        let inner = &trace.calls[0];
+        assert_eq!(inner.call_type, CallType::Call);
+        assert_eq!((inner.from, inner.to), (proxy, contract));
+        assert_eq!((inner.value, &inner.input), (Some(U256::zero()), &value));
+        assert!(inner.gas_used > 20_000 && inner.gas_used < inner.gas);
+        assert_eq!(inner.error, None);
+
+        let mut args = call_args(proxy, value);
+        args.gas_limit = 30_000;
+        let trace = engine.trace_calls(args);
+        assert_eq!(trace.error.as_deref(), Some("execution reverted"));
+        let inner = &trace.calls[0];
+        assert_eq!(inner.error.as_deref(), Some("out of gas"));
+        assert_eq!(inner.gas_used, inner.gas);
+
+        // a plain CALL nested in a STATICCALL keeps its own type
+        let static_proxy = deploy_init_code(&io, &env, static_proxy_init_code(proxy));
+        let trace = engine.trace_calls(call_args(static_proxy, vec![]));
+        assert_eq!(trace.error, None);
+        let outer = &trace.calls[0];
+        assert_eq!(outer.call_type, CallType::StaticCall);
+        assert_eq!((outer.from, outer.to, outer.value), (static_proxy, proxy, None));
+        let nested = &outer.calls[0];
+        assert_eq!(nested.call_type, CallType::Call);
+        assert_eq!(
+            (nested.from, nested.to, nested.value),
+            (proxy, contract, Some(U256::zero()))
+        );
+    }
+
+    #[test]
+    fn test_predict_deploy_address() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let code = hex::decode([INIT_CODE, RUNTIME_CODE].concat()).unwrap();
+
+        let (first, salt) = predict_deploy_address(caller(), U256::zero(), &code);
+        let (second, _) = predict_deploy_address(caller(), U256::one(), &code);
+        assert_eq!(first.get_top_address_tableid(), caller().get_top_address_tableid());
+
+        let engine = Engine::new(io.clone(), &env);
+        let predicted = engine.predict_deploy_address(call_args(Address::zero(), code)).unwrap();
+        assert_eq!(
+            predicted,
+            DeployAddressResult {
+                contract_address: first,
+                salt
+            }
+        );
+
+        assert_eq!(deploy(&io, &env), first);
+        assert_eq!(deploy(&io, &env), second);
+    }
+
+    #[test]
+    fn test_deploy_with_salt() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let code = hex::decode([INIT_CODE, RUNTIME_CODE].concat()).unwrap();
+        let salted = |salt: u64| salted_deploy_address(caller(), H256::from_low_u64_be(salt), &code);
+        let same = H256::from_low_u64_be((0..).find(|salt| salted(*salt).is_ok()).unwrap());
+        let other = H256::from_low_u64_be((0..).find(|salt| salted(*salt).is_err()).unwrap());
+        let salt_args = |salt: H256| CallArgs {
+            salt: Some(salt),
+            ..call_args(Address::zero(), code.clone())
+        };
+
+        let mut engine = Engine::new(io.clone(), &env);
+        let error = engine.call(salt_args(other)).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            EngineErrorEnum::InvalidTransaction(InvalidTransaction::SaltTableMismatch)
+        ));
+        assert!(engine.predict_deploy_address(salt_args(other)).is_err());
+        assert_eq!(get_nonce(&io, &caller()), U256::zero());
+
+        let predicted = engine.predict_deploy_address(salt_args(same)).unwrap();
+        assert_eq!(predicted.salt, same);
+        let result = engine.call(salt_args(same)).unwrap();
+        let runtime = hex::decode(RUNTIME_CODE).unwrap();
+        assert_eq!(result.status, TransactionStatus::Succeed(Vec::new()));
+        assert_eq!(
+            result.deployment,
+            Some(Deployment {
+                contract_address: predicted.contract_address,
+                code_hash: utils::keccak(&runtime),
+                code_size: runtime.len() as u64,
+                salt: same,
+            })
+        );
+        let presult = PReturnResult::from(result);
+        assert!(presult.status_data.is_empty());
+        assert_eq!(
+            Address::from(presult.contract_address.unwrap()),
+            predicted.contract_address
+        );
+        assert_eq!(presult.code_hash, utils::keccak(&runtime).as_bytes());
+        assert_eq!(presult.code_size, runtime.len() as u64);
+        assert_eq!(presult.salt, same.as_bytes());
+        assert_eq!(
+            get_code(&io, &predicted.contract_address),
+            hex::decode(RUNTIME_CODE).unwrap()
+        );
+
+        // the same salt and code always give the same address, which is taken now
+        let mut engine = Engine::new(io.clone(), &env);
+        assert!(engine.call(salt_args(same)).is_err());
+    }
+
+    #[test]
+    fn test_nested_create_table_id() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        // CREATE2 of empty init code with the salt from calldata
+        let factory = deploy_runtime(&io, &env, "600035600060006000f500");
+        let created = |salt: u64| {
+            let hash = utils::keccak(
+                &[
+                    &[0xff][..],
+                    factory.as_slice(),
+                    H256::from_low_u64_be(salt).as_bytes(),
+                    utils::keccak(&[]).as_bytes(),
+                ]
+                .concat(),
+            );
+            Address::build_from_hash160(H160::from(hash))
+        };
+        let table_id = caller().get_top_address_tableid();
+        let same = (0..)
+            .find(|salt| created(*salt).get_top_address_tableid() == table_id)
+            .unwrap();
+        let other = (0..)
+            .find(|salt| created(*salt).get_top_address_tableid() != table_id)
+            .unwrap();
+
+        let mut engine = Engine::new(io.clone(), &env);
+        let input = word(other);
+        let error = engine.call(call_args(factory, input)).unwrap_err();
+        assert!(matches!(error.kind, EngineErrorEnum::CrossTableCreate(address) if address == created(other)));
+        assert_eq!(get_nonce(&io, &created(other)), U256::zero());
+
+        let mut engine = Engine::new(io.clone(), &env);
+        let input = word(same);
+        let result = engine.call(call_args(factory, input)).unwrap();
+        assert_eq!(result.status, TransactionStatus::Succeed(Vec::new()));
+        assert_eq!(get_nonce(&io, &created(same)), U256::one());
+    }
+
+    #[test]
+    fn test_nested_create_in_factory_table() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let table_id = caller().get_top_address_tableid();
+        // a factory deployed by another sender lands in that sender's table
+        let deployer = (1..)
+            .map(|n| Address::build_from_hash160(H160::from_low_u64_be(n)))
+            .find(|address| address.get_top_address_tableid() != table_id)
+            .unwrap();
+        let mut engine = Engine::new(io.clone(), &env);
+        let code = hex::decode("600b80600b6000396000f3600035600060006000f500").unwrap();
+        let result = engine
+            .call(CallArgs {
+                sender_addr: deployer,
+                ..call_args(Address::zero(), code)
+            })
+            .unwrap();
+        let factory = result.deployment.unwrap().contract_address;
+        let factory_table_id = factory.get_top_address_tableid();
+        assert_ne!(factory_table_id, table_id);
+
+        let created = |salt: u64| create2_address(factory, H256::from_low_u64_be(salt), utils::keccak(&[]));
+        let in_factory_table = (0..)
+            .find(|salt| created(*salt).get_top_address_tableid() == factory_table_id)
+            .unwrap();
+        let in_sender_table = (0..)
+            .find(|salt| created(*salt).get_top_address_tableid() == table_id)
+            .unwrap();
+
+        // checked against the factory, not the sender
+        let mut engine = Engine::new(io.clone(), &env);
+        let error = engine.call(call_args(factory, word(in_sender_table))).unwrap_err();
+        assert!(
+            matches!(error.kind, EngineErrorEnum::CrossTableCreate(address) if address == created(in_sender_table))
+        );
+
+        let mut engine = Engine::new(io.clone(), &env);
+        let result = engine.call(call_args(factory, word(in_factory_table))).unwrap();
+        assert_eq!(result.status, TransactionStatus::Succeed(Vec::new()));
+        assert_eq!(get_nonce(&io, &created(in_factory_table)), U256::one());
+    }
+
+    #[test]
+    fn test_nested_create_then_call() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+        let table_id = caller().get_top_address_tableid();
+        // `CREATE2` with the salt in calldata, then calls the created contract
+        let factory = deploy_runtime(&io, &env, "600035600060006000f5600052600060006000600060006000515af100");
+
+        let created = |salt: u64| create2_address(factory, H256::from_low_u64_be(salt), utils::keccak(&[]));
+        let in_other_table = (0..)
+            .find(|salt| created(*salt).get_top_address_tableid() != table_id)
+            .unwrap();
+        let in_factory_table = (0..)
+            .find(|salt| created(*salt).get_top_address_tableid() == table_id)
+            .unwrap();
+
+        // the call modifies the created account again, which must not hide its creation
+        let mut engine = Engine::new(io.clone(), &env);
+        let error = engine.call(call_args(factory, word(in_other_table))).unwrap_err();
+        assert!(matches!(error.kind, EngineErrorEnum::CrossTableCreate(address) if address == created(in_other_table)));
+        assert_eq!(get_nonce(&io, &created(in_other_table)), U256::zero());
+
+        let mut engine = Engine::new(io.clone(), &env);
+        let result = engine.call(call_args(factory, word(in_factory_table))).unwrap();
+        assert_eq!(result.status, TransactionStatus::Succeed(Vec::new()));
+        assert_eq!(get_nonce(&io, &created(in_factory_table)), U256::one());
+    }
+
+    #[test]
+    fn test_deploy_twice_different_address() {
+        let io = MemoryIO::new();
+        let env = MemoryEnv::default();
+
+        let first = deploy(&io, &env);
+        let second = deploy(&io, &env);
+        assert_ne!(first, second);
+        assert_eq!(get_nonce(&io, &caller()), U256::from(2));
     }
 }
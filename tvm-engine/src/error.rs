@@ -1,44 +1,190 @@
+use std::fmt;
+
 use evm::{ExitError, ExitFatal};
+use tvm_engine_types::{Address, PStatusCode};
 
+/// Error which aborts a transaction, `gas_used` is still charged.
+#[derive(Debug)]
 pub struct EngineError {
     pub kind: EngineErrorEnum,
     pub gas_used: u64,
 }
 
+/// Evm exit reasons which are not a normal `TransactionStatus`, and transactions rejected before execution.
+#[derive(Debug)]
 pub enum EngineErrorEnum {
     EvmError(ExitError),
     EvmFatal(ExitFatal),
+    /// rejected before execution, nothing was charged or written.
+    InvalidTransaction(InvalidTransaction),
+    /// a nested `CREATE`/`CREATE2` made this contract outside the table of the contract creating it.
+    CrossTableCreate(Address),
+}
+
+/// Why a transaction was rejected before execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidTransaction {
+    /// the args nonce is below the sender's, the transaction already ran.
+    NonceTooLow,
+    /// the args nonce is above the sender's, a transaction before it is missing.
+    NonceTooHigh,
+    /// the args chain id is not `Env::chain_id`.
+    ChainIdMismatch,
+    /// `gas_limit` does not cover the base cost, input and access list of the transaction.
+    IntrinsicGasTooLow,
+    /// the sender's balance does not cover `value` and `gas_limit` at the fee cap.
+    InsufficientFunds,
+    /// init code above twice the contract code size limit, as EIP-3860.
+    InitCodeTooLarge,
+    /// `max_fee_per_gas`, or the legacy gas price, is below the block base fee.
+    FeeCapTooLow,
+    /// `max_priority_fee_per_gas` is above `max_fee_per_gas`.
+    TipAboveFeeCap,
+    /// the deployment's `salt` yields an address outside the sender's table.
+    SaltTableMismatch,
+    /// an address, storage key, nonce or salt of the protobuf args has the wrong length.
+    MalformedArgs,
+}
+
+impl InvalidTransaction {
+    pub fn status_code(&self) -> PStatusCode {
+        match self {
+            InvalidTransaction::NonceTooLow => PStatusCode::NONCE_TOO_LOW,
+            InvalidTransaction::NonceTooHigh => PStatusCode::NONCE_TOO_HIGH,
+            InvalidTransaction::ChainIdMismatch => PStatusCode::CHAIN_ID_MISMATCH,
+            InvalidTransaction::IntrinsicGasTooLow => PStatusCode::INTRINSIC_GAS_TOO_LOW,
+            InvalidTransaction::InsufficientFunds => PStatusCode::INSUFFICIENT_FUNDS,
+            InvalidTransaction::InitCodeTooLarge => PStatusCode::INIT_CODE_TOO_LARGE,
+            InvalidTransaction::FeeCapTooLow => PStatusCode::FEE_CAP_TOO_LOW,
+            InvalidTransaction::TipAboveFeeCap => PStatusCode::TIP_ABOVE_FEE_CAP,
+            InvalidTransaction::SaltTableMismatch => PStatusCode::SALT_TABLE_MISMATCH,
+            InvalidTransaction::MalformedArgs => PStatusCode::MALFORMED_ARGS,
+        }
+    }
+
+    /// rejected transactions consume no gas.
+    pub fn into_engine_error(self) -> EngineError {
+        EngineErrorEnum::from(self).with_gas_used(0)
+    }
 }
 
+impl fmt::Display for InvalidTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            InvalidTransaction::NonceTooLow => "nonce too low",
+            InvalidTransaction::NonceTooHigh => "nonce too high",
+            InvalidTransaction::ChainIdMismatch => "invalid chain id",
+            InvalidTransaction::IntrinsicGasTooLow => "intrinsic gas too low",
+            InvalidTransaction::InsufficientFunds => "insufficient funds for gas * price + value",
+            InvalidTransaction::InitCodeTooLarge => "max initcode size exceeded",
+            InvalidTransaction::FeeCapTooLow => "max fee per gas less than block base fee",
+            InvalidTransaction::TipAboveFeeCap => "max priority fee per gas higher than max fee per gas",
+            InvalidTransaction::SaltTableMismatch => "salt gives a contract address outside the sender's table",
+            InvalidTransaction::MalformedArgs => "malformed transaction args",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for InvalidTransaction {}
+
 impl EngineErrorEnum {
     pub fn with_gas_used(self, gas_used: u64) -> EngineError {
         EngineError { kind: self, gas_used }
     }
 
-    pub fn as_bytes(&self) -> &[u8] {
+    /// `status` of the protobuf results, `error_message` is the `Display` of the error.
+    pub fn status_code(&self) -> PStatusCode {
+        use EngineErrorEnum::*;
+        match self {
+            EvmError(e) => exit_error_code(e),
+            EvmFatal(ExitFatal::NotSupported) => PStatusCode::NOT_SUPPORTED,
+            EvmFatal(ExitFatal::UnhandledInterrupt) => PStatusCode::UNHANDLED_INTERRUPT,
+            EvmFatal(ExitFatal::CallErrorAsFatal(_)) => PStatusCode::CALL_ERROR_AS_FATAL,
+            EvmFatal(ExitFatal::Other(_)) => PStatusCode::EVM_FATAL_OTHER,
+            InvalidTransaction(invalid) => invalid.status_code(),
+            CrossTableCreate(_) => PStatusCode::CROSS_TABLE_CREATE,
+        }
+    }
+}
+
+impl fmt::Display for EngineErrorEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use EngineErrorEnum::*;
         match self {
-            EvmError(ExitError::StackUnderflow) => b"ERR_STACK_UNDERFLOW",
-            EvmError(ExitError::StackOverflow) => b"ERR_STACK_OVERFLOW",
-            EvmError(ExitError::InvalidJump) => b"ERR_INVALID_JUMP",
-            EvmError(ExitError::InvalidRange) => b"ERR_INVALID_RANGE",
-            EvmError(ExitError::DesignatedInvalid) => b"ERR_DESIGNATED_INVALID",
-            EvmError(ExitError::CallTooDeep) => b"ERR_CALL_TOO_DEEP",
-            EvmError(ExitError::CreateCollision) => b"ERR_CREATE_COLLISION",
-            EvmError(ExitError::CreateContractLimit) => b"ERR_CREATE_CONTRACT_LIMIT",
-            EvmError(ExitError::OutOfOffset) => b"ERR_OUT_OF_OFFSET",
-            EvmError(ExitError::OutOfGas) => b"ERR_OUT_OF_GAS",
-            EvmError(ExitError::OutOfFund) => b"ERR_OUT_OF_FUND",
-            EvmError(ExitError::Other(m)) => m.as_bytes(),
-            EvmError(_) => unreachable!(), // unused misc
-            EvmFatal(ExitFatal::NotSupported) => b"ERR_NOT_SUPPORTED",
-            EvmFatal(ExitFatal::UnhandledInterrupt) => b"ERR_UNHANDLED_INTERRUPT",
-            EvmFatal(ExitFatal::Other(m)) => m.as_bytes(),
-            EvmFatal(_) => unreachable!(), // unused misc
+            EvmError(e) => write_exit_error(f, e),
+            EvmFatal(ExitFatal::NotSupported) => f.write_str("not supported"),
+            EvmFatal(ExitFatal::UnhandledInterrupt) => f.write_str("unhandled interrupt"),
+            EvmFatal(ExitFatal::CallErrorAsFatal(e)) => {
+                f.write_str("call error as fatal: ")?;
+                write_exit_error(f, e)
+            }
+            EvmFatal(ExitFatal::Other(m)) => f.write_str(m),
+            InvalidTransaction(invalid) => invalid.fmt(f),
+            CrossTableCreate(address) => write!(f, "contract {} created outside its creator's table", address),
         }
     }
 }
 
+impl std::error::Error for EngineErrorEnum {}
+
+/// the message of `kind`, the gas used is not part of it.
+impl fmt::Display for EngineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind.fmt(f)
+    }
+}
+
+impl std::error::Error for EngineError {}
+
+fn exit_error_code(e: &ExitError) -> PStatusCode {
+    match e {
+        ExitError::StackUnderflow => PStatusCode::STACK_UNDERFLOW,
+        ExitError::StackOverflow => PStatusCode::STACK_OVERFLOW,
+        ExitError::InvalidJump => PStatusCode::INVALID_JUMP,
+        ExitError::InvalidRange => PStatusCode::INVALID_RANGE,
+        ExitError::DesignatedInvalid => PStatusCode::DESIGNATED_INVALID,
+        ExitError::CallTooDeep => PStatusCode::CALL_TOO_DEEP,
+        ExitError::CreateCollision => PStatusCode::CREATE_COLLISION,
+        ExitError::CreateContractLimit => PStatusCode::CREATE_CONTRACT_LIMIT,
+        ExitError::InvalidCode(_) => PStatusCode::INVALID_CODE,
+        // normally a `TransactionStatus`, same code when it surfaces as an error
+        ExitError::OutOfOffset => PStatusCode::OUT_OF_OFFSET,
+        ExitError::OutOfGas => PStatusCode::OUT_OF_GAS,
+        ExitError::OutOfFund => PStatusCode::OUT_OF_FUND,
+        ExitError::PCUnderflow => PStatusCode::PC_UNDERFLOW,
+        ExitError::CreateEmpty => PStatusCode::CREATE_EMPTY,
+        ExitError::Other(_) => PStatusCode::EVM_ERROR_OTHER,
+    }
+}
+
+fn write_exit_error(f: &mut fmt::Formatter<'_>, e: &ExitError) -> fmt::Result {
+    let msg = match e {
+        ExitError::StackUnderflow => "stack underflow",
+        ExitError::StackOverflow => "stack limit reached",
+        ExitError::InvalidJump => "invalid jump destination",
+        ExitError::InvalidRange => "return data out of bounds",
+        ExitError::DesignatedInvalid => "invalid opcode: INVALID",
+        ExitError::CallTooDeep => "max call depth exceeded",
+        ExitError::CreateCollision => "contract address collision",
+        ExitError::CreateContractLimit => "max code size exceeded",
+        ExitError::InvalidCode(opcode) => return write!(f, "invalid code: 0x{:02x}", opcode.as_u8()),
+        ExitError::OutOfOffset => "out of offset",
+        ExitError::OutOfGas => "out of gas",
+        ExitError::OutOfFund => "insufficient balance for transfer",
+        ExitError::PCUnderflow => "program counter underflow",
+        ExitError::CreateEmpty => "create empty",
+        ExitError::Other(m) => m,
+    };
+    f.write_str(msg)
+}
+
+impl From<InvalidTransaction> for EngineErrorEnum {
+    fn from(value: InvalidTransaction) -> Self {
+        Self::InvalidTransaction(value)
+    }
+}
+
 impl From<ExitError> for EngineErrorEnum {
     fn from(value: ExitError) -> Self {
         Self::EvmError(value)
@@ -50,3 +196,61 @@ impl From<ExitFatal> for EngineErrorEnum {
         Self::EvmFatal(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_code_of_every_error() {
+        let errors = [
+            ExitError::StackUnderflow,
+            ExitError::StackOverflow,
+            ExitError::InvalidJump,
+            ExitError::InvalidRange,
+            ExitError::DesignatedInvalid,
+            ExitError::CallTooDeep,
+            ExitError::CreateCollision,
+            ExitError::CreateContractLimit,
+            ExitError::InvalidCode(evm::Opcode(0xef)),
+            ExitError::PCUnderflow,
+            ExitError::CreateEmpty,
+            ExitError::Other("custom".into()),
+        ]
+        .into_iter()
+        .map(EngineErrorEnum::from)
+        .chain(
+            [
+                ExitFatal::NotSupported,
+                ExitFatal::UnhandledInterrupt,
+                ExitFatal::CallErrorAsFatal(ExitError::CallTooDeep),
+                ExitFatal::Other("custom".into()),
+            ]
+            .map(EngineErrorEnum::from),
+        )
+        .chain([EngineErrorEnum::CrossTableCreate(Address::zero())]);
+        let mut codes = std::collections::BTreeSet::new();
+        for error in errors {
+            assert!(!error.to_string().is_empty());
+            assert!(
+                codes.insert(error.status_code() as i32),
+                "duplicated code of {:?}",
+                error
+            );
+        }
+        assert_eq!(
+            EngineErrorEnum::EvmError(ExitError::Other("custom".into())).to_string(),
+            "custom"
+        );
+        assert_eq!(
+            EngineErrorEnum::EvmError(ExitError::OutOfGas).status_code(),
+            PStatusCode::OUT_OF_GAS
+        );
+        assert_eq!(
+            EngineErrorEnum::from(InvalidTransaction::NonceTooLow).status_code() as i32,
+            100
+        );
+        let error: Box<dyn std::error::Error> = Box::new(InvalidTransaction::NonceTooLow.into_engine_error());
+        assert_eq!(error.to_string(), "nonce too low");
+    }
+}
@@ -0,0 +1,106 @@
+use tvm_engine_types::{PForkSchedule, PHardfork};
+
+/// EVM rule sets the engine can run with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Hardfork {
+    Frontier,
+    Istanbul,
+    Berlin,
+    London,
+}
+
+static FRONTIER_CONFIG: evm::Config = evm::Config::frontier();
+static ISTANBUL_CONFIG: evm::Config = evm::Config::istanbul();
+static BERLIN_CONFIG: evm::Config = evm::Config::berlin();
+static LONDON_CONFIG: evm::Config = evm::Config::london();
+
+impl Hardfork {
+    pub fn config(&self) -> &'static evm::Config {
+        match self {
+            Hardfork::Frontier => &FRONTIER_CONFIG,
+            Hardfork::Istanbul => &ISTANBUL_CONFIG,
+            Hardfork::Berlin => &BERLIN_CONFIG,
+            Hardfork::London => &LONDON_CONFIG,
+        }
+    }
+}
+
+/// Block heights at which each hardfork becomes active, so old blocks replay with their original rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForkSchedule {
+    /// sorted by height, the first one activates at 0.
+    activations: Vec<(u64, Hardfork)>,
+}
+
+impl ForkSchedule {
+    /// Schedule running `genesis` from the first block.
+    pub fn new(genesis: Hardfork) -> Self {
+        Self {
+            activations: vec![(0, genesis)],
+        }
+    }
+
+    /// Activate `hardfork` from `height` on, replacing whatever was scheduled at that height.
+    pub fn activate(mut self, height: u64, hardfork: Hardfork) -> Self {
+        match self.activations.binary_search_by_key(&height, |(h, _)| *h) {
+            Ok(i) => self.activations[i].1 = hardfork,
+            Err(i) => self.activations.insert(i, (height, hardfork)),
+        }
+        self
+    }
+
+    /// Hardfork active at block `height`.
+    pub fn hardfork_at(&self, height: u64) -> Hardfork {
+        let i = self.activations.partition_point(|(h, _)| *h <= height);
+        self.activations[i - 1].1
+    }
+}
+
+/// TOP has run London since its first EVM block.
+impl Default for ForkSchedule {
+    fn default() -> Self {
+        Self::new(Hardfork::London)
+    }
+}
+
+impl TryFrom<PForkSchedule> for ForkSchedule {
+    /// the unknown `PHardfork` value.
+    type Error = i32;
+
+    fn try_from(value: PForkSchedule) -> Result<Self, Self::Error> {
+        value
+            .activations
+            .iter()
+            .try_fold(Self::default(), |schedule, activation| {
+                let hardfork = match activation.hardfork.enum_value()? {
+                    PHardfork::FRONTIER => Hardfork::Frontier,
+                    PHardfork::ISTANBUL => Hardfork::Istanbul,
+                    PHardfork::BERLIN => Hardfork::Berlin,
+                    PHardfork::LONDON => Hardfork::London,
+                };
+                Ok(schedule.activate(activation.height, hardfork))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hardfork_at() {
+        let schedule = ForkSchedule::new(Hardfork::Istanbul)
+            .activate(200, Hardfork::London)
+            .activate(100, Hardfork::Berlin);
+        assert_eq!(schedule.hardfork_at(0), Hardfork::Istanbul);
+        assert_eq!(schedule.hardfork_at(99), Hardfork::Istanbul);
+        assert_eq!(schedule.hardfork_at(100), Hardfork::Berlin);
+        assert_eq!(schedule.hardfork_at(199), Hardfork::Berlin);
+        assert_eq!(schedule.hardfork_at(u64::MAX), Hardfork::London);
+
+        // replaces the genesis rules
+        let schedule = schedule.activate(0, Hardfork::Frontier);
+        assert_eq!(schedule.hardfork_at(0), Hardfork::Frontier);
+        assert_eq!(ForkSchedule::default().hardfork_at(u64::MAX), Hardfork::London);
+    }
+}
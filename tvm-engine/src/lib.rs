@@ -1,11 +1,50 @@
+//! TOP-Chain Virtual Machine Engine.
+//!
+//! The C++ node drives the engine through the exports in `c_interface` with protobuf bytes,
+//! Rust hosts can link this crate and drive [`Engine`] directly with their own `IO` and `Env`:
+//!
+//! ```
+//! use tvm_engine::{CallArgs, Engine, TransactionStatus};
+//! use tvm_engine_runtime::{MemoryEnv, MemoryIO};
+//! use tvm_engine_types::{uTop, Address};
+//!
+//! let io = MemoryIO::new();
+//! let env = MemoryEnv::default();
+//! let mut engine = Engine::new(io, &env);
+//!
+//! // deploy a contract whose runtime code is a single `STOP`
+//! let result = engine
+//!     .call(CallArgs::new(
+//!         Address::build_from_str("56d9407e0ae1246a2aafcfa57f3fc1bd7023df81").unwrap(),
+//!         Address::zero(),
+//!         vec![0x60, 0x01, 0x60, 0x0c, 0x60, 0x00, 0x39, 0x60, 0x01, 0x60, 0x00, 0xf3, 0x00],
+//!         uTop::zero(),
+//!         1_000_000,
+//!     ))
+//!     .unwrap();
+//! assert!(matches!(result.status, TransactionStatus::Succeed(_)));
+//! ```
+
 mod engine;
 mod error;
+mod fork;
+mod stack_state;
+mod state_diff;
 mod types;
 
-/// should enable this condition feature:
-/// but will casue too many `dead_code` as we don't have standalone test for now
+#[cfg(feature = "tracing")]
+pub mod tracing;
+
+/// exports for the C++ node, could be gated by `build_as_xtop_lib`:
+/// but Rust hosts never reference these symbols, and leaving it unconditional keeps it checked by every build.
 // #[cfg(feature = "build_as_xtop_lib")]
 mod c_interface;
 
-pub(crate) use error::{EngineError, EngineErrorEnum};
-pub(crate) use types::{CallArgs, ReturnResult, TransactionStatus};
+pub use engine::{predict_deploy_address, Engine, EngineResult};
+pub use error::{EngineError, EngineErrorEnum, InvalidTransaction};
+pub use fork::{ForkSchedule, Hardfork};
+pub use state_diff::{AccountDiff, AccountState, StateDiff};
+pub use types::{
+    AccessListResult, CallArgs, DeployAddressResult, Deployment, EstimateGasResult, GasPrice, ReturnResult,
+    TransactionStatus,
+};
@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+
+use evm::backend::{Apply, Backend, Basic, Log};
+use evm::executor::stack::{MemoryStackState, StackState, StackSubstateMetadata};
+use evm::{ExitError, Transfer};
+use tvm_engine_types::{H160, H256, U256};
+
+/// `MemoryStackState` which also records the creator of every account `CREATE`/`CREATE2` initializes.
+///
+/// The executor computes created addresses itself and reports nothing about them but the state calls:
+/// it resets the storage of the new account, then transfers the endowment from its creator to it. A creation
+/// is kept with the substate it happened in, so it only counts once every enclosing call frame committed.
+pub(crate) struct CreatorStackState<'backend, 'config, B> {
+    state: MemoryStackState<'backend, 'config, B>,
+    /// account whose storage was just reset, the next transfer into it is from its creator.
+    creating: Option<H160>,
+    /// creations of each substate entered and not exited yet, innermost last.
+    substates: Vec<Vec<(H160, H160)>>,
+    /// created account to its creator, for the creations committed into the root state.
+    creators: BTreeMap<H160, H160>,
+}
+
+impl<'backend, 'config, B: Backend> CreatorStackState<'backend, 'config, B> {
+    pub fn new(metadata: StackSubstateMetadata<'config>, backend: &'backend B) -> Self {
+        Self {
+            state: MemoryStackState::new(metadata, backend),
+            creating: None,
+            substates: Vec::new(),
+            creators: BTreeMap::new(),
+        }
+    }
+
+    /// state changes and logs like `MemoryStackState::deconstruct`, with the creator of each created account.
+    #[must_use]
+    pub fn deconstruct(
+        self,
+    ) -> (
+        impl IntoIterator<Item = Apply<impl IntoIterator<Item = (H256, H256)>>>,
+        impl IntoIterator<Item = Log>,
+        BTreeMap<H160, H160>,
+    ) {
+        let (values, logs) = self.state.deconstruct();
+        (values, logs, self.creators)
+    }
+
+    fn record(&mut self, created: H160, creator: H160) {
+        match self.substates.last_mut() {
+            Some(substate) => substate.push((created, creator)),
+            None => {
+                self.creators.insert(created, creator);
+            }
+        }
+    }
+
+    pub fn withdraw(&mut self, address: H160, value: U256) -> Result<(), ExitError> {
+        self.state.withdraw(address, value)
+    }
+
+    pub fn deposit(&mut self, address: H160, value: U256) {
+        self.state.deposit(address, value)
+    }
+}
+
+impl<'backend, 'config, B: Backend> Backend for CreatorStackState<'backend, 'config, B> {
+    fn gas_price(&self) -> U256 {
+        self.state.gas_price()
+    }
+    fn origin(&self) -> H160 {
+        self.state.origin()
+    }
+    fn block_hash(&self, number: U256) -> H256 {
+        self.state.block_hash(number)
+    }
+    fn block_number(&self) -> U256 {
+        self.state.block_number()
+    }
+    fn block_coinbase(&self) -> H160 {
+        self.state.block_coinbase()
+    }
+    fn block_timestamp(&self) -> U256 {
+        self.state.block_timestamp()
+    }
+    fn block_difficulty(&self) -> U256 {
+        self.state.block_difficulty()
+    }
+    fn block_gas_limit(&self) -> U256 {
+        self.state.block_gas_limit()
+    }
+    fn block_base_fee_per_gas(&self) -> U256 {
+        self.state.block_base_fee_per_gas()
+    }
+    fn chain_id(&self) -> U256 {
+        self.state.chain_id()
+    }
+    fn exists(&self, address: H160) -> bool {
+        self.state.exists(address)
+    }
+    fn basic(&self, address: H160) -> Basic {
+        self.state.basic(address)
+    }
+    fn code(&self, address: H160) -> Vec<u8> {
+        self.state.code(address)
+    }
+    fn storage(&self, address: H160, index: H256) -> H256 {
+        self.state.storage(address, index)
+    }
+    fn original_storage(&self, address: H160, index: H256) -> Option<H256> {
+        self.state.original_storage(address, index)
+    }
+}
+
+impl<'backend, 'config, B: Backend> StackState<'config> for CreatorStackState<'backend, 'config, B> {
+    fn metadata(&self) -> &StackSubstateMetadata<'config> {
+        self.state.metadata()
+    }
+    fn metadata_mut(&mut self) -> &mut StackSubstateMetadata<'config> {
+        self.state.metadata_mut()
+    }
+    fn enter(&mut self, gas_limit: u64, is_static: bool) {
+        self.substates.push(Vec::new());
+        self.state.enter(gas_limit, is_static)
+    }
+    fn exit_commit(&mut self) -> Result<(), ExitError> {
+        for (created, creator) in self.substates.pop().unwrap_or_default() {
+            self.record(created, creator);
+        }
+        self.state.exit_commit()
+    }
+    fn exit_revert(&mut self) -> Result<(), ExitError> {
+        self.creating = None;
+        self.substates.pop();
+        self.state.exit_revert()
+    }
+    fn exit_discard(&mut self) -> Result<(), ExitError> {
+        self.creating = None;
+        self.substates.pop();
+        self.state.exit_discard()
+    }
+    fn is_empty(&self, address: H160) -> bool {
+        self.state.is_empty(address)
+    }
+    fn deleted(&self, address: H160) -> bool {
+        self.state.deleted(address)
+    }
+    fn is_cold(&self, address: H160) -> bool {
+        self.state.is_cold(address)
+    }
+    fn is_storage_cold(&self, address: H160, key: H256) -> bool {
+        self.state.is_storage_cold(address, key)
+    }
+    fn inc_nonce(&mut self, address: H160) {
+        self.state.inc_nonce(address)
+    }
+    fn set_storage(&mut self, address: H160, key: H256, value: H256) {
+        self.state.set_storage(address, key, value)
+    }
+    /// only called on the account a `CREATE`/`CREATE2` initializes.
+    fn reset_storage(&mut self, address: H160) {
+        self.creating = Some(address);
+        self.state.reset_storage(address)
+    }
+    fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) {
+        self.state.log(address, topics, data)
+    }
+    fn set_deleted(&mut self, address: H160) {
+        self.state.set_deleted(address)
+    }
+    fn set_code(&mut self, address: H160, code: Vec<u8>) {
+        self.state.set_code(address, code)
+    }
+    fn transfer(&mut self, transfer: Transfer) -> Result<(), ExitError> {
+        if self.creating.take() == Some(transfer.target) {
+            self.record(transfer.target, transfer.source);
+        }
+        self.state.transfer(transfer)
+    }
+    fn reset_balance(&mut self, address: H160) {
+        self.state.reset_balance(address)
+    }
+    fn touch(&mut self, address: H160) {
+        self.state.touch(address)
+    }
+}
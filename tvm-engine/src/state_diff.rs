@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use evm::backend::{Apply, Backend};
+use tvm_engine_runtime::{env::Env, io::IO, utils};
+use tvm_engine_types::{uTop, Address, H256, U256};
+
+use crate::Engine;
+
+/// Balance, nonce and code hash of an account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountState {
+    /// whole uTop of the balance, what the native TOP side sees.
+    pub balance: uTop,
+    /// full balance, as seen by the evm and stored.
+    pub balance_wei: U256,
+    pub nonce: U256,
+    /// keccak of the code, keccak of nothing for accounts without code.
+    pub code_hash: H256,
+}
+
+/// How one transaction changed an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountDiff {
+    pub pre: AccountState,
+    /// `None` if the account was deleted, with all of its storage.
+    pub post: Option<AccountState>,
+    /// every changed slot with its previous and new value, zero is an empty slot.
+    pub storage: BTreeMap<H256, (H256, H256)>,
+    /// all slots were cleared before `storage` was written, when a contract is created at an address with storage.
+    pub storage_reset: bool,
+}
+
+/// Account changes of one transaction, as written through `IO`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    pub accounts: BTreeMap<Address, AccountDiff>,
+}
+
+impl AccountState {
+    fn write_json(&self, out: &mut String) {
+        write!(
+            out,
+            r#"{{"balance":"{:#x}","balanceUTop":{},"nonce":"{:#x}","codeHash":"{:?}"}}"#,
+            self.balance_wei,
+            self.balance.raw(),
+            self.nonce,
+            self.code_hash
+        )
+        .unwrap();
+    }
+}
+
+impl StateDiff {
+    /// Record the changes `values` make to the state behind `engine`, before they are applied.
+    pub(crate) fn record<'env, I: IO, E: Env>(
+        &mut self,
+        engine: &Engine<'env, I, E>,
+        values: &[Apply<BTreeMap<H256, H256>>],
+    ) {
+        for apply in values {
+            let (address, post, storage, storage_reset) = match apply {
+                Apply::Modify {
+                    address,
+                    basic,
+                    code,
+                    storage,
+                    reset_storage,
+                } => {
+                    let post = AccountState {
+                        balance: uTop::from_wei_value(basic.balance).unwrap_or(uTop::zero()),
+                        balance_wei: basic.balance,
+                        nonce: basic.nonce,
+                        code_hash: match code {
+                            Some(code) => utils::keccak(code),
+                            None => utils::keccak(&engine.code(*address)),
+                        },
+                    };
+                    (*address, Some(post), Some(storage), *reset_storage)
+                }
+                Apply::Delete { address } => (*address, None, None, false),
+            };
+
+            let storage = storage
+                .into_iter()
+                .flatten()
+                .filter_map(|(index, value)| {
+                    let pre = engine.storage(address, *index);
+                    (pre != *value).then_some((*index, (pre, *value)))
+                })
+                .collect::<BTreeMap<_, _>>();
+            // the backend still reads the state before the transaction
+            let basic = engine.basic(address);
+            let pre = AccountState {
+                balance: uTop::from_wei_value(basic.balance).unwrap_or(uTop::zero()),
+                balance_wei: basic.balance,
+                nonce: basic.nonce,
+                code_hash: utils::keccak(&engine.code(address)),
+            };
+            // accounts which were only read or touched
+            if post == Some(pre) && storage.is_empty() && !storage_reset {
+                continue;
+            }
+            self.accounts.insert(
+                Address::build_from_hash160(address),
+                AccountDiff {
+                    pre,
+                    post,
+                    storage,
+                    storage_reset,
+                },
+            );
+        }
+    }
+
+    /// encode as `{"0x<address>": {"pre": .., "post": .., "storage": {"0x<slot>": {"pre": .., "post": ..}}, "storageReset": ..}}`,
+    /// balances are in wei like geth's `prestateTracer`, with the uTop amount beside.
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{");
+        for (i, (address, diff)) in self.accounts.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write!(out, r#""0x{}":{{"pre":"#, address.encode()).unwrap();
+            diff.pre.write_json(&mut out);
+            out.push_str(r#","post":"#);
+            match &diff.post {
+                Some(post) => post.write_json(&mut out),
+                None => out.push_str("null"),
+            }
+            out.push_str(r#","storage":{"#);
+            for (i, (index, (pre, post))) in diff.storage.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write!(out, r#""{:?}":{{"pre":"{:?}","post":"{:?}"}}"#, index, pre, post).unwrap();
+            }
+            write!(out, r#"}},"storageReset":{}}}"#, diff.storage_reset).unwrap();
+        }
+        out.push('}');
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_diff_json() {
+        let address = Address::build_from_str("56d9407e0ae1246a2aafcfa57f3fc1bd7023df81").unwrap();
+        let pre = AccountState {
+            balance: uTop::new(1),
+            balance_wei: uTop::new(1).into_wei_raw(),
+            nonce: U256::zero(),
+            code_hash: utils::keccak(&[]),
+        };
+        let diff = StateDiff {
+            accounts: BTreeMap::from([(
+                address,
+                AccountDiff {
+                    pre,
+                    post: None,
+                    storage: BTreeMap::from([(H256::zero(), (H256::from_low_u64_be(1), H256::zero()))]),
+                    storage_reset: false,
+                },
+            )]),
+        };
+        let zero = format!("0x{}", "0".repeat(64));
+        let one = format!("0x{}1", "0".repeat(63));
+        assert_eq!(
+            diff.to_json(),
+            format!(
+                r#"{{"0x56d9407e0ae1246a2aafcfa57f3fc1bd7023df81":{{"pre":{{"balance":"0xe8d4a51000","balanceUTop":1,"nonce":"0x0","codeHash":"0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"}},"post":null,"storage":{{"{zero}":{{"pre":"{one}","post":"{zero}"}}}},"storageReset":false}}}}"#
+            )
+        );
+    }
+}
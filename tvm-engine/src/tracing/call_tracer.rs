@@ -0,0 +1,391 @@
+use std::fmt::Write;
+
+use evm::gasometer::tracing::Event as GasometerEvent;
+use evm::tracing::Event as EvmEvent;
+use evm::Opcode;
+use evm_runtime::tracing::Event as RuntimeEvent;
+use tvm_engine_types::{Address, U256};
+
+use super::{exit_error, json_string};
+use crate::{CallArgs, EngineErrorEnum, TransactionStatus};
+
+/// extra gas a value transferring `CALL` or `CALLCODE` hands to the callee.
+const CALL_STIPEND: u64 = 2300;
+
+/// `Error(string)` selector of solidity revert data.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallType {
+    Call,
+    StaticCall,
+    DelegateCall,
+    CallCode,
+    Create,
+    Create2,
+    SelfDestruct,
+}
+
+impl CallType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CallType::Call => "CALL",
+            CallType::StaticCall => "STATICCALL",
+            CallType::DelegateCall => "DELEGATECALL",
+            CallType::CallCode => "CALLCODE",
+            CallType::Create => "CREATE",
+            CallType::Create2 => "CREATE2",
+            CallType::SelfDestruct => "SELFDESTRUCT",
+        }
+    }
+
+    fn is_create(&self) -> bool {
+        matches!(self, CallType::Create | CallType::Create2)
+    }
+}
+
+/// One call or create of the transaction with its sub calls, geth's `callTracer` frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallFrame {
+    pub call_type: CallType,
+    pub from: Address,
+    /// callee, or the created contract.
+    pub to: Address,
+    /// wei transferred, `None` for `DELEGATECALL` and `STATICCALL`.
+    pub value: Option<U256>,
+    pub gas: u64,
+    pub gas_used: u64,
+    pub input: Vec<u8>,
+    /// return or revert data, the deployed code for creates.
+    pub output: Vec<u8>,
+    pub error: Option<String>,
+    /// message of a solidity `Error(string)` revert.
+    pub revert_reason: Option<String>,
+    pub calls: Vec<CallFrame>,
+}
+
+impl CallFrame {
+    fn new(call_type: CallType, from: Address, to: Address, value: Option<U256>, input: Vec<u8>) -> Self {
+        Self {
+            call_type,
+            from,
+            to,
+            value,
+            gas: 0,
+            gas_used: 0,
+            input,
+            output: Vec::new(),
+            error: None,
+            revert_reason: None,
+            calls: Vec::new(),
+        }
+    }
+
+    fn exit(&mut self, reason: &evm::ExitReason, return_value: &[u8]) {
+        // the deployed code is the init code output, creates exit with no data
+        if !(self.call_type.is_create() && reason.is_succeed()) {
+            self.output = return_value.to_vec();
+        }
+        self.error = exit_error(reason);
+        if reason.is_revert() {
+            self.revert_reason = revert_reason(&self.output);
+        }
+    }
+
+    /// encode in the json shape `debug_traceTransaction` returns with `callTracer`.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out);
+        out
+    }
+
+    fn write_json(&self, out: &mut String) {
+        write!(
+            out,
+            r#"{{"type":"{}","from":"0x{}","to":"0x{}","#,
+            self.call_type.as_str(),
+            self.from.encode(),
+            self.to.encode()
+        )
+        .unwrap();
+        if let Some(value) = self.value {
+            write!(out, r#""value":"{:#x}","#, value).unwrap();
+        }
+        write!(
+            out,
+            r#""gas":"{:#x}","gasUsed":"{:#x}","input":"0x{}""#,
+            self.gas,
+            self.gas_used,
+            hex::encode(&self.input)
+        )
+        .unwrap();
+        if !self.output.is_empty() {
+            write!(out, r#","output":"0x{}""#, hex::encode(&self.output)).unwrap();
+        }
+        if let Some(error) = &self.error {
+            out.push_str(r#","error":"#);
+            json_string(out, error);
+        }
+        if let Some(reason) = &self.revert_reason {
+            out.push_str(r#","revertReason":"#);
+            json_string(out, reason);
+        }
+        if !self.calls.is_empty() {
+            out.push_str(r#","calls":["#);
+            for (i, call) in self.calls.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                call.write_json(out);
+            }
+            out.push(']');
+        }
+        out.push('}');
+    }
+}
+
+/// Builds the `CallFrame` tree from the evm call, create and exit events.
+///
+/// The gas of a frame is the first cost its caller records after entering it,
+/// the gas it returns is the stipend its caller records before the exit event.
+#[derive(Default)]
+pub(crate) struct CallTracer {
+    stack: Vec<CallFrame>,
+    /// opcode of the last step, the call opcode when a call event follows.
+    last_opcode: Option<Opcode>,
+    /// gas returned by the frame on the top of `stack`.
+    returned_gas: Vec<u64>,
+    root: Option<CallFrame>,
+    /// the frame on the top of `stack` has not been charged yet.
+    gas_pending: bool,
+}
+
+impl CallTracer {
+    /// The root frame, its gas is the transaction `gas_limit` and its gas used the transaction gas used.
+    pub(crate) fn into_trace(
+        self,
+        args: CallArgs,
+        status: Result<TransactionStatus, EngineErrorEnum>,
+        used_gas: u64,
+    ) -> CallFrame {
+        let mut root = self.root.unwrap_or_else(|| {
+            // the transaction failed before entering its frame
+            let call_type = if args.recver_addr.is_zero() {
+                CallType::Create2
+            } else {
+                CallType::Call
+            };
+            let mut root = CallFrame::new(
+                call_type,
+                args.sender_addr,
+                args.recver_addr,
+                Some(args.value.into_wei_raw()),
+                args.input,
+            );
+            root.error = match &status {
+                Ok(TransactionStatus::Succeed(_)) => None,
+                Ok(status) => Some(status.error_message().to_owned()),
+                Err(e) => Some(e.to_string()),
+            };
+            root
+        });
+        root.gas = args.gas_limit;
+        root.gas_used = used_gas;
+        root
+    }
+
+    fn enter(&mut self, frame: CallFrame) {
+        self.stack.push(frame);
+        self.returned_gas.push(0);
+        self.gas_pending = true;
+    }
+
+    fn exit(&mut self, reason: &evm::ExitReason, return_value: &[u8]) {
+        self.gas_pending = false;
+        if let (Some(mut frame), Some(returned_gas)) = (self.stack.pop(), self.returned_gas.pop()) {
+            frame.exit(reason, return_value);
+            frame.gas_used = frame.gas.saturating_sub(returned_gas);
+            match self.stack.last_mut() {
+                Some(parent) => parent.calls.push(frame),
+                None => self.root = Some(frame),
+            }
+        }
+    }
+}
+
+impl evm::tracing::EventListener for CallTracer {
+    fn event(&mut self, event: EvmEvent) {
+        match event {
+            EvmEvent::Call {
+                code_address,
+                transfer,
+                input,
+                is_static,
+                context,
+                ..
+            } => {
+                // from the opcode of the call itself, a `CALL` inside a `STATICCALL` is still static
+                let call_type = match self.last_opcode.take() {
+                    Some(Opcode::STATICCALL) => CallType::StaticCall,
+                    Some(Opcode::DELEGATECALL) => CallType::DelegateCall,
+                    Some(Opcode::CALLCODE) => CallType::CallCode,
+                    Some(_) => CallType::Call,
+                    // the transaction itself
+                    None if is_static => CallType::StaticCall,
+                    None if code_address == context.address => CallType::Call,
+                    None if transfer.is_some() => CallType::CallCode,
+                    None => CallType::DelegateCall,
+                };
+                // a delegate call keeps the caller of the current frame
+                let from = match call_type {
+                    CallType::DelegateCall => context.address,
+                    _ => context.caller,
+                };
+                let value = match call_type {
+                    CallType::Call | CallType::CallCode => Some(transfer.as_ref().map(|t| t.value).unwrap_or_default()),
+                    _ => None,
+                };
+                self.enter(CallFrame::new(
+                    call_type,
+                    Address::build_from_hash160(from),
+                    Address::build_from_hash160(code_address),
+                    value,
+                    input.to_vec(),
+                ));
+            }
+            EvmEvent::Create {
+                caller,
+                address,
+                scheme,
+                value,
+                init_code,
+                ..
+            } => {
+                let call_type = match scheme {
+                    evm::CreateScheme::Create2 { .. } => CallType::Create2,
+                    _ => CallType::Create,
+                };
+                self.enter(CallFrame::new(
+                    call_type,
+                    Address::build_from_hash160(caller),
+                    Address::build_from_hash160(address),
+                    Some(value),
+                    init_code.to_vec(),
+                ));
+            }
+            EvmEvent::Suicide {
+                address,
+                target,
+                balance,
+            } => {
+                if let Some(frame) = self.stack.last_mut() {
+                    frame.calls.push(CallFrame::new(
+                        CallType::SelfDestruct,
+                        Address::build_from_hash160(address),
+                        Address::build_from_hash160(target),
+                        Some(balance),
+                        Vec::new(),
+                    ));
+                }
+            }
+            EvmEvent::Exit { reason, return_value } => self.exit(reason, return_value),
+            _ => (),
+        }
+    }
+}
+
+impl evm_runtime::tracing::EventListener for CallTracer {
+    fn event(&mut self, event: RuntimeEvent) {
+        match event {
+            RuntimeEvent::Step { opcode, .. } => self.last_opcode = Some(opcode),
+            RuntimeEvent::StepResult {
+                result: Err(evm::Capture::Exit(_)),
+                return_value,
+            } => {
+                if let Some(frame) = self.stack.last_mut() {
+                    frame.output = return_value.to_vec();
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+impl evm::gasometer::tracing::EventListener for CallTracer {
+    fn event(&mut self, event: GasometerEvent) {
+        match event {
+            GasometerEvent::RecordCost { cost, .. } if self.gas_pending => {
+                self.gas_pending = false;
+                if let Some(frame) = self.stack.last_mut() {
+                    frame.gas = match (frame.call_type, frame.value) {
+                        (CallType::Call | CallType::CallCode, Some(value)) if !value.is_zero() => {
+                            cost.saturating_add(CALL_STIPEND)
+                        }
+                        _ => cost,
+                    };
+                }
+            }
+            GasometerEvent::RecordStipend { stipend, .. } if !self.gas_pending => {
+                if let Some(returned_gas) = self.returned_gas.last_mut() {
+                    *returned_gas = stipend;
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// message of `Error(string)` revert data.
+fn revert_reason(output: &[u8]) -> Option<String> {
+    let data = output.strip_prefix(&ERROR_SELECTOR)?;
+    let word = |at: usize| -> Option<usize> {
+        let word = data.get(at..at.checked_add(32)?)?;
+        // offsets and lengths never exceed the 8 low bytes
+        if word[..24].iter().any(|b| *b != 0) {
+            return None;
+        }
+        usize::try_from(u64::from_be_bytes(word[24..].try_into().ok()?)).ok()
+    };
+    let offset = word(0)?;
+    let len = word(offset)?;
+    let start = offset.checked_add(32)?;
+    let message = data.get(start..start.checked_add(len)?)?;
+    String::from_utf8(message.to_vec()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revert_reason() {
+        // `revert("Not enough Ether provided.")`
+        let output = hex::decode(
+            "08c379a0\
+             0000000000000000000000000000000000000000000000000000000000000020\
+             000000000000000000000000000000000000000000000000000000000000001a\
+             4e6f7420656e6f7567682045746865722070726f76696465642e000000000000",
+        )
+        .unwrap();
+        assert_eq!(revert_reason(&output).as_deref(), Some("Not enough Ether provided."));
+        assert_eq!(revert_reason(&output[..40]), None);
+        assert_eq!(revert_reason(&[]), None);
+    }
+
+    #[test]
+    fn test_call_frame_json() {
+        let from = Address::build_from_str("56d9407e0ae1246a2aafcfa57f3fc1bd7023df81").unwrap();
+        let to = Address::build_from_str("7156526fbd7a3c72969b54f64e42c10fbb768c8a").unwrap();
+        let mut frame = CallFrame::new(CallType::Call, from, to, Some(U256::zero()), vec![0x01]);
+        frame.gas = 30_000;
+        frame.gas_used = 21_000;
+        let mut inner = CallFrame::new(CallType::StaticCall, to, from, None, Vec::new());
+        inner.output = vec![0x02];
+        inner.error = Some("execution reverted".to_owned());
+        frame.calls.push(inner);
+        assert_eq!(
+            frame.to_json(),
+            r#"{"type":"CALL","from":"0x56d9407e0ae1246a2aafcfa57f3fc1bd7023df81","to":"0x7156526fbd7a3c72969b54f64e42c10fbb768c8a","value":"0x0","gas":"0x7530","gasUsed":"0x5208","input":"0x01","calls":[{"type":"STATICCALL","from":"0x7156526fbd7a3c72969b54f64e42c10fbb768c8a","to":"0x56d9407e0ae1246a2aafcfa57f3fc1bd7023df81","gas":"0x0","gasUsed":"0x0","input":"0x","output":"0x02","error":"execution reverted"}]}"#
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Tracers listening to the sputnik evm tracing events, only built with the `tracing` feature.
+//!
+//! The evm, runtime and gasometer each emit their own events to a `'static` listener,
+//! so one tracer is shared between the three through `trace_with`.
+
+mod call_tracer;
+mod struct_logger;
+
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::rc::Rc;
+
+use crate::EngineErrorEnum;
+
+pub use call_tracer::{CallFrame, CallType};
+pub use struct_logger::{StructLog, StructLogTrace};
+
+pub(crate) use call_tracer::CallTracer;
+pub(crate) use struct_logger::StructLogger;
+
+/// Run `f` with `tracer` receiving every evm, runtime and gasometer event, returns it afterwards.
+pub(crate) fn trace_with<T, R, F>(tracer: T, f: F) -> (R, T)
+where
+    T: evm::tracing::EventListener
+        + evm_runtime::tracing::EventListener
+        + evm::gasometer::tracing::EventListener
+        + 'static,
+    F: FnOnce() -> R,
+{
+    let tracer = Rc::new(RefCell::new(tracer));
+    let result = evm::tracing::using(&mut Shared(tracer.clone()), || {
+        evm_runtime::tracing::using(&mut Shared(tracer.clone()), || {
+            evm::gasometer::tracing::using(&mut Shared(tracer.clone()), f)
+        })
+    });
+    let tracer = match Rc::try_unwrap(tracer) {
+        Ok(tracer) => tracer.into_inner(),
+        Err(_) => unreachable!("listeners are dropped once `using` returns"),
+    };
+    (result, tracer)
+}
+
+struct Shared<T>(Rc<RefCell<T>>);
+
+impl<T: evm::tracing::EventListener> evm::tracing::EventListener for Shared<T> {
+    fn event(&mut self, event: evm::tracing::Event) {
+        self.0.borrow_mut().event(event)
+    }
+}
+
+impl<T: evm_runtime::tracing::EventListener> evm_runtime::tracing::EventListener for Shared<T> {
+    fn event(&mut self, event: evm_runtime::tracing::Event) {
+        self.0.borrow_mut().event(event)
+    }
+}
+
+impl<T: evm::gasometer::tracing::EventListener> evm::gasometer::tracing::EventListener for Shared<T> {
+    fn event(&mut self, event: evm::gasometer::tracing::Event) {
+        self.0.borrow_mut().event(event)
+    }
+}
+
+/// json string literal, escaping what `ExitError::Other` messages may contain.
+fn json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// geth style error message of a failed frame, the `Display` of the engine error it becomes.
+fn exit_error(reason: &evm::ExitReason) -> Option<String> {
+    use evm::ExitReason::*;
+    match reason {
+        Succeed(_) => None,
+        Revert(_) => Some("execution reverted".to_owned()),
+        Error(e) => Some(EngineErrorEnum::from(e.clone()).to_string()),
+        Fatal(e) => Some(EngineErrorEnum::from(e.clone()).to_string()),
+    }
+}
@@ -0,0 +1,355 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use evm::gasometer::tracing::Event as GasometerEvent;
+use evm::tracing::Event as EvmEvent;
+use evm::Opcode;
+use evm_runtime::tracing::Event as RuntimeEvent;
+use tvm_engine_types::{H160, H256, U256};
+
+use super::{exit_error, json_string};
+use crate::{EngineErrorEnum, TransactionStatus};
+
+/// One executed opcode, an entry of geth's `structLogs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructLog {
+    pub pc: usize,
+    pub op: Opcode,
+    /// gas left before the opcode.
+    pub gas: u64,
+    /// gas charged for the opcode, including memory expansion and the gas passed to sub calls.
+    pub gas_cost: u64,
+    pub depth: usize,
+    /// stack before the opcode, bottom first.
+    pub stack: Vec<H256>,
+    /// memory before the opcode.
+    pub memory: Vec<u8>,
+    /// slots of the executing contract loaded or stored so far, only set on `SLOAD` and `SSTORE`.
+    pub storage: Option<BTreeMap<H256, H256>>,
+    pub error: Option<String>,
+}
+
+/// Result of `Engine::trace_struct_logs`, geth's struct logger output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructLogTrace {
+    pub gas: u64,
+    pub failed: bool,
+    pub return_value: Vec<u8>,
+    pub struct_logs: Vec<StructLog>,
+}
+
+impl StructLogTrace {
+    /// encode in the json shape `debug_traceTransaction` returns without a tracer.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        write!(
+            out,
+            r#"{{"gas":{},"failed":{},"returnValue":"0x{}","structLogs":["#,
+            self.gas,
+            self.failed,
+            hex::encode(&self.return_value)
+        )
+        .unwrap();
+        for (i, log) in self.struct_logs.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            log.write_json(&mut out);
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+impl StructLog {
+    fn write_json(&self, out: &mut String) {
+        write!(
+            out,
+            r#"{{"pc":{},"op":"{}","gas":{},"gasCost":{},"depth":{},"stack":["#,
+            self.pc,
+            opcode_name(self.op),
+            self.gas,
+            self.gas_cost,
+            self.depth
+        )
+        .unwrap();
+        for (i, value) in self.stack.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write!(out, r#""{:#x}""#, U256::from_big_endian(value.as_bytes())).unwrap();
+        }
+        out.push_str(r#"],"memory":["#);
+        for (i, word) in self.memory.chunks(32).enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write!(out, r#""{}""#, hex::encode(word)).unwrap();
+        }
+        out.push(']');
+        if let Some(storage) = &self.storage {
+            out.push_str(r#","storage":{"#);
+            for (i, (index, value)) in storage.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write!(out, r#""{}":"{}""#, hex::encode(index), hex::encode(value)).unwrap();
+            }
+            out.push('}');
+        }
+        if let Some(error) = &self.error {
+            out.push_str(r#","error":"#);
+            json_string(out, error);
+        }
+        out.push('}');
+    }
+}
+
+/// Records a `StructLog` on every runtime step.
+///
+/// Gas comes from the gasometer events following the step, depth from the evm call and create events.
+#[derive(Default)]
+pub(crate) struct StructLogger {
+    logs: Vec<StructLog>,
+    depth: usize,
+    /// the last step has not seen a gasometer snapshot yet.
+    gas_pending: bool,
+    storage: BTreeMap<H160, BTreeMap<H256, H256>>,
+}
+
+impl StructLogger {
+    pub(crate) fn into_trace(
+        self,
+        status: Result<TransactionStatus, EngineErrorEnum>,
+        used_gas: u64,
+    ) -> StructLogTrace {
+        let (failed, return_value) = match status {
+            Ok(status @ TransactionStatus::Succeed(_)) => (false, status.into_data()),
+            Ok(status) => (true, status.into_data()),
+            Err(_) => (true, Vec::new()),
+        };
+        StructLogTrace {
+            gas: used_gas,
+            failed,
+            return_value,
+            struct_logs: self.logs,
+        }
+    }
+
+    fn record_cost(&mut self, cost: u64, snapshot: Option<evm::gasometer::Snapshot>) {
+        if let Some(log) = self.logs.last_mut() {
+            if let (true, Some(snapshot)) = (self.gas_pending, snapshot) {
+                log.gas = snapshot.gas();
+                self.gas_pending = false;
+            }
+            log.gas_cost = log.gas_cost.saturating_add(cost);
+        }
+    }
+
+    fn record_storage(&mut self, address: H160, index: H256, value: H256) {
+        let storage = self.storage.entry(address).or_default();
+        storage.insert(index, value);
+        if let Some(log) = self.logs.last_mut() {
+            log.storage = Some(storage.clone());
+        }
+    }
+}
+
+impl evm::tracing::EventListener for StructLogger {
+    fn event(&mut self, event: EvmEvent) {
+        match event {
+            EvmEvent::Call { .. } | EvmEvent::Create { .. } => self.depth += 1,
+            EvmEvent::Exit { reason, .. } => {
+                // the frame failed on its last step, either in the step or in its gas check
+                if let Some(log) = self.logs.last_mut().filter(|log| log.depth == self.depth) {
+                    if log.error.is_none() {
+                        log.error = exit_error(reason);
+                    }
+                }
+                self.depth = self.depth.saturating_sub(1);
+            }
+            _ => (),
+        }
+    }
+}
+
+impl evm_runtime::tracing::EventListener for StructLogger {
+    fn event(&mut self, event: RuntimeEvent) {
+        match event {
+            RuntimeEvent::Step {
+                opcode,
+                position,
+                stack,
+                memory,
+                ..
+            } => {
+                self.logs.push(StructLog {
+                    pc: position.as_ref().copied().unwrap_or_default(),
+                    op: opcode,
+                    gas: 0,
+                    gas_cost: 0,
+                    depth: self.depth,
+                    stack: stack.data().clone(),
+                    memory: memory.data().clone(),
+                    storage: None,
+                    error: None,
+                });
+                self.gas_pending = true;
+            }
+            RuntimeEvent::SLoad { address, index, value } | RuntimeEvent::SStore { address, index, value } => {
+                self.record_storage(address, index, value)
+            }
+            RuntimeEvent::StepResult { .. } => (),
+        }
+    }
+}
+
+impl evm::gasometer::tracing::EventListener for StructLogger {
+    fn event(&mut self, event: GasometerEvent) {
+        match event {
+            GasometerEvent::RecordCost { cost, snapshot } => self.record_cost(cost, snapshot),
+            GasometerEvent::RecordDynamicCost {
+                gas_cost,
+                memory_gas,
+                snapshot,
+                ..
+            } => {
+                // `memory_gas` is the total after expansion
+                let memory_cost = memory_gas.saturating_sub(snapshot.map(|s| s.memory_gas).unwrap_or_default());
+                self.record_cost(gas_cost.saturating_add(memory_cost), snapshot)
+            }
+            _ => (),
+        }
+    }
+}
+
+/// opcode mnemonic as geth prints it.
+fn opcode_name(opcode: Opcode) -> String {
+    let name = match opcode.as_u8() {
+        0x00 => "STOP",
+        0x01 => "ADD",
+        0x02 => "MUL",
+        0x03 => "SUB",
+        0x04 => "DIV",
+        0x05 => "SDIV",
+        0x06 => "MOD",
+        0x07 => "SMOD",
+        0x08 => "ADDMOD",
+        0x09 => "MULMOD",
+        0x0a => "EXP",
+        0x0b => "SIGNEXTEND",
+        0x10 => "LT",
+        0x11 => "GT",
+        0x12 => "SLT",
+        0x13 => "SGT",
+        0x14 => "EQ",
+        0x15 => "ISZERO",
+        0x16 => "AND",
+        0x17 => "OR",
+        0x18 => "XOR",
+        0x19 => "NOT",
+        0x1a => "BYTE",
+        0x1b => "SHL",
+        0x1c => "SHR",
+        0x1d => "SAR",
+        0x20 => "KECCAK256",
+        0x30 => "ADDRESS",
+        0x31 => "BALANCE",
+        0x32 => "ORIGIN",
+        0x33 => "CALLER",
+        0x34 => "CALLVALUE",
+        0x35 => "CALLDATALOAD",
+        0x36 => "CALLDATASIZE",
+        0x37 => "CALLDATACOPY",
+        0x38 => "CODESIZE",
+        0x39 => "CODECOPY",
+        0x3a => "GASPRICE",
+        0x3b => "EXTCODESIZE",
+        0x3c => "EXTCODECOPY",
+        0x3d => "RETURNDATASIZE",
+        0x3e => "RETURNDATACOPY",
+        0x3f => "EXTCODEHASH",
+        0x40 => "BLOCKHASH",
+        0x41 => "COINBASE",
+        0x42 => "TIMESTAMP",
+        0x43 => "NUMBER",
+        0x44 => "DIFFICULTY",
+        0x45 => "GASLIMIT",
+        0x46 => "CHAINID",
+        0x47 => "SELFBALANCE",
+        0x48 => "BASEFEE",
+        0x50 => "POP",
+        0x51 => "MLOAD",
+        0x52 => "MSTORE",
+        0x53 => "MSTORE8",
+        0x54 => "SLOAD",
+        0x55 => "SSTORE",
+        0x56 => "JUMP",
+        0x57 => "JUMPI",
+        0x58 => "PC",
+        0x59 => "MSIZE",
+        0x5a => "GAS",
+        0x5b => "JUMPDEST",
+        n @ 0x60..=0x7f => return format!("PUSH{}", n - 0x5f),
+        n @ 0x80..=0x8f => return format!("DUP{}", n - 0x7f),
+        n @ 0x90..=0x9f => return format!("SWAP{}", n - 0x8f),
+        n @ 0xa0..=0xa4 => return format!("LOG{}", n - 0xa0),
+        0xf0 => "CREATE",
+        0xf1 => "CALL",
+        0xf2 => "CALLCODE",
+        0xf3 => "RETURN",
+        0xf4 => "DELEGATECALL",
+        0xf5 => "CREATE2",
+        0xfa => "STATICCALL",
+        0xfd => "REVERT",
+        0xfe => "INVALID",
+        0xff => "SELFDESTRUCT",
+        n => return format!("opcode {:#04x} not defined", n),
+    };
+    name.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opcode_name() {
+        assert_eq!(opcode_name(Opcode::SHA3), "KECCAK256");
+        assert_eq!(opcode_name(Opcode::PUSH1), "PUSH1");
+        assert_eq!(opcode_name(Opcode::PUSH32), "PUSH32");
+        assert_eq!(opcode_name(Opcode::DUP16), "DUP16");
+        assert_eq!(opcode_name(Opcode::SWAP1), "SWAP1");
+        assert_eq!(opcode_name(Opcode(0xa4)), "LOG4");
+        assert_eq!(opcode_name(Opcode::SUICIDE), "SELFDESTRUCT");
+        assert_eq!(opcode_name(Opcode(0x0c)), "opcode 0x0c not defined");
+    }
+
+    #[test]
+    fn test_struct_log_json() {
+        let trace = StructLogTrace {
+            gas: 21003,
+            failed: false,
+            return_value: vec![0x12],
+            struct_logs: vec![StructLog {
+                pc: 2,
+                op: Opcode::SSTORE,
+                gas: 100,
+                gas_cost: 20000,
+                depth: 1,
+                stack: vec![H256::from_low_u64_be(0x1234), H256::zero()],
+                memory: vec![0u8; 32],
+                storage: Some(BTreeMap::from([(H256::zero(), H256::from_low_u64_be(0x1234))])),
+                error: Some("out of \"gas\"".to_owned()),
+            }],
+        };
+        let zero = "0".repeat(64);
+        let value = format!("{}1234", "0".repeat(60));
+        assert_eq!(
+            trace.to_json(),
+            format!(
+                r#"{{"gas":21003,"failed":false,"returnValue":"0x12","structLogs":[{{"pc":2,"op":"SSTORE","gas":100,"gasCost":20000,"depth":1,"stack":["0x1234","0x0"],"memory":["{zero}"],"storage":{{"{zero}":"{value}"}},"error":"out of \"gas\""}}]}}"#
+            )
+        );
+    }
+}
@@ -1,61 +1,216 @@
 use evm::backend::Log;
 use tvm_engine_runtime::utils::panic_utf8;
-use tvm_engine_types::{uTop, Address, PU256};
-use tvm_engine_types::{PCallArgs, PLog, PReturnResult};
+use tvm_engine_types::{uTop, Address, PAddress, H256, PU256, U256};
+use tvm_engine_types::{
+    PAccessListItem, PAccessListResult, PCallArgs, PDeployAddressResult, PEstimateGasResult, PLog, PReturnResult,
+    PStatusCode,
+};
 
-/// Args from outside call.
-pub(crate) struct CallArgs {
+use crate::{EngineError, InvalidTransaction};
+
+/// Args of one transaction: deploy a contract when `recver_addr` is zero, call it otherwise.
+///
+/// Built with `CallArgs::new` and the `with_*` setters, new fields may be added in any release.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CallArgs {
     pub sender_addr: Address,
     pub recver_addr: Address,
     pub value: uTop,
     pub input: Vec<u8>,
     pub gas_limit: u64,
+    /// `Env::gas_price` as a legacy price when `None`.
+    pub gas_price: Option<GasPrice>,
+    /// EIP-2930 addresses and slots warmed before execution, paid for with the intrinsic gas.
+    pub access_list: Vec<(Address, Vec<H256>)>,
+    /// must be the sender's nonce when set, `call` rejects the transaction otherwise.
+    pub nonce: Option<U256>,
+    /// must be `Env::chain_id` when set, `call` rejects the transaction otherwise.
+    pub chain_id: Option<u64>,
+    /// `CREATE2` salt of a deployment, derived from the sender's nonce when `None`.
+    /// Its address must land in the sender's table, or the transaction is rejected.
+    pub salt: Option<H256>,
+}
+
+/// Fee a transaction offers, in wei per gas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasPrice {
+    Legacy(U256),
+    /// EIP-1559: the base fee is burned, the tip on top of it goes to the coinbase.
+    Eip1559 {
+        max_fee_per_gas: U256,
+        max_priority_fee_per_gas: U256,
+    },
 }
 
-/// Result that return back.
+/// Result of an executed transaction.
 #[derive(Debug)]
-pub(crate) struct ReturnResult {
-    status: u32,
-    status_data: Vec<u8>,
-    gas_used: u64,
-    logs: Vec<Log>,
+#[non_exhaustive]
+pub struct ReturnResult {
+    pub status: TransactionStatus,
+    pub gas_used: u64,
+    pub logs: Vec<Log>,
+    /// wei per gas the sender paid, base fee and tip.
+    pub effective_gas_price: U256,
+    /// what a successful deployment created, `None` for calls and failed deployments.
+    pub deployment: Option<Deployment>,
+}
+
+/// Contract created by a deployment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Deployment {
+    pub contract_address: Address,
+    /// keccak of the runtime code the constructor returned.
+    pub code_hash: H256,
+    pub code_size: u64,
+    /// `CREATE2` salt the address derives from.
+    pub salt: H256,
 }
 
 /// Execute result if no eninge error occur.
 /// Returned by evm, than convert into return_result.status.
-#[derive(Debug)]
-pub(crate) enum TransactionStatus {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionStatus {
+    /// contract call output, empty for deployments whose runtime code is described by `ReturnResult::deployment`.
     Succeed(Vec<u8>),
+    /// revert data.
     Revert(Vec<u8>),
     OutOfGas,
     OutOfFund,
     OutOfOffset,
 }
 
+/// Result of `Engine::create_access_list`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct AccessListResult {
+    /// status with `access_list` attached.
+    pub status: TransactionStatus,
+    /// gas used with `access_list` attached.
+    pub gas_used: u64,
+    pub access_list: Vec<(Address, Vec<H256>)>,
+}
+
+/// Result of `Engine::predict_deploy_address`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DeployAddressResult {
+    pub contract_address: Address,
+    /// `CREATE2` salt `deploy_code` will use.
+    pub salt: H256,
+}
+
+/// Result of `Engine::estimate_gas`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct EstimateGasResult {
+    /// status with the estimated `gas_limit`, or with the cap if the call never succeeds.
+    pub status: TransactionStatus,
+    pub gas_used: u64,
+    /// minimal `gas_limit` the call succeeds with, zero if it never succeeds.
+    pub gas_limit: u64,
+}
+
+impl CallArgs {
+    /// Args with the optional fields unset, see their docs for the defaults.
+    pub fn new(sender_addr: Address, recver_addr: Address, input: Vec<u8>, value: uTop, gas_limit: u64) -> Self {
+        Self {
+            sender_addr,
+            recver_addr,
+            value,
+            input,
+            gas_limit,
+            gas_price: None,
+            access_list: Vec::new(),
+            nonce: None,
+            chain_id: None,
+            salt: None,
+        }
+    }
+
+    pub fn with_gas_price(mut self, gas_price: GasPrice) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    pub fn with_access_list(mut self, access_list: Vec<(Address, Vec<H256>)>) -> Self {
+        self.access_list = access_list;
+        self
+    }
+
+    pub fn with_nonce(mut self, nonce: U256) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn with_salt(mut self, salt: H256) -> Self {
+        self.salt = Some(salt);
+        self
+    }
+}
+
+impl GasPrice {
+    /// Price paid per gas at `base_fee`, the fee cap of a legacy price is the price itself.
+    pub fn effective(&self, base_fee: U256) -> Result<U256, InvalidTransaction> {
+        let (max_fee_per_gas, max_priority_fee_per_gas) = match *self {
+            GasPrice::Legacy(gas_price) => (gas_price, gas_price),
+            GasPrice::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            } => (max_fee_per_gas, max_priority_fee_per_gas),
+        };
+        if max_priority_fee_per_gas > max_fee_per_gas {
+            return Err(InvalidTransaction::TipAboveFeeCap);
+        }
+        if max_fee_per_gas < base_fee {
+            return Err(InvalidTransaction::FeeCapTooLow);
+        }
+        Ok(base_fee + max_priority_fee_per_gas.min(max_fee_per_gas - base_fee))
+    }
+
+    /// most the sender may pay per gas.
+    pub fn max_fee_per_gas(&self) -> U256 {
+        match *self {
+            GasPrice::Legacy(gas_price) => gas_price,
+            GasPrice::Eip1559 { max_fee_per_gas, .. } => max_fee_per_gas,
+        }
+    }
+}
+
 impl ReturnResult {
-    pub(crate) fn new(tx_status: TransactionStatus, gas_used: u64, logs: Vec<Log>) -> Self {
+    pub fn new(
+        tx_status: TransactionStatus,
+        gas_used: u64,
+        logs: Vec<Log>,
+        effective_gas_price: U256,
+        deployment: Option<Deployment>,
+    ) -> Self {
         Self {
-            status: tx_status.as_u32(),
-            status_data: {
-                if let TransactionStatus::Succeed(data) | TransactionStatus::Revert(data) = tx_status {
-                    data
-                } else {
-                    Vec::new()
-                }
-            },
+            status: tx_status,
             gas_used,
             logs,
+            effective_gas_price,
+            deployment,
         }
     }
-}
 
-impl TransactionStatus {}
+    pub fn is_succeed(&self) -> bool {
+        matches!(self.status, TransactionStatus::Succeed(_))
+    }
+}
 
 impl From<ReturnResult> for PReturnResult {
     fn from(value: ReturnResult) -> Self {
         Self {
-            status: value.status,
-            status_data: value.status_data,
+            status: value.status.status_code().into(),
+            error_message: value.status.error_message().to_owned(),
+            status_data: value.status.into_data(),
             gas_used: value.gas_used,
             // thank god this conversion code is not that difficult to write :)
             logs: value
@@ -75,31 +230,195 @@ impl From<ReturnResult> for PReturnResult {
                     ..Default::default()
                 })
                 .collect(),
+            // saturated, only a `U256` price from `Env` can exceed u64
+            effective_gas_price: if value.effective_gas_price > U256::from(u64::MAX) {
+                u64::MAX
+            } else {
+                value.effective_gas_price.as_u64()
+            },
+            contract_address: value.deployment.map(|d| d.contract_address.into()).into(),
+            code_hash: value
+                .deployment
+                .map(|d| d.code_hash.as_bytes().to_vec())
+                .unwrap_or_default(),
+            code_size: value.deployment.map(|d| d.code_size).unwrap_or_default(),
+            salt: value.deployment.map(|d| d.salt.as_bytes().to_vec()).unwrap_or_default(),
             ..Default::default()
         }
     }
 }
 
-impl From<PCallArgs> for CallArgs {
-    fn from(value: PCallArgs) -> Self {
+impl From<EngineError> for PReturnResult {
+    fn from(value: EngineError) -> Self {
         Self {
-            sender_addr: value.sender_address.get_or_default().into(),
-            recver_addr: value.recver_address.get_or_default().into(),
+            status: value.kind.status_code().into(),
+            error_message: value.kind.to_string(),
+            gas_used: value.gas_used,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<EstimateGasResult> for PEstimateGasResult {
+    fn from(value: EstimateGasResult) -> Self {
+        Self {
+            status: value.status.status_code().into(),
+            error_message: value.status.error_message().to_owned(),
+            status_data: value.status.into_data(),
+            gas_used: value.gas_used,
+            gas_limit: value.gas_limit,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<EngineError> for PEstimateGasResult {
+    fn from(value: EngineError) -> Self {
+        Self {
+            status: value.kind.status_code().into(),
+            error_message: value.kind.to_string(),
+            gas_used: value.gas_used,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<AccessListResult> for PAccessListResult {
+    fn from(value: AccessListResult) -> Self {
+        Self {
+            status: value.status.status_code().into(),
+            error_message: value.status.error_message().to_owned(),
+            status_data: value.status.into_data(),
+            gas_used: value.gas_used,
+            access_list: value
+                .access_list
+                .into_iter()
+                .map(|(address, keys)| PAccessListItem {
+                    address: Some(address.into()).into(),
+                    storage_keys: keys.into_iter().map(|key| key.as_bytes().to_vec()).collect(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<EngineError> for PAccessListResult {
+    fn from(value: EngineError) -> Self {
+        Self {
+            status: value.kind.status_code().into(),
+            error_message: value.kind.to_string(),
+            gas_used: value.gas_used,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<DeployAddressResult> for PDeployAddressResult {
+    fn from(value: DeployAddressResult) -> Self {
+        Self {
+            status: PStatusCode::SUCCEED.into(),
+            contract_address: Some(value.contract_address.into()).into(),
+            salt: value.salt.as_bytes().to_vec(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<EngineError> for PDeployAddressResult {
+    fn from(value: EngineError) -> Self {
+        Self {
+            status: value.kind.status_code().into(),
+            error_message: value.kind.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Malformed lengths of addresses, storage keys, nonce or salt are rejected as `InvalidTransaction::MalformedArgs`.
+impl TryFrom<PCallArgs> for CallArgs {
+    type Error = InvalidTransaction;
+
+    fn try_from(value: PCallArgs) -> Result<Self, Self::Error> {
+        let address = |address: &PAddress| {
+            Address::build_from_slice(&address.value).map_err(|_| InvalidTransaction::MalformedArgs)
+        };
+        let h256 = |bytes: &[u8]| {
+            (bytes.len() == 32)
+                .then(|| H256::from_slice(bytes))
+                .ok_or(InvalidTransaction::MalformedArgs)
+        };
+        Ok(Self {
+            sender_addr: address(value.sender_address.get_or_default())?,
+            recver_addr: address(value.recver_address.get_or_default())?,
             value: value.value.into(),
             input: value.input,
             gas_limit: value.gas_limit,
-        }
+            gas_price: if value.max_fee_per_gas != 0 {
+                Some(GasPrice::Eip1559 {
+                    max_fee_per_gas: value.max_fee_per_gas.into(),
+                    max_priority_fee_per_gas: value.max_priority_fee_per_gas.into(),
+                })
+            } else if value.gas_price != 0 {
+                Some(GasPrice::Legacy(value.gas_price.into()))
+            } else {
+                None
+            },
+            access_list: value
+                .access_list
+                .iter()
+                .map(|item| {
+                    let keys = item
+                        .storage_keys
+                        .iter()
+                        .map(|key| h256(key))
+                        .collect::<Result<_, _>>()?;
+                    Ok((address(item.address.get_or_default())?, keys))
+                })
+                .collect::<Result<_, _>>()?,
+            nonce: match value.nonce.into_option() {
+                Some(nonce) if nonce.data.len() > 32 => return Err(InvalidTransaction::MalformedArgs),
+                nonce => nonce.map(|nonce| U256::from_big_endian(&nonce.data)),
+            },
+            chain_id: (value.chain_id != 0).then_some(value.chain_id),
+            salt: if value.salt.is_empty() {
+                None
+            } else {
+                Some(h256(&value.salt)?)
+            },
+        })
     }
 }
 
 impl TransactionStatus {
-    pub(crate) fn as_u32(&self) -> u32 {
+    /// stable code carried in `PReturnResult.status`.
+    pub fn status_code(&self) -> PStatusCode {
+        match self {
+            TransactionStatus::Succeed(_) => PStatusCode::SUCCEED,
+            TransactionStatus::Revert(_) => PStatusCode::REVERT,
+            TransactionStatus::OutOfGas => PStatusCode::OUT_OF_GAS,
+            TransactionStatus::OutOfFund => PStatusCode::OUT_OF_FUND,
+            TransactionStatus::OutOfOffset => PStatusCode::OUT_OF_OFFSET,
+        }
+    }
+
+    /// human readable reason of a failed status, empty on success.
+    pub fn error_message(&self) -> &'static str {
+        match self {
+            TransactionStatus::Succeed(_) => "",
+            TransactionStatus::Revert(_) => "execution reverted",
+            TransactionStatus::OutOfGas => "out of gas",
+            TransactionStatus::OutOfFund => "insufficient balance for transfer",
+            TransactionStatus::OutOfOffset => "out of offset",
+        }
+    }
+
+    /// output or revert data, empty for the other status.
+    pub fn into_data(self) -> Vec<u8> {
         match self {
-            TransactionStatus::Succeed(_) => 0,
-            TransactionStatus::Revert(_) => 1,
-            TransactionStatus::OutOfGas => 2,
-            TransactionStatus::OutOfFund => 3,
-            TransactionStatus::OutOfOffset => 4,
+            TransactionStatus::Succeed(data) | TransactionStatus::Revert(data) => data,
+            _ => Vec::new(),
         }
     }
 }
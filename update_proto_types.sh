@@ -5,6 +5,13 @@
 # apt-get install protobuf-compiler
 # cargo uninstall protobuf-codegen
 # cargo install protobuf-codegen
+#
+# The C++ bindings are generated by protoc 3.11.4 and need the protobuf headers and runtime of
+# xdepends at 3.11 or later, their `PROTOBUF_VERSION` guard fails the build below that.
+# The baseline bindings came from protoc 3.6.1, the raised minimum is the price of regenerating
+# them for the new proto types with a current protoc. pbasic.pb.* is regenerated too although
+# pbasic.proto did not change: pparameters.pb.cc uses the pbasic symbols of the protoc that
+# generated it, so both must come from the same protoc. Check `protoc --version` first.
 
 protoc --rust_out ./tvm-engine-types/src/proto/ ./protobuf_types/pbasic.proto ./protobuf_types/pparameters.proto
 protoc --cpp_out ./tvm-c-api/ ./protobuf_types/pbasic.proto ./protobuf_types/pparameters.proto
\ No newline at end of file